@@ -7,13 +7,17 @@ an updated src/tld_registry.rs file with all current TLDs and their nameservers.
 
 Usage:
     python scripts/update_tlds.py
+    python scripts/update_tlds.py --offline   # re-apply this script's rules to the current data
     
 Or make it executable:
     chmod +x scripts/update_tlds.py
     ./scripts/update_tlds.py
 """
 
+import argparse
 import urllib.request
+import json
+import re
 import sys
 from collections import defaultdict
 from datetime import datetime
@@ -26,9 +30,20 @@ import time
 # URL for the IANA root zone file
 ROOT_ZONE_URL = "https://www.internic.net/domain/root.zone"
 
+# IANA bootstrap file mapping TLDs to RDAP base URLs (RFC 9224)
+RDAP_BOOTSTRAP_URL = "https://data.iana.org/rdap/dns.json"
+
+# Public Suffix List; its ICANN section tells second-level registries (co.uk, com.au) apart
+PUBLIC_SUFFIX_LIST_URL = "https://publicsuffix.org/list/public_suffix_list.dat"
+PSL_ICANN_END = "// ===END ICANN DOMAINS==="
+
+# IANA's WHOIS server, which refers each TLD to its registry's WHOIS server
+IANA_WHOIS_SERVER = "whois.iana.org"
+
 # Output file path (relative to script location)
 SCRIPT_DIR = os.path.dirname(os.path.abspath(__file__))
 OUTPUT_FILE = os.path.join(SCRIPT_DIR, "..", "src", "tld_registry.rs")
+PSL_FILE = os.path.join(SCRIPT_DIR, "..", "src", "public_suffix_list.dat")
 
 # TLD categories for timeout assignment
 COMMON_GTLDS = {
@@ -51,6 +66,24 @@ ASIA_PACIFIC_CCTLDS = {
     'kz', 'uz', 'tm', 'kg', 'tj', 'af', 'bt'
 }
 
+# Registration rules that differ from LabelPolicy::DEFAULT (labels of 1-63 characters,
+# digits-only and IDN labels accepted). Only rules the registry publishes belong here.
+# Second-level suffixes (`com.au`) have their own rules, which don't follow the TLD's.
+AU_POLICY = {'min_length': 2, 'idn': False}
+BR_POLICY = {'min_length': 2, 'max_length': 26}
+
+LABEL_POLICIES = {
+    'au': AU_POLICY,
+    'be': {'min_length': 2},
+    'ca': {'min_length': 2},
+    'eu': {'min_length': 2},
+    'ru': {'min_length': 2, 'idn': False},
+    'us': {'idn': False},
+    'xn--p1ai': {'min_length': 2},
+    **{f'{sld}.au': AU_POLICY for sld in ('asn', 'com', 'id', 'net', 'org')},
+    **{f'{sld}.br': BR_POLICY for sld in ('app', 'art', 'blog', 'com', 'dev', 'eco', 'ind', 'net', 'org', 'tec')},
+}
+
 # Categories to exclude from default --all searches
 # These can still be checked individually
 EXCLUDED_CATEGORIES = {
@@ -502,7 +535,17 @@ EXCLUDED_CATEGORIES = {
         'qtile', 'xmonad', 'stumpwm', 'ratpoison',
         'bash', 'zsh', 'fish', 'nushell',
         'powershell', 'cmd', 'tcsh', 'ksh',
-        'dash', 'ash', 'csh', 'rc'
+        'dash', 'ash', 'csh', 'rc',
+        # Brand IDNs: 谷歌, グーグル (Google), アマゾン, 亚马逊 (Amazon), 飞利浦 (Philips),
+        # 삼성 (Samsung), 微博 (Weibo), 中信 (CITIC), 联通 (China Unicom), 淡马锡 (Temasek),
+        # 香格里拉 (Shangri-La), 嘉里, 嘉里大酒店 (Kerry), 電訊盈科 (PCCW), ارامكو (Aramco),
+        # العليان (Olayan), بيتك (Kuwait Finance House), vermögensberater/-beratung (DVAG)
+        'xn--flw351e', 'xn--qcka1pmc', 'xn--cckwcxetd', 'xn--jlq480n2rg',
+        'xn--kcrx77d1x4a', 'xn--cg4bki', 'xn--9krt00a', 'xn--fiq64b',
+        'xn--8y0a063a', 'xn--b4w605ferd', 'xn--5su34j936bgsg', 'xn--w4rs40l',
+        'xn--w4r85el8fhu5dnra', 'xn--fzys8d69uvgm', 'xn--mgba3a3ejt',
+        'xn--mgba7c0bbn0a', 'xn--ngbe9e0a', 'xn--vermgensberater-ctb',
+        'xn--vermgensberatung-pwb'
     },
     
     # Adult content TLDs
@@ -520,7 +563,9 @@ EXCLUDED_CATEGORIES = {
     # Religious TLDs
     'religious': {
         'bible', 'catholic', 'church', 'faith', 'islam', 'halal', 'mormon',
-        'shia', 'lds', 'buddhist', 'hindu', 'kosher'
+        'shia', 'lds', 'buddhist', 'hindu', 'kosher',
+        # католик, كاثوليك, 天主教 (catholic)
+        'xn--80aqecdr1a', 'xn--mgbi4ecexp', 'xn--tiq49xqyj'
     },
     
     # Alcohol/Controlled substances
@@ -529,7 +574,8 @@ EXCLUDED_CATEGORIES = {
         'brewery', 'distillery', 'smoke', 'vape', 'cannabis', 'weed'
     },
     
-    # Non-ASCII/International TLDs (IDNs starting with xn--)
+    # Non-ASCII/International TLDs (IDNs starting with xn--); tagged only, since they're
+    # open for registration like any other TLD and --all includes them
     'non_ascii': set()  # Will be populated by checking for 'xn--' prefix
 }
 
@@ -644,6 +690,131 @@ def resolve_all_nameservers(tlds):
     
     return tld_ips
 
+def lookup_whois_server(tld, timeout=10, attempts=3):
+    """Ask IANA's WHOIS server for the registry WHOIS server of a TLD.
+
+    Returns '' when IANA lists no WHOIS server for the TLD, None when it couldn't be asked.
+    """
+    for attempt in range(attempts):
+        try:
+            with socket.create_connection((IANA_WHOIS_SERVER, 43), timeout=timeout) as sock:
+                sock.sendall(f"{tld}\r\n".encode('ascii'))
+                response = b''
+                while chunk := sock.recv(4096):
+                    response += chunk
+        except (OSError, socket.timeout):
+            # IANA drops connections when queried too fast; back off and try again
+            time.sleep(2 ** attempt)
+            continue
+
+        for line in response.decode('utf-8', errors='replace').splitlines():
+            key, _, value = line.partition(':')
+            if key.strip().lower() == 'whois' and value.strip():
+                return value.strip().lower()
+        return ''
+    return None
+
+def lookup_all_whois_servers(tlds):
+    """Look up registry WHOIS servers for all TLDs."""
+    print(f"\nLooking up WHOIS servers via {IANA_WHOIS_SERVER}...")
+
+    whois_servers = {}
+    unreachable = []
+    # IANA rate-limits WHOIS queries, so keep concurrency low
+    with concurrent.futures.ThreadPoolExecutor(max_workers=4) as executor:
+        future_to_tld = {executor.submit(lookup_whois_server, tld): tld for tld in tlds}
+        for future in concurrent.futures.as_completed(future_to_tld):
+            server = future.result()
+            if server:
+                whois_servers[future_to_tld[future]] = server
+            elif server is None:
+                unreachable.append(future_to_tld[future])
+
+    print(f"Found WHOIS servers for {len(whois_servers)}/{len(tlds)} TLDs")
+    # A partial table would silently drop WHOIS servers from the generated file
+    if unreachable:
+        print(f"Error: {IANA_WHOIS_SERVER} didn't answer for {len(unreachable)} TLDs "
+              f"(e.g. {', '.join(sorted(unreachable)[:5])}); try again later")
+        sys.exit(1)
+    return whois_servers
+
+def download_rdap_bootstrap():
+    """Download the IANA RDAP bootstrap file and map each TLD to its RDAP base URL."""
+    print(f"\nDownloading RDAP bootstrap from {RDAP_BOOTSTRAP_URL}...")
+    try:
+        with urllib.request.urlopen(RDAP_BOOTSTRAP_URL) as response:
+            bootstrap = json.loads(response.read().decode('utf-8'))
+    except Exception as e:
+        # Writing the table without RDAP URLs would silently turn RDAP off for every TLD
+        print(f"Error downloading RDAP bootstrap: {e}")
+        sys.exit(1)
+
+    rdap_urls = {}
+    for tlds, urls in bootstrap.get('services', []):
+        # Prefer HTTPS, and normalize to a trailing slash
+        urls = sorted(urls, key=lambda url: not url.startswith('https://'))
+        if not urls:
+            continue
+        base = urls[0] if urls[0].endswith('/') else urls[0] + '/'
+        for tld in tlds:
+            rdap_urls[tld.lower()] = base
+
+    print(f"Found RDAP base URLs for {len(rdap_urls)} TLDs")
+    return rdap_urls
+
+def update_public_suffix_list():
+    """Download the Public Suffix List and keep its ICANN section (registry suffixes only)."""
+    print(f"\nDownloading Public Suffix List from {PUBLIC_SUFFIX_LIST_URL}...")
+    try:
+        with urllib.request.urlopen(PUBLIC_SUFFIX_LIST_URL) as response:
+            content = response.read().decode('utf-8')
+    except Exception as e:
+        print(f"Error downloading Public Suffix List: {e}")
+        sys.exit(1)
+
+    end = content.find(PSL_ICANN_END)
+    if end < 0:
+        print("Public Suffix List has no ICANN section, keeping the current snapshot")
+        return
+    icann = content[:end + len(PSL_ICANN_END)] + '\n'
+
+    with open(PSL_FILE, 'w') as f:
+        f.write(icann)
+    print(f"Wrote {len(icann)} bytes to {PSL_FILE}")
+
+def load_current_registry():
+    """Read the nameserver IPs, WHOIS servers and RDAP URLs of the current generated file.
+
+    Lets --offline re-apply this script's rules (timeouts, categories, label policies)
+    without downloading anything.
+    """
+    print(f"Reading current TLD data from {OUTPUT_FILE}...")
+    with open(OUTPUT_FILE) as f:
+        content = f.read()
+
+    entry = re.compile(
+        r'^    "(?P<tld>[^"]+)" => TldInfo \{\n'
+        r'        servers: &\[(?P<servers>[^\]]*)\],\n'
+        r'(?:        (?!whois:).*\n)*'
+        r'        whois: (?:Some\("(?P<whois>[^"]+)"\)|None),\n'
+        r'        rdap: (?:Some\("(?P<rdap>[^"]+)"\)|None),\n',
+        re.MULTILINE,
+    )
+    tld_ips, whois_servers, rdap_urls = {}, {}, {}
+    for match in entry.finditer(content):
+        tld = match['tld']
+        tld_ips[tld] = re.findall(r'"([^"]+)"', match['servers'])
+        if match['whois']:
+            whois_servers[tld] = match['whois']
+        if match['rdap']:
+            rdap_urls[tld] = match['rdap']
+
+    if not tld_ips:
+        print(f"Error: found no TLD entries in {OUTPUT_FILE}")
+        sys.exit(1)
+    print(f"Read {len(tld_ips)} TLDs, {len(whois_servers)} WHOIS servers, {len(rdap_urls)} RDAP URLs")
+    return tld_ips, whois_servers, rdap_urls
+
 def determine_timeout(tld):
     """Determine appropriate timeout for a TLD based on its type and location."""
     tld = tld.lower()
@@ -662,10 +833,6 @@ def is_excluded_tld(tld):
     """Check if a TLD should be excluded from default --all searches."""
     tld = tld.lower()
     
-    # Check if it's a non-ASCII TLD (IDN)
-    if tld.startswith('xn--'):
-        return True
-    
     # Check each excluded category
     for category, tld_set in EXCLUDED_CATEGORIES.items():
         if category != 'non_ascii' and tld in tld_set:
@@ -687,7 +854,18 @@ def get_tld_categories(tld):
     
     return categories
 
-def generate_rust_code(tld_ips):
+def format_label_policy(suffix):
+    """Rust expression for the LabelPolicy of a TLD or second-level suffix."""
+    overrides = LABEL_POLICIES.get(suffix)
+    if not overrides:
+        return 'LabelPolicy::DEFAULT'
+    fields = ', '.join(
+        f'{name}: {str(value).lower() if isinstance(value, bool) else value}'
+        for name, value in sorted(overrides.items())
+    )
+    return f'LabelPolicy {{ {fields}, ..LabelPolicy::DEFAULT }}'
+
+def generate_rust_code(tld_ips, whois_servers, rdap_urls):
     """Generate Rust code for the TLD module with pre-resolved IPs."""
     print(f"Generating Rust code for {len(tld_ips)} TLDs...")
     
@@ -718,6 +896,35 @@ pub struct TldInfo {
     pub timeout_ms: u64,
     pub excluded_from_all: bool,
     pub categories: &'static [&'static str],
+    /// Registry WHOIS server (port 43), if known; otherwise ask whois.iana.org for a referral.
+    pub whois: Option<&'static str>,
+    /// RDAP base URL from the IANA bootstrap file (ends with `/`), if the registry runs RDAP.
+    pub rdap: Option<&'static str>,
+    /// Which labels the registry accepts directly under this TLD.
+    pub policy: LabelPolicy,
+}
+
+/// Which second-level labels a registry accepts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LabelPolicy {
+    /// Shortest registrable label, in characters of its ASCII form.
+    pub min_length: u8,
+    /// Longest registrable label, in characters of its ASCII form.
+    pub max_length: u8,
+    /// Whether labels made only of digits are accepted.
+    pub numeric: bool,
+    /// Whether internationalized (`xn--`) labels are accepted.
+    pub idn: bool,
+}
+
+impl LabelPolicy {
+    /// What DNS itself allows: 1-63 characters, digits-only and IDN labels accepted.
+    pub const DEFAULT: LabelPolicy = LabelPolicy {
+        min_length: 1,
+        max_length: 63,
+        numeric: true,
+        idn: true,
+    };
 }
 
 pub static TLD_SERVERS: phf::Map<&'static str, TldInfo> = phf_map! {
@@ -735,6 +942,10 @@ pub static TLD_SERVERS: phf::Map<&'static str, TldInfo> = phf_map! {
         excluded = is_excluded_tld(tld)
         categories = get_tld_categories(tld)
         categories_array = ', '.join(f'"{cat}"' for cat in categories)
+        whois = whois_servers.get(tld)
+        whois_value = f'Some("{whois}")' if whois else 'None'
+        rdap = rdap_urls.get(tld)
+        rdap_value = f'Some("{rdap}")' if rdap else 'None'
         
         # Add entry
         rust_code += f'''    "{tld}" => TldInfo {{
@@ -742,77 +953,83 @@ pub static TLD_SERVERS: phf::Map<&'static str, TldInfo> = phf_map! {
         timeout_ms: {timeout},
         excluded_from_all: {str(excluded).lower()},
         categories: &[{categories_array}],
+        whois: {whois_value},
+        rdap: {rdap_value},
+        policy: {format_label_policy(tld)},
     }},
 '''
     
     rust_code += '''};
 
+/// Label policies of second-level suffixes (`com.au`), whose registries set their own rules.
+pub static SUFFIX_POLICIES: phf::Map<&'static str, LabelPolicy> = phf_map! {
+'''
+
+    for suffix in sorted(s for s in LABEL_POLICIES if '.' in s):
+        rust_code += f'''    "{suffix}" => {format_label_policy(suffix)},
+'''
+
+    rust_code += '''};
+
 /// Get TLD info for a domain
+///
+/// Looks up the domain's public suffix (`co.uk` for `brand.co.uk`), falling back to the
+/// TLD it belongs to when the suffix has no entry of its own.
 pub fn get_tld_info(domain: &str) -> Option<&'static TldInfo> {
-    let parts: Vec<&str> = domain.split('.').collect();
-    if parts.len() < 2 {
+    let domain = domain.to_lowercase();
+    if !domain.contains('.') {
         return None;
     }
-    
-    let tld = parts[parts.len() - 1].to_lowercase();
-    TLD_SERVERS.get(tld.as_str())
+
+    let suffix = crate::psl::public_suffix(&domain)?;
+    let tld = suffix.rsplit('.').next()?;
+    TLD_SERVERS.get(suffix).or_else(|| TLD_SERVERS.get(tld))
 }
 
-/// Get all TLDs (including excluded ones)
-pub fn get_all_tlds() -> Vec<&'static str> {
-    TLD_SERVERS.keys().copied().collect()
+/// Label policy of a public suffix (`com`, `com.au`): its own, never its TLD's.
+///
+/// `None` for suffixes without registry data, like `co.uk`.
+pub fn get_suffix_policy(suffix: &str) -> Option<LabelPolicy> {
+    SUFFIX_POLICIES
+        .get(suffix)
+        .copied()
+        .or_else(|| TLD_SERVERS.get(suffix).map(|info| info.policy))
 }
 
 /// Get TLDs suitable for --all flag (excludes private, adult, gambling, etc.)
 pub fn get_public_tlds() -> Vec<&'static str> {
     TLD_SERVERS
         .entries()
-        .filter_map(|(tld, info)| {
-            if info.excluded_from_all {
-                None
-            } else {
-                Some(*tld)
-            }
-        })
+        .filter_map(|(tld, info)| if info.excluded_from_all { None } else { Some(*tld) })
         .collect()
 }
 
-/// Get TLDs by category
-pub fn get_tlds_by_category(category: &str) -> Vec<&'static str> {
-    TLD_SERVERS
-        .entries()
-        .filter_map(|(tld, info)| {
-            if info.categories.contains(&category) {
-                Some(*tld)
-            } else {
-                None
-            }
-        })
-        .collect()
-}
-
-/// Check if a TLD is excluded from default searches
-pub fn is_excluded_tld(tld: &str) -> bool {
-    TLD_SERVERS
-        .get(tld)
-        .map(|info| info.excluded_from_all)
-        .unwrap_or(false)
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
-    
+
     #[test]
-    fn test_get_tld_info() {
+    fn get_tld_info_returns_known_tlds() {
         assert!(get_tld_info("example.com").is_some());
         assert!(get_tld_info("test.xyz").is_some());
         assert!(get_tld_info("invalid.unknown").is_none());
         assert!(get_tld_info("invalid").is_none());
+        assert_eq!(
+            get_tld_info("brand.co.uk").map(|info| info.servers),
+            get_tld_info("brand.uk").map(|info| info.servers)
+        );
     }
-    
+
     #[test]
-    fn test_common_tlds() {
+    fn suffix_policies_dont_inherit_the_tld() {
+        assert_eq!(get_suffix_policy("com"), Some(LabelPolicy::DEFAULT));
+        assert_eq!(get_suffix_policy("com.br").map(|policy| policy.max_length), Some(26));
+        assert!(get_suffix_policy("co.uk").is_none());
+        assert!(get_suffix_policy("unknown").is_none());
+    }
+
+    #[test]
+    fn common_tlds_are_present() {
         assert!(TLD_SERVERS.get("com").is_some());
         assert!(TLD_SERVERS.get("org").is_some());
         assert!(TLD_SERVERS.get("net").is_some());
@@ -820,44 +1037,43 @@ mod tests {
         assert!(TLD_SERVERS.get("dev").is_some());
         assert!(TLD_SERVERS.get("app").is_some());
     }
-    
+
     #[test]
-    fn test_excluded_tlds() {
+    fn excluded_tlds_are_filtered() {
         // Test that private TLDs are excluded
         if let Some(aws_info) = TLD_SERVERS.get("aws") {
             assert!(aws_info.excluded_from_all);
             assert!(aws_info.categories.contains(&"private"));
         }
-        
+
         // Test that adult TLDs are excluded
         if let Some(xxx_info) = TLD_SERVERS.get("xxx") {
             assert!(xxx_info.excluded_from_all);
             assert!(xxx_info.categories.contains(&"adult"));
         }
-        
+
         // Test that common TLDs are not excluded
         if let Some(com_info) = TLD_SERVERS.get("com") {
             assert!(!com_info.excluded_from_all);
             assert!(com_info.categories.is_empty());
         }
     }
-    
+
     #[test]
-    fn test_get_public_tlds() {
+    fn get_public_tlds_returns_valid_list() {
         let public_tlds = get_public_tlds();
-        
+
         // Should include common TLDs
         assert!(public_tlds.contains(&"com"));
         assert!(public_tlds.contains(&"org"));
-        
+
         // Should not include excluded TLDs
         assert!(!public_tlds.contains(&"xxx"));
         assert!(!public_tlds.contains(&"aws"));
-        
-        // Should not include IDN TLDs
-        for tld in &public_tlds {
-            assert!(!tld.starts_with("xn--"));
-        }
+
+        // Should include IDN TLDs (.рф, .中国)
+        assert!(public_tlds.contains(&"xn--p1ai"));
+        assert!(public_tlds.contains(&"xn--fiqs8s"));
     }
 }
 '''
@@ -942,21 +1158,42 @@ def print_statistics(tld_ips):
 
 def main():
     """Main function."""
+    parser = argparse.ArgumentParser(description="Regenerate src/tld_registry.rs from IANA data.")
+    parser.add_argument(
+        '--offline',
+        action='store_true',
+        help="reuse the nameserver IPs, WHOIS servers and RDAP URLs of the current "
+             "src/tld_registry.rs instead of downloading them",
+    )
+    args = parser.parse_args()
+
     print(f"TLD Update Script - {datetime.now().strftime('%Y-%m-%d %H:%M:%S')}")
     print("=" * 50)
     
-    # Download root zone
-    root_zone_content = download_root_zone()
-    print(f"Downloaded {len(root_zone_content)} bytes")
-    
-    # Parse TLDs
-    tlds = parse_root_zone(root_zone_content)
-    
-    # Resolve nameservers to IPs
-    tld_ips = resolve_all_nameservers(tlds)
-    
+    if args.offline:
+        tld_ips, whois_servers, rdap_urls = load_current_registry()
+    else:
+        # Download root zone
+        root_zone_content = download_root_zone()
+        print(f"Downloaded {len(root_zone_content)} bytes")
+
+        # Parse TLDs
+        tlds = parse_root_zone(root_zone_content)
+
+        # Resolve nameservers to IPs
+        tld_ips = resolve_all_nameservers(tlds)
+
+        # Look up registry WHOIS servers
+        whois_servers = lookup_all_whois_servers(tld_ips)
+
+        # Map TLDs to RDAP base URLs
+        rdap_urls = download_rdap_bootstrap()
+
+        # Refresh the public suffix snapshot
+        update_public_suffix_list()
+
     # Generate Rust code
-    rust_code = generate_rust_code(tld_ips)
+    rust_code = generate_rust_code(tld_ips, whois_servers, rdap_urls)
     
     # Write to file
     write_rust_file(rust_code)
@@ -968,7 +1205,7 @@ def main():
     print("\nNext steps:")
     print("1. Run 'cargo build' to compile with new TLDs")
     print("2. Run 'cargo test' to verify everything works")
-    print("3. Commit the updated src/tld_registry.rs file")
+    print("3. Commit the updated src/tld_registry.rs and src/public_suffix_list.dat files")
 
 if __name__ == "__main__":
     main()
\ No newline at end of file
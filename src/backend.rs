@@ -0,0 +1,356 @@
+//! Pluggable check backends.
+//!
+//! A [`CheckBackend`] maps a [`Domain`] to a [`CheckResult`]. The `Checker` queries DNS by
+//! default, and [`CheckerBuilder::backend`](crate::checker::CheckerBuilder::backend) adds
+//! others: the built-in [`RdapBackend`] and [`WhoisBackend`], a registrar API, or a mock
+//! for tests. A [`Strategy`] decides how their verdicts combine.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use dotchk::backend::{CheckBackend, Strategy};
+//! use dotchk::{CheckResult, Checker, Domain, DomainCheckerError, DomainStatus};
+//! use futures::future::BoxFuture;
+//!
+//! /// Everything but "taken.com" is available.
+//! struct Mock;
+//!
+//! impl CheckBackend for Mock {
+//!     fn name(&self) -> &str {
+//!         "mock"
+//!     }
+//!
+//!     fn check<'a>(&'a self, domain: &'a Domain) -> BoxFuture<'a, Result<CheckResult, DomainCheckerError>> {
+//!         Box::pin(async move {
+//!             let status = if domain.as_str() == "taken.com" { DomainStatus::Delegated } else { DomainStatus::NxDomain };
+//!             Ok(CheckResult::new(domain.as_str(), status))
+//!         })
+//!     }
+//! }
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let checker = Checker::builder()
+//!     .dns(false)?
+//!     .backend(Mock)?
+//!     .strategy(Strategy::FirstWins)?
+//!     .build()
+//!     .await?;
+//!
+//! assert!(!checker.check("taken.com").await?.available());
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::DomainCheckerError;
+use crate::checker::{CheckResult, DomainStatus};
+use crate::domain::Domain;
+use crate::rdap::RdapClient;
+use crate::whois::{WhoisClient, WhoisVerdict, parse_response};
+use futures::future::{BoxFuture, join_all};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::time::Instant;
+use tracing::{debug, warn};
+
+/// A source of availability verdicts.
+///
+/// Report "couldn't decide" either as `Err` or as `Ok` with [`DomainStatus::Error`];
+/// strategies treat both as no verdict.
+pub trait CheckBackend: Send + Sync {
+    /// Short name for logs, e.g. `"dns"` or `"rdap"`.
+    fn name(&self) -> &str;
+
+    /// Check one domain.
+    fn check<'a>(&'a self, domain: &'a Domain) -> BoxFuture<'a, Result<CheckResult, DomainCheckerError>>;
+}
+
+/// How the verdicts of several backends combine into one result.
+///
+/// "Decisive" below means an `Ok` result whose status isn't [`DomainStatus::Error`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Strategy {
+    /// Ask backends in order; the first decisive result wins. Later backends are fallbacks.
+    #[default]
+    FirstWins,
+    /// Ask the first backend; if it says available, every other backend must agree. The
+    /// first one that says taken wins; one that can't decide is skipped.
+    ConfirmPositives,
+    /// Ask all backends at once; the verdict most decisive results agree on wins, with
+    /// ties going to "taken".
+    Majority,
+}
+
+impl Strategy {
+    /// Check `domain` with `backends` and combine the verdicts.
+    ///
+    /// When no backend is decisive, returns the last backend's outcome.
+    pub(crate) async fn combine(
+        self,
+        backends: &[Arc<dyn CheckBackend>],
+        domain: &Domain,
+    ) -> Result<CheckResult, DomainCheckerError> {
+        match self {
+            Strategy::FirstWins => {
+                let mut last = None;
+                for backend in backends {
+                    let outcome = backend.check(domain).await;
+                    if is_decisive(&outcome) {
+                        return outcome;
+                    }
+                    debug!("Backend {} had no verdict for {}, trying next", backend.name(), domain);
+                    last = Some(outcome);
+                }
+                last.unwrap_or_else(|| Err(no_backends()))
+            }
+            Strategy::ConfirmPositives => {
+                let Some((primary, confirmers)) = backends.split_first() else {
+                    return Err(no_backends());
+                };
+                let mut result = match primary.check(domain).await {
+                    Ok(result) if result.available() => result,
+                    outcome => return outcome,
+                };
+
+                for backend in confirmers {
+                    match backend.check(domain).await {
+                        Ok(confirmation) if confirmation.status.is_error() => {
+                            warn!("Backend {} could not confirm {}", backend.name(), domain);
+                        }
+                        Ok(confirmation) if !confirmation.available() => {
+                            debug!("Backend {} says {} is taken", backend.name(), domain);
+                            return Ok(confirmation);
+                        }
+                        Ok(confirmation) => {
+                            // Keep what the confirming backend learned
+                            result.whois = result.whois.or(confirmation.whois);
+                            result.rdap = result.rdap.or(confirmation.rdap);
+                        }
+                        Err(e) => warn!("Backend {} could not confirm {}: {}", backend.name(), domain, e),
+                    }
+                }
+                Ok(result)
+            }
+            Strategy::Majority => {
+                let mut outcomes = join_all(backends.iter().map(|backend| backend.check(domain))).await;
+                let decisive: Vec<&CheckResult> = outcomes
+                    .iter()
+                    .filter(|outcome| is_decisive(outcome))
+                    .filter_map(|outcome| outcome.as_ref().ok())
+                    .collect();
+                if decisive.is_empty() {
+                    return outcomes.pop().unwrap_or_else(|| Err(no_backends()));
+                }
+
+                let available = decisive.iter().filter(|result| result.available()).count();
+                let majority_available = available * 2 > decisive.len();
+                let winner = decisive
+                    .iter()
+                    .find(|result| result.available() == majority_available)
+                    .copied()
+                    .cloned();
+                winner.ok_or_else(no_backends)
+            }
+        }
+    }
+}
+
+fn is_decisive(outcome: &Result<CheckResult, DomainCheckerError>) -> bool {
+    matches!(outcome, Ok(result) if !result.status.is_error())
+}
+
+fn no_backends() -> DomainCheckerError {
+    DomainCheckerError::Internal("no check backends configured".to_string())
+}
+
+/// No verdict for a public suffix, which registries don't record as a registration. RDAP
+/// and WHOIS look up a subdomain's registrable domain instead, so a suffix has nothing to
+/// look up.
+fn public_suffix(domain: &Domain) -> CheckResult {
+    CheckResult::new(
+        domain.as_str(),
+        DomainStatus::Error {
+            message: format!("{domain} is a public suffix, not a registrable domain"),
+        },
+    )
+}
+
+/// Registration lookups over RDAP of the domain a name is registered as, e.g. `example.com`
+/// for `www.example.com`; see [`crate::rdap`].
+#[derive(Debug, Clone, Default)]
+pub struct RdapBackend {
+    client: RdapClient,
+}
+
+impl RdapBackend {
+    pub fn new(client: RdapClient) -> Self {
+        Self { client }
+    }
+}
+
+impl CheckBackend for RdapBackend {
+    fn name(&self) -> &str {
+        "rdap"
+    }
+
+    fn check<'a>(&'a self, domain: &'a Domain) -> BoxFuture<'a, Result<CheckResult, DomainCheckerError>> {
+        Box::pin(async move {
+            let Some(registrable) = domain.registrable() else {
+                return Ok(public_suffix(domain));
+            };
+            let start = Instant::now();
+            let (info, base_url) = self.client.lookup(registrable).await?;
+
+            let mut result = CheckResult::new(
+                domain.as_str(),
+                DomainStatus::Rdap {
+                    registered: info.registered,
+                },
+            );
+            result.server = Some(base_url);
+            result.authoritative = true;
+            result.rtt_ms = Some(start.elapsed().as_secs_f64() * 1000.0);
+            result.nameservers = info.nameservers.clone();
+            result.rdap = Some(info);
+            Ok(result)
+        })
+    }
+}
+
+/// Registration lookups over WHOIS of the domain a name is registered as; see [`crate::whois`].
+#[derive(Debug, Clone, Default)]
+pub struct WhoisBackend {
+    client: WhoisClient,
+}
+
+impl WhoisBackend {
+    pub fn new(client: WhoisClient) -> Self {
+        Self { client }
+    }
+}
+
+impl CheckBackend for WhoisBackend {
+    fn name(&self) -> &str {
+        "whois"
+    }
+
+    fn check<'a>(&'a self, domain: &'a Domain) -> BoxFuture<'a, Result<CheckResult, DomainCheckerError>> {
+        Box::pin(async move {
+            let Some(registrable) = domain.registrable() else {
+                return Ok(public_suffix(domain));
+            };
+            let start = Instant::now();
+            let server = self.client.server_for(registrable).await?;
+            let verdict = parse_response(&server, &self.client.query(&server, registrable).await?);
+
+            let status = match verdict {
+                WhoisVerdict::Available => DomainStatus::Whois { registered: false },
+                WhoisVerdict::Registered => DomainStatus::Whois { registered: true },
+                WhoisVerdict::Unknown => DomainStatus::Error {
+                    message: format!("WHOIS answer from {server} was inconclusive"),
+                },
+            };
+            let mut result = CheckResult::new(domain.as_str(), status);
+            result.server = Some(server);
+            result.authoritative = true;
+            result.rtt_ms = Some(start.elapsed().as_secs_f64() * 1000.0);
+            result.whois = Some(verdict);
+            Ok(result)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Answers every domain with a fixed status, or fails.
+    struct Fixed(Option<DomainStatus>);
+
+    impl CheckBackend for Fixed {
+        fn name(&self) -> &str {
+            "fixed"
+        }
+
+        fn check<'a>(&'a self, domain: &'a Domain) -> BoxFuture<'a, Result<CheckResult, DomainCheckerError>> {
+            Box::pin(async move {
+                match &self.0 {
+                    Some(status) => Ok(CheckResult::new(domain.as_str(), status.clone())),
+                    None => Err(DomainCheckerError::Timeout),
+                }
+            })
+        }
+    }
+
+    fn backends(statuses: Vec<Option<DomainStatus>>) -> Vec<Arc<dyn CheckBackend>> {
+        statuses
+            .into_iter()
+            .map(|status| Arc::new(Fixed(status)) as Arc<dyn CheckBackend>)
+            .collect()
+    }
+
+    async fn combine(
+        strategy: Strategy,
+        statuses: Vec<Option<DomainStatus>>,
+    ) -> Result<CheckResult, DomainCheckerError> {
+        let domain = Domain::new("example.com").unwrap();
+        strategy.combine(&backends(statuses), &domain).await
+    }
+
+    fn error() -> Option<DomainStatus> {
+        Some(DomainStatus::Error {
+            message: "timeout".to_string(),
+        })
+    }
+
+    #[tokio::test]
+    async fn first_wins_falls_through_undecided_backends() {
+        let result = combine(Strategy::FirstWins, vec![None, error(), Some(DomainStatus::Delegated)])
+            .await
+            .unwrap();
+        assert_eq!(result.status, DomainStatus::Delegated);
+
+        // Nobody decided: the last outcome is returned
+        assert!(combine(Strategy::FirstWins, vec![error(), None]).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn confirm_positives_lets_any_backend_veto_available() {
+        let taken = Some(DomainStatus::Rdap { registered: true });
+        let result = combine(
+            Strategy::ConfirmPositives,
+            vec![Some(DomainStatus::NxDomain), None, taken],
+        )
+        .await
+        .unwrap();
+        assert_eq!(result.status, DomainStatus::Rdap { registered: true });
+
+        // Undecided confirmers don't veto
+        let result = combine(Strategy::ConfirmPositives, vec![Some(DomainStatus::NxDomain), error()])
+            .await
+            .unwrap();
+        assert!(result.available());
+
+        // Taken needs no confirmation
+        let result = combine(Strategy::ConfirmPositives, vec![Some(DomainStatus::Delegated)])
+            .await
+            .unwrap();
+        assert_eq!(result.status, DomainStatus::Delegated);
+    }
+
+    #[tokio::test]
+    async fn majority_ignores_undecided_and_breaks_ties_as_taken() {
+        let available = Some(DomainStatus::NxDomain);
+        let taken = Some(DomainStatus::Delegated);
+
+        let result = combine(
+            Strategy::Majority,
+            vec![available.clone(), taken.clone(), available.clone(), None],
+        )
+        .await
+        .unwrap();
+        assert!(result.available());
+
+        let result = combine(Strategy::Majority, vec![available, taken, error()]).await.unwrap();
+        assert!(!result.available());
+    }
+}
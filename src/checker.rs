@@ -6,7 +6,8 @@
 //! # How It Works
 //!
 //! 1. Extract TLD from domain (e.g., "com" from "example.com")
-//! 2. Look up authoritative nameservers for that TLD (from pre-resolved IP list)
+//! 2. Look up authoritative nameservers for that TLD (from pre-resolved IP list, or a
+//!    [`Registry`] loaded at runtime)
 //! 3. Query those nameservers for NS records of the domain
 //! 4. If no NS records exist → domain appears available
 //! 5. If NS records exist → domain is registered
@@ -20,29 +21,208 @@
 //! # Fallback Strategy
 //!
 //! When authoritative servers fail:
-//! 1. Try all configured authoritative servers for the TLD (up to 3)
+//! 1. Try all configured authoritative servers for the TLD (up to 3), healthiest first
+//!    (see [`crate::health`]); servers that keep failing are sidelined and tried last.
+//!    Timeouts and SERVFAIL are retried on the same server first, with backoff
+//!    (see [`RetryPolicy`])
 //! 2. If all fail, fall back to system DNS resolver
 //! 3. If system resolver fails, return error
 //!
 //! This ensures maximum reliability while preferring authoritative answers.
+//!
+//! # Backends
+//!
+//! DNS is the default [`CheckBackend`]; [`CheckerBuilder::backend`] adds others, and a
+//! [`Strategy`] combines their verdicts. [`CheckerBuilder::rdap`] is a shortcut for the
+//! registry's RDAP server: either confirming domains DNS reports as available
+//! ([`RdapMode::Verify`]), or asked first, with DNS as the fallback for TLDs without RDAP
+//! ([`RdapMode::Primary`]).
+//!
+//! # DNSSEC
+//!
+//! With [`CheckerBuilder::dnssec`], queries set the DO bit and every NXDOMAIN from an
+//! authoritative server is validated: [`CheckResult::dnssec`] says whether signed NSEC or
+//! NSEC3 records, with keys chained to the root trust anchor, prove the name absent (see
+//! [`crate::dnssec`]). Each zone's DS and DNSKEY records are fetched once per checker.
+//!
+//! [`Checker::zone_walk`] lists the registered names of a zone signed with plain NSEC, which
+//! for a sweep over such a TLD is far cheaper than one NS query per candidate.
+//!
+//! # Rate Limiting
+//!
+//! Besides the concurrency cap (`max_parallel`), queries are paced per TLD and per server IP
+//! with token buckets (see [`CheckerBuilder::qps`] and [`CheckerBuilder::qps_per_server`]),
+//! so a wide run against one registry doesn't get us rate-limited or blocked.
 
 use crate::DomainCheckerError;
-use crate::dns_pipelined::{DnsError, PipelinedDnsClient};
-use crate::tld_registry::get_tld_info;
+use crate::backend::{CheckBackend, RdapBackend, Strategy};
+use crate::dns_cache::DEFAULT_CACHE_SIZE;
+use crate::dns_message::{TYPE_A, TYPE_DNSKEY, TYPE_DS, TYPE_NSEC, TYPE_NSEC3};
+use crate::dns_pipelined::{DEFAULT_EDNS_UDP_SIZE, DnsClientConfig, DnsError, NsAnswer, PipelinedDnsClient};
+use crate::dns_ratelimit::{DEFAULT_QPS_PER_SERVER, DEFAULT_QPS_PER_TLD, RateLimitConfig};
+use crate::dnssec::{self, DnssecStatus, ZoneKeys};
+use crate::domain::{Domain, to_ascii};
+use crate::health::{HealthTracker, ServerHealth};
+use crate::psl;
+use crate::rdap::{RdapClient, RdapInfo, RdapMode};
+use crate::registry::{DEFAULT_TIMEOUT_MS, Registry};
+use crate::retry::RetryPolicy;
+use crate::transport::ResolverTransport;
+use crate::whois::WhoisVerdict;
+use crate::zone_walk::{self, ZoneWalkError};
+use dashmap::DashMap;
+use futures::future::BoxFuture;
 use futures::stream::{self, Stream, StreamExt};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
-use tokio::sync::Semaphore;
+use tokio::sync::{OnceCell, Semaphore};
 use tracing::{debug, info, warn};
 
+/// What the DNS answer says about a domain.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DomainStatus {
+    /// The TLD's authoritative server answered NXDOMAIN: the name is not in the zone.
+    NxDomain,
+    /// NOERROR with NS records for the name: registered and delegated.
+    Delegated,
+    /// NOERROR without NS records for the name: the name exists in the zone but isn't
+    /// delegated (e.g. registered without nameservers).
+    NoData,
+    /// No authoritative server answered; the fallback resolver did. `exists` is false on NXDOMAIN.
+    Resolver { exists: bool },
+    /// Decided by the registry's RDAP server (HTTP 200 vs 404), see [`RdapMode::Primary`].
+    Rdap { registered: bool },
+    /// Decided by the registry's WHOIS server, see [`crate::backend::WhoisBackend`].
+    Whois { registered: bool },
+    /// The registry's rules rule the name out (see [`Domain::validate_for_tld`]), so it
+    /// wasn't queried.
+    Unregistrable { reason: String },
+    /// Neither the authoritative servers nor the fallback resolver produced an answer.
+    Error { message: String },
+}
+
+impl DomainStatus {
+    /// Whether this status means the domain appears available.
+    pub fn is_available(&self) -> bool {
+        matches!(
+            self,
+            DomainStatus::NxDomain
+                | DomainStatus::Resolver { exists: false }
+                | DomainStatus::Rdap { registered: false }
+                | DomainStatus::Whois { registered: false }
+        )
+    }
+
+    /// Whether the check produced no verdict.
+    pub fn is_error(&self) -> bool {
+        matches!(self, DomainStatus::Error { .. })
+    }
+
+    /// Short machine-readable name, as used in CSV exports.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DomainStatus::NxDomain => "nxdomain",
+            DomainStatus::Delegated => "delegated",
+            DomainStatus::NoData => "nodata",
+            DomainStatus::Resolver { exists: false } => "resolver_nxdomain",
+            DomainStatus::Resolver { exists: true } => "resolver_exists",
+            DomainStatus::Rdap { registered: false } => "rdap_available",
+            DomainStatus::Rdap { registered: true } => "rdap_registered",
+            DomainStatus::Whois { registered: false } => "whois_available",
+            DomainStatus::Whois { registered: true } => "whois_registered",
+            DomainStatus::Unregistrable { .. } => "unregistrable",
+            DomainStatus::Error { .. } => "error",
+        }
+    }
+}
+
 /// Result of a domain availability check
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CheckResult {
     pub domain: String,
-    /// Whether the domain appears available (no NS records found).
-    /// Note: This may be a false positive if the domain is registered without nameservers.
-    pub available: bool,
+    pub status: DomainStatus,
+    /// IP address (or RDAP base URL, or WHOIS host) of the server whose answer decided the status
+    /// (`None` on error).
+    pub server: Option<String>,
+    /// Response code of that answer, including EDNS extended bits.
+    pub rcode: Option<u16>,
+    /// Whether that answer had the AA (authoritative answer) flag set.
+    pub authoritative: bool,
+    /// Round-trip time of the deciding query in milliseconds.
+    pub rtt_ms: Option<f64>,
+    /// Number of DNS queries this check took, counting retries and the resolver fallback.
+    pub attempts: u32,
+    /// Nameserver hostnames a taken domain is delegated to (lowercase, no trailing dot).
+    /// Empty for available domains, or when the answer carried no NS records for the name.
+    pub nameservers: Vec<String>,
+    /// WHOIS verdict, if the result went through [`crate::WhoisClient::verify_available`] or
+    /// came from a [`crate::WhoisBackend`].
+    pub whois: Option<WhoisVerdict>,
+    /// Registration record from RDAP, if RDAP was asked (see [`RdapMode`]).
+    pub rdap: Option<RdapInfo>,
+    /// DNSSEC validation of an authoritative NXDOMAIN, if [`CheckerBuilder::dnssec`] is on.
+    pub dnssec: Option<DnssecStatus>,
+}
+
+impl CheckResult {
+    /// Whether the domain appears available (NXDOMAIN), and neither WHOIS nor RDAP, if asked,
+    /// found a registration.
+    /// Note: Without verification this may be a false positive, e.g. for a domain on hold.
+    pub fn available(&self) -> bool {
+        self.status.is_available()
+            && self.whois != Some(WhoisVerdict::Registered)
+            && !self.rdap.as_ref().is_some_and(|rdap| rdap.registered)
+    }
+
+    /// The domain in Unicode, if it's internationalized: `münchen.de` for
+    /// `xn--mnchen-3ya.de`. `domain` always holds the A-label form that was queried.
+    pub fn unicode_domain(&self) -> Option<String> {
+        crate::domain::to_unicode(&self.domain)
+    }
+
+    /// A result with only the domain and status set, e.g. for a custom [`CheckBackend`].
+    pub fn new(domain: impl Into<String>, status: DomainStatus) -> Self {
+        Self {
+            domain: domain.into(),
+            status,
+            server: None,
+            rcode: None,
+            authoritative: false,
+            rtt_ms: None,
+            attempts: 0,
+            nameservers: Vec::new(),
+            whois: None,
+            rdap: None,
+            dnssec: None,
+        }
+    }
+
+    fn failed(domain: String, attempts: u32, message: String) -> Self {
+        Self {
+            attempts,
+            ..Self::new(domain, DomainStatus::Error { message })
+        }
+    }
+}
+
+/// Where the deciding answer came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AnswerSource {
+    Authoritative,
+    Resolver,
+}
+
+fn status_from_answer(answer: &NsAnswer, source: AnswerSource) -> DomainStatus {
+    match (source, answer.rcode) {
+        (AnswerSource::Resolver, rcode) => DomainStatus::Resolver { exists: rcode != 3 },
+        (AnswerSource::Authoritative, 3) => DomainStatus::NxDomain,
+        (AnswerSource::Authoritative, _) if !answer.nameservers.is_empty() => DomainStatus::Delegated,
+        (AnswerSource::Authoritative, _) => DomainStatus::NoData,
+    }
 }
 
 /// Domain availability checker using DNS NS record queries
@@ -51,10 +231,45 @@ pub struct CheckResult {
 /// - Clone shares the same semaphore to maintain global parallelism limit across clones
 #[derive(Clone)]
 pub struct Checker {
-    dns_client: Arc<PipelinedDnsClient>,
+    /// The DNS backend, kept for its statistics; `None` if DNS was turned off.
+    dns: Option<Arc<DnsBackend>>,
+    backends: Arc<[Arc<dyn CheckBackend>]>,
+    strategy: Strategy,
     max_parallel: usize,
+    /// Limits checks in flight, whichever backends they use, to `max_parallel`.
     semaphore: Arc<Semaphore>,
+    registry: Registry,
+}
+
+/// The default backend: NS queries against the TLD's authoritative servers.
+pub(crate) struct DnsBackend {
+    dns_client: PipelinedDnsClient,
+    health: HealthTracker,
+    retry_policy: RetryPolicy,
     timeout_ms: u64,
+    registry: Registry,
+    /// Servers from [`CheckerBuilder::authoritative_override`] by TLD or suffix, which win
+    /// over the registry's.
+    authoritative_overrides: HashMap<String, Arc<[String]>>,
+    /// Where the answer cache is persisted, if anywhere.
+    cache_file: Option<PathBuf>,
+    /// Whether NXDOMAIN answers are validated with DNSSEC.
+    dnssec: bool,
+    /// Whether a recursive resolver is asked when the authoritative servers can't answer.
+    resolver_fallback: bool,
+    /// Validated keys per zone (`""` for the root), fetched once.
+    zone_keys: DashMap<String, Arc<OnceCell<ZoneKeys>>>,
+    /// Nameserver IPs of second-level suffixes (`co.uk`) looked up so far; `None` when the
+    /// TLD's servers gave no usable addresses and are used instead.
+    suffix_servers: DashMap<String, Option<Arc<[String]>>>,
+}
+
+/// Where a backend goes in the checker's list; DNS is only created at build time.
+enum BackendSlot {
+    Dns,
+    /// Set by [`CheckerBuilder::rdap`], which replaces it rather than adding another.
+    Rdap(Arc<RdapBackend>),
+    Custom(Arc<dyn CheckBackend>),
 }
 
 /// Builder for constructing a Checker with custom settings
@@ -62,6 +277,21 @@ pub struct CheckerBuilder {
     max_parallel: usize,
     timeout_ms: u64,
     cache_ttl: Duration,
+    cache_size: usize,
+    cache_file: Option<PathBuf>,
+    udp_sockets: usize,
+    qps_per_tld: u32,
+    qps_per_server: u32,
+    retry_policy: RetryPolicy,
+    edns_udp_size: Option<u16>,
+    dnssec: bool,
+    resolver_transport: ResolverTransport,
+    resolvers: Vec<String>,
+    resolver_fallback: bool,
+    authoritative_overrides: Vec<(String, Vec<String>)>,
+    backends: Vec<BackendSlot>,
+    strategy: Strategy,
+    registry: Registry,
 }
 
 impl Default for CheckerBuilder {
@@ -70,6 +300,21 @@ impl Default for CheckerBuilder {
             max_parallel: 100,
             timeout_ms: 500,
             cache_ttl: Duration::from_secs(300),
+            cache_size: DEFAULT_CACHE_SIZE,
+            cache_file: None,
+            udp_sockets: 4,
+            qps_per_tld: DEFAULT_QPS_PER_TLD,
+            qps_per_server: DEFAULT_QPS_PER_SERVER,
+            retry_policy: RetryPolicy::default(),
+            edns_udp_size: Some(DEFAULT_EDNS_UDP_SIZE),
+            dnssec: false,
+            resolver_transport: ResolverTransport::Udp,
+            resolvers: Vec::new(),
+            resolver_fallback: true,
+            authoritative_overrides: Vec::new(),
+            backends: vec![BackendSlot::Dns],
+            strategy: Strategy::FirstWins,
+            registry: Registry::default(),
         }
     }
 }
@@ -115,7 +360,10 @@ impl CheckerBuilder {
         Ok(self)
     }
 
-    /// Set the DNS cache TTL (time-to-live).
+    /// Set the longest time a DNS answer is cached (5 minutes by default).
+    ///
+    /// Answers are cached for their own TTL up to this: a delegation's NS TTL, or the SOA's
+    /// negative TTL for NXDOMAIN and NODATA. Negative answers without an SOA aren't cached.
     ///
     /// # Errors
     ///
@@ -135,17 +383,407 @@ impl CheckerBuilder {
         Ok(self)
     }
 
+    /// Set how many DNS answers are cached before the least recently used are evicted.
+    ///
+    /// Defaults to 100,000.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if cache_size is 0 or exceeds 10,000,000.
+    pub fn cache_size(mut self, cache_size: usize) -> Result<Self, DomainCheckerError> {
+        if cache_size == 0 {
+            return Err(DomainCheckerError::Internal(
+                "cache_size must be at least 1".to_string(),
+            ));
+        }
+        if cache_size > 10_000_000 {
+            return Err(DomainCheckerError::Internal(
+                "cache_size cannot exceed 10,000,000".to_string(),
+            ));
+        }
+        self.cache_size = cache_size;
+        Ok(self)
+    }
+
+    /// Persist the DNS answer cache in `path` (off by default).
+    ///
+    /// Unexpired answers saved there are loaded at build time, so a rerun skips domains
+    /// whose answers still hold; [`Checker::save_cache`] writes the cache back. A cache file
+    /// that can't be read is ignored with a warning. [`crate::cache_dir`] is the usual place.
+    pub fn cache_file(mut self, path: impl Into<PathBuf>) -> Result<Self, DomainCheckerError> {
+        self.cache_file = Some(path.into());
+        Ok(self)
+    }
+
+    /// Set the number of UDP sockets queries are spread across.
+    ///
+    /// Each socket has its own 16-bit transaction ID space, so more sockets allow more
+    /// queries in flight to the same server without ID reuse.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if udp_sockets is 0 or exceeds 64.
+    pub fn udp_sockets(mut self, udp_sockets: usize) -> Result<Self, DomainCheckerError> {
+        if udp_sockets == 0 {
+            return Err(DomainCheckerError::Internal(
+                "udp_sockets must be at least 1".to_string(),
+            ));
+        }
+        if udp_sockets > 64 {
+            return Err(DomainCheckerError::Internal("udp_sockets cannot exceed 64".to_string()));
+        }
+        self.udp_sockets = udp_sockets;
+        Ok(self)
+    }
+
+    /// Set the maximum queries per second sent to all servers of one TLD combined.
+    ///
+    /// Defaults to 1,000. Queries over the limit wait in the send queue; one that waits longer
+    /// than its timeout fails with a rate limit error.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if qps is 0 or exceeds 100,000.
+    pub fn qps(mut self, qps: u32) -> Result<Self, DomainCheckerError> {
+        if qps == 0 {
+            return Err(DomainCheckerError::Internal("qps must be at least 1".to_string()));
+        }
+        if qps > 100_000 {
+            return Err(DomainCheckerError::Internal("qps cannot exceed 100,000".to_string()));
+        }
+        self.qps_per_tld = qps;
+        Ok(self)
+    }
+
+    /// Set the maximum queries per second sent to a single nameserver IP.
+    ///
+    /// Defaults to 250.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if qps_per_server is 0 or exceeds 100,000.
+    pub fn qps_per_server(mut self, qps_per_server: u32) -> Result<Self, DomainCheckerError> {
+        if qps_per_server == 0 {
+            return Err(DomainCheckerError::Internal(
+                "qps_per_server must be at least 1".to_string(),
+            ));
+        }
+        if qps_per_server > 100_000 {
+            return Err(DomainCheckerError::Internal(
+                "qps_per_server cannot exceed 100,000".to_string(),
+            ));
+        }
+        self.qps_per_server = qps_per_server;
+        Ok(self)
+    }
+
+    /// Set how each authoritative server is retried before moving on to the next one.
+    ///
+    /// Defaults to [`RetryPolicy::default`]; use [`RetryPolicy::none`] for a single query per server.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the policy allows 0 or more than 10 attempts per server, or a
+    /// backoff above 10 seconds.
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Result<Self, DomainCheckerError> {
+        retry_policy.validate()?;
+        self.retry_policy = retry_policy;
+        Ok(self)
+    }
+
+    /// Set the UDP payload size advertised in the EDNS0 OPT record of every query.
+    ///
+    /// Defaults to 1232 bytes. A larger size means fewer truncated answers that must be
+    /// retried over TCP; `None` sends plain queries without EDNS0 for servers that reject it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the size is below 512 or exceeds 4096.
+    pub fn edns_udp_size(mut self, edns_udp_size: Option<u16>) -> Result<Self, DomainCheckerError> {
+        if let Some(size) = edns_udp_size {
+            if size < 512 {
+                return Err(DomainCheckerError::Internal(
+                    "edns_udp_size must be at least 512".to_string(),
+                ));
+            }
+            if size > 4096 {
+                return Err(DomainCheckerError::Internal(
+                    "edns_udp_size cannot exceed 4096".to_string(),
+                ));
+            }
+        }
+        self.edns_udp_size = edns_udp_size;
+        Ok(self)
+    }
+
+    /// Validate NXDOMAIN answers with DNSSEC (off by default).
+    ///
+    /// Queries set the DO bit, and every NXDOMAIN from an authoritative server gets a
+    /// [`CheckResult::dnssec`] status: [`DnssecStatus::ProvenAbsent`] when signed NSEC or
+    /// NSEC3 records, with keys chained to the root trust anchor, rule the name out. This
+    /// costs bigger answers, plus a DS and a DNSKEY lookup per zone.
+    ///
+    /// Needs EDNS0: [`CheckerBuilder::build`] fails if `edns_udp_size` is `None`.
+    pub fn dnssec(mut self, enabled: bool) -> Result<Self, DomainCheckerError> {
+        self.dnssec = enabled;
+        Ok(self)
+    }
+
+    /// Set how the fallback resolver is reached when a TLD's authoritative servers all fail.
+    ///
+    /// Defaults to [`ResolverTransport::Udp`], the system's resolvers in the clear.
+    /// [`ResolverTransport::Tls`] and [`ResolverTransport::Https`] send those queries to the
+    /// given upstreams over DNS over TLS or DNS over HTTPS instead, trying each in turn.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the list of upstreams is empty, or a DNS-over-HTTPS URL isn't
+    /// `https://`.
+    pub fn resolver_transport(mut self, transport: ResolverTransport) -> Result<Self, DomainCheckerError> {
+        match &transport {
+            ResolverTransport::Udp => {}
+            ResolverTransport::Tls(upstreams) if upstreams.is_empty() => {
+                return Err(DomainCheckerError::Internal(
+                    "at least one DNS-over-TLS upstream is required".to_string(),
+                ));
+            }
+            ResolverTransport::Tls(_) => {}
+            ResolverTransport::Https(urls) => {
+                if urls.is_empty() {
+                    return Err(DomainCheckerError::Internal(
+                        "at least one DNS-over-HTTPS URL is required".to_string(),
+                    ));
+                }
+                if let Some(url) = urls.iter().find(|url| !url.starts_with("https://")) {
+                    return Err(DomainCheckerError::Internal(format!(
+                        "DNS-over-HTTPS URL must start with https://: {url}"
+                    )));
+                }
+            }
+        }
+        self.resolver_transport = transport;
+        Ok(self)
+    }
+
+    /// Ask these resolvers, instead of the system's, when a TLD's authoritative servers all fail.
+    ///
+    /// Each is an IP address, optionally with a port (`10.0.0.53`, `10.0.0.53:5353`,
+    /// `[2001:db8::53]:53`), and is queried over plain DNS in the order given. Can't be
+    /// combined with an encrypted [`CheckerBuilder::resolver_transport`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the list is empty or an entry isn't an IP address.
+    pub fn resolvers<S: AsRef<str>>(mut self, resolvers: &[S]) -> Result<Self, DomainCheckerError> {
+        self.resolvers = server_addresses("resolvers", resolvers)?;
+        Ok(self)
+    }
+
+    /// Whether to ask a recursive resolver when a TLD's authoritative servers all fail, or it
+    /// has none (on by default).
+    ///
+    /// With the fallback off, such a check fails instead, so every answer comes from the
+    /// authoritative servers themselves.
+    pub fn resolver_fallback(mut self, enabled: bool) -> Result<Self, DomainCheckerError> {
+        self.resolver_fallback = enabled;
+        Ok(self)
+    }
+
+    /// Query `servers` for names under `tld` (or a second-level suffix like `co.uk`), instead
+    /// of the nameservers the registry lists for it.
+    ///
+    /// Servers are IP addresses, optionally with a port, as for
+    /// [`CheckerBuilder::resolvers`]. The rest of the registry entry (timeout, registration
+    /// rules, WHOIS and RDAP servers) is kept, whichever [`CheckerBuilder::registry`] is set.
+    /// Calling this again for the same TLD replaces its servers.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `tld` isn't a valid name, or `servers` is empty or holds anything
+    /// but IP addresses.
+    pub fn authoritative_override<S: AsRef<str>>(
+        mut self,
+        tld: &str,
+        servers: &[S],
+    ) -> Result<Self, DomainCheckerError> {
+        let name = to_ascii(tld.trim_start_matches('.').trim_end_matches('.'))
+            .filter(|name| !name.is_empty())
+            .ok_or_else(|| DomainCheckerError::Internal(format!("invalid TLD for authoritative_override: '{tld}'")))?;
+        let servers = server_addresses("authoritative_override servers", servers)?;
+        self.authoritative_overrides.retain(|(overridden, _)| *overridden != name);
+        self.authoritative_overrides.push((name, servers));
+        Ok(self)
+    }
+
+    /// Set how RDAP is used alongside DNS (off by default).
+    ///
+    /// `client` sets the RDAP timeout, the [bootstrap download](RdapClient::bootstrap_fallback),
+    /// or a fixed base URL for testing; `None` keeps the default client, which uses the
+    /// registry's RDAP server for each TLD.
+    ///
+    /// [`RdapMode::Verify`] appends an [`RdapBackend`] and sets [`Strategy::ConfirmPositives`];
+    /// [`RdapMode::Primary`] puts it first and sets [`Strategy::FirstWins`]. Calling this
+    /// again replaces the RDAP backend, and [`RdapMode::Off`] removes it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the client's timeout is 0 or exceeds 60 seconds.
+    pub fn rdap(mut self, mode: RdapMode, client: Option<RdapClient>) -> Result<Self, DomainCheckerError> {
+        let client = client.unwrap_or_default();
+        if client.timeout_duration().is_zero() {
+            return Err(DomainCheckerError::Internal(
+                "RDAP timeout must be greater than 0".to_string(),
+            ));
+        }
+        if client.timeout_duration() > Duration::from_secs(60) {
+            return Err(DomainCheckerError::Internal(
+                "RDAP timeout cannot exceed 60 seconds".to_string(),
+            ));
+        }
+
+        self.backends.retain(|slot| !matches!(slot, BackendSlot::Rdap(_)));
+        let backend = BackendSlot::Rdap(Arc::new(RdapBackend::new(client)));
+        match mode {
+            RdapMode::Off => {}
+            RdapMode::Verify => {
+                self.backends.push(backend);
+                self.strategy = Strategy::ConfirmPositives;
+            }
+            RdapMode::Primary => {
+                self.backends.insert(0, backend);
+                self.strategy = Strategy::FirstWins;
+            }
+        }
+        Ok(self)
+    }
+
+    /// Add a backend after those already configured (by default just DNS).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if more than 8 backends would be configured.
+    pub fn backend(mut self, backend: impl CheckBackend + 'static) -> Result<Self, DomainCheckerError> {
+        if self.backends.len() >= 8 {
+            return Err(DomainCheckerError::Internal(
+                "cannot configure more than 8 check backends".to_string(),
+            ));
+        }
+        self.backends.push(BackendSlot::Custom(Arc::new(backend)));
+        Ok(self)
+    }
+
+    /// Whether to use the DNS backend (on by default).
+    ///
+    /// Turn it off to check with custom backends only, e.g. a mock in tests that must not
+    /// touch the network. The DNS settings above are then ignored.
+    pub fn dns(mut self, enabled: bool) -> Result<Self, DomainCheckerError> {
+        let has_dns = self.backends.iter().any(|slot| matches!(slot, BackendSlot::Dns));
+        if !enabled {
+            self.backends.retain(|slot| !matches!(slot, BackendSlot::Dns));
+        } else if !has_dns {
+            self.backends.insert(0, BackendSlot::Dns);
+        }
+        Ok(self)
+    }
+
+    /// Set how the verdicts of several backends combine ([`Strategy::FirstWins`] by default).
+    pub fn strategy(mut self, strategy: Strategy) -> Result<Self, DomainCheckerError> {
+        self.strategy = strategy;
+        Ok(self)
+    }
+
+    /// Set the TLD registry data: nameservers, timeouts and registration rules per TLD.
+    ///
+    /// Defaults to the compiled-in table; a [`Registry`] loaded from IANA's root zone or a
+    /// dotchk dump overrides or extends it.
+    pub fn registry(mut self, registry: Registry) -> Result<Self, DomainCheckerError> {
+        self.registry = registry;
+        Ok(self)
+    }
+
+    /// # Errors
+    ///
+    /// Returns an error if no backend is configured, DNSSEC is on without EDNS0, resolvers
+    /// are set along with an encrypted resolver transport, or the DNS client can't bind its
+    /// sockets.
     pub async fn build(self) -> Result<Checker, DomainCheckerError> {
-        let client = PipelinedDnsClient::new("0.0.0.0:0", self.cache_ttl).await?;
+        if self.backends.is_empty() {
+            return Err(DomainCheckerError::Internal(
+                "at least one check backend is required".to_string(),
+            ));
+        }
+        if self.dnssec && self.edns_udp_size.is_none() {
+            return Err(DomainCheckerError::Internal(
+                "dnssec needs EDNS0, but edns_udp_size is None".to_string(),
+            ));
+        }
+
+        if !self.resolvers.is_empty() && self.resolver_transport != ResolverTransport::Udp {
+            return Err(DomainCheckerError::Internal(
+                "resolvers are queried over plain DNS and can't be combined with an encrypted resolver_transport"
+                    .to_string(),
+            ));
+        }
 
-        // Don't pre-resolve all nameservers at startup - it's too slow
-        // They'll be resolved on-demand and cached
+        let mut dns = None;
+        let mut backends: Vec<Arc<dyn CheckBackend>> = Vec::with_capacity(self.backends.len());
+        for slot in self.backends {
+            match slot {
+                BackendSlot::Dns => {
+                    let config = DnsClientConfig {
+                        cache_ttl: self.cache_ttl,
+                        cache_size: self.cache_size,
+                        socket_count: self.udp_sockets,
+                        rate_limit: RateLimitConfig {
+                            qps_per_tld: self.qps_per_tld,
+                            qps_per_server: self.qps_per_server,
+                        },
+                        edns_udp_size: self.edns_udp_size,
+                        dnssec_ok: self.dnssec,
+                        resolver_transport: self.resolver_transport.clone(),
+                        resolvers: self.resolvers.clone(),
+                    };
+                    // Don't pre-resolve all nameservers at startup - it's too slow
+                    // They'll be resolved on-demand and cached
+                    let dns_client = PipelinedDnsClient::new("0.0.0.0:0", config).await?;
+                    if let Some(path) = &self.cache_file {
+                        match dns_client.load_cache(path) {
+                            Ok(count) => debug!("Loaded {} cached answers from {}", count, path.display()),
+                            Err(e) => warn!("Ignoring DNS cache {}: {}", path.display(), e),
+                        }
+                    }
+                    let backend = Arc::new(DnsBackend {
+                        dns_client,
+                        health: HealthTracker::new(),
+                        retry_policy: self.retry_policy.clone(),
+                        timeout_ms: self.timeout_ms,
+                        registry: self.registry.clone(),
+                        authoritative_overrides: self
+                            .authoritative_overrides
+                            .iter()
+                            .map(|(name, servers)| (name.clone(), Arc::from(servers.as_slice())))
+                            .collect(),
+                        cache_file: self.cache_file.clone(),
+                        dnssec: self.dnssec,
+                        resolver_fallback: self.resolver_fallback,
+                        zone_keys: DashMap::new(),
+                        suffix_servers: DashMap::new(),
+                    });
+                    dns = Some(backend.clone());
+                    backends.push(backend);
+                }
+                BackendSlot::Rdap(backend) => backends.push(backend),
+                BackendSlot::Custom(backend) => backends.push(backend),
+            }
+        }
 
         Ok(Checker {
-            dns_client: Arc::new(client),
+            dns,
+            backends: backends.into(),
+            strategy: self.strategy,
             max_parallel: self.max_parallel,
             semaphore: Arc::new(Semaphore::new(self.max_parallel)),
-            timeout_ms: self.timeout_ms,
+            registry: self.registry,
         })
     }
 }
@@ -155,6 +793,53 @@ impl Checker {
         CheckerBuilder::default()
     }
 
+    /// Number of DNS responses dropped so far because they didn't answer any pending query:
+    /// an unexpected source address, a different echoed question, or a late reply.
+    pub fn mismatched_responses(&self) -> u64 {
+        self.dns.as_ref().map_or(0, |dns| dns.dns_client.mismatched_responses())
+    }
+
+    /// Health of every authoritative server queried so far, least reliable first.
+    ///
+    /// Useful to spot registry nameservers that are timing out or answering slowly;
+    /// sidelined servers are only tried after all healthy ones for a TLD.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dotchk::Checker;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let checker = Checker::builder().build().await?;
+    /// checker.check("example.com").await?;
+    ///
+    /// for health in checker.server_health() {
+    ///     println!("{}: score {:.2}, sidelined: {}", health.server, health.score, health.sidelined);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn server_health(&self) -> Vec<ServerHealth> {
+        self.dns.as_ref().map(|dns| dns.health.snapshot()).unwrap_or_default()
+    }
+
+    /// Write the DNS answer cache to the file set with [`CheckerBuilder::cache_file`].
+    ///
+    /// Returns how many answers were saved; 0 if no cache file is set or DNS is off.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can't be written.
+    pub fn save_cache(&self) -> Result<usize, DomainCheckerError> {
+        let Some(dns) = &self.dns else {
+            return Ok(0);
+        };
+        let Some(path) = &dns.cache_file else {
+            return Ok(0);
+        };
+        Ok(dns.dns_client.save_cache(path)?)
+    }
+
     /// Check if a single domain is available.
     ///
     /// # Important Note
@@ -178,132 +863,54 @@ impl Checker {
     ///     .await?;
     ///
     /// let result = checker.check("example.com").await?;
-    /// println!("{} - available: {}", result.domain, result.available);
+    /// println!("{} - available: {}", result.domain, result.available());
     /// # Ok(())
     /// # }
     /// ```
     pub async fn check(&self, domain: &str) -> Result<CheckResult, DomainCheckerError> {
         let start = Instant::now();
-        let domain = domain.to_lowercase();
-
-        let available = self.check_domain_internal(&domain).await?;
-
-        let result = CheckResult {
-            domain: domain.clone(),
-            available,
-        };
-
-        debug!(
-            "Checked {}: available={}, time={}ms",
-            result.domain,
-            result.available,
-            start.elapsed().as_millis()
-        );
-
-        Ok(result)
-    }
-
-    async fn check_domain_internal(&self, domain: &str) -> Result<bool, DomainCheckerError> {
-        debug!("Starting check for domain: {}", domain);
-        if !is_valid_domain(domain) {
-            debug!("Domain {} failed validation", domain);
-            return Err(DomainCheckerError::InvalidDomain(domain.to_string()));
+        let domain = Domain::new(domain).inspect_err(|_| debug!("Domain {} failed validation", domain))?;
+        if let Err(violation) = domain.validate_against(self.registry.label_policy(domain.suffix())) {
+            debug!("Not querying {}: {}", domain, violation);
+            return Ok(CheckResult::new(
+                domain.into_string(),
+                DomainStatus::Unregistrable {
+                    reason: violation.to_string(),
+                },
+            ));
         }
 
-        let tld_info = get_tld_info(domain).ok_or_else(|| DomainCheckerError::UnsupportedTld(extract_tld(domain)))?;
-        debug!("Got TLD info for {}: {} servers", domain, tld_info.servers.len());
-
-        debug!("Acquiring semaphore permit for {}", domain);
         let _permit = self.semaphore.acquire().await.map_err(|_| {
             warn!("Semaphore acquire failed for {}", domain);
             DomainCheckerError::Internal("concurrency semaphore closed unexpectedly".to_string())
         })?;
-        debug!("Acquired semaphore permit for {}", domain);
+        let result = match self.strategy.combine(&self.backends, &domain).await {
+            Ok(result) => result,
+            // The domain was checkable but nobody answered: that's a result, not a usage error
+            Err(
+                e @ (DomainCheckerError::Dns(_)
+                | DomainCheckerError::Timeout
+                | DomainCheckerError::Rdap(_)
+                | DomainCheckerError::Whois(_)),
+            ) => CheckResult::failed(domain.into_string(), 0, e.to_string()),
+            Err(e) => return Err(e),
+        };
 
-        // Use the minimum of user timeout and TLD timeout to respect user's maximum
-        let per_server_timeout = self.timeout_ms.min(tld_info.timeout_ms);
         debug!(
-            "Checking {} with {} servers, per-server timeout {}ms (checker: {}ms, tld: {}ms)",
-            domain,
-            tld_info.servers.len(),
-            per_server_timeout,
-            self.timeout_ms,
-            tld_info.timeout_ms
+            "Checked {}: status={}, attempts={}, time={}ms",
+            result.domain,
+            result.status.as_str(),
+            result.attempts,
+            start.elapsed().as_millis()
         );
 
-        // The servers are already pre-resolved IPs, so we can use them directly
-        // If there are no servers configured, fall back to local resolver
-        if tld_info.servers.is_empty() {
-            info!(
-                "No authoritative servers configured for {}, falling back to local resolver",
-                domain
-            );
-            match self.dns_client.query_local_resolver(domain).await {
-                Ok(has_records) => {
-                    info!(
-                        "Local resolver query for {} succeeded: available={}",
-                        domain, !has_records
-                    );
-                    return Ok(!has_records);
-                }
-                Err(local_err) => {
-                    warn!("Local resolver also failed for {}: {:?}", domain, local_err);
-                    return Err(DomainCheckerError::Timeout);
-                }
-            }
-        }
-
-        // Try the pre-resolved IP addresses (already limited to 3 by update_tlds.py)
-        for (i, server_ip) in tld_info.servers.iter().enumerate() {
-            debug!(
-                "Trying server {} ({}/{}) for {}",
-                server_ip,
-                i + 1,
-                tld_info.servers.len(),
-                domain
-            );
-            match self.dns_client.query_ns(domain, server_ip, per_server_timeout).await {
-                Ok(has_ns) => {
-                    debug!("Server {} succeeded for {}: has_ns={}", server_ip, domain, has_ns);
-                    return Ok(!has_ns);
-                }
-                Err(DnsError::NameError) => {
-                    debug!("Server {} returned NXDOMAIN for {}", server_ip, domain);
-                    return Ok(true);
-                }
-                Err(e) => {
-                    debug!("Server {} failed for {}: {:?}", server_ip, domain, e);
-                    if i == tld_info.servers.len() - 1 {
-                        warn!(
-                            "All {} authoritative servers failed for {}: last error was {:?}",
-                            tld_info.servers.len(),
-                            domain,
-                            e
-                        );
-                        // All authoritative servers failed, try local resolver as fallback
-                        info!("Falling back to local resolver for {}", domain);
-                        match self.dns_client.query_local_resolver(domain).await {
-                            Ok(has_records) => {
-                                info!(
-                                    "Local resolver query for {} succeeded: available={}",
-                                    domain, !has_records
-                                );
-                                return Ok(!has_records);
-                            }
-                            Err(local_err) => {
-                                warn!("Local resolver also failed for {}: {:?}", domain, local_err);
-                                return Err(e.into());
-                            }
-                        }
-                    }
-                }
-            }
-        }
-
-        // This can happen if the TLD has no servers configured
-        Err(DomainCheckerError::UnsupportedTld(extract_tld(domain)))
+        Ok(result)
     }
 
+    /// Check domains concurrently, yielding results as they complete.
+    ///
+    /// Results arrive in completion order, not input order. A failed check yields
+    /// [`DomainCheckerError::Check`], which carries the domain it was for.
     pub fn check_stream(
         &self,
         domains: Vec<String>,
@@ -312,7 +919,7 @@ impl Checker {
             .into_iter()
             .map(|domain| {
                 let checker = self;
-                async move { checker.check(&domain).await }
+                async move { checker.check_for_batch(domain).await }
             })
             .collect();
 
@@ -321,6 +928,9 @@ impl Checker {
 
     /// Check multiple domains concurrently.
     ///
+    /// As with [`Checker::check_stream`], every `Err` is a [`DomainCheckerError::Check`]
+    /// naming its domain.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -337,8 +947,8 @@ impl Checker {
     ///
     /// for result in results {
     ///     match result {
-    ///         Ok(check) => println!("{}: {}", check.domain, if check.available { "available" } else { "taken" }),
-    ///         Err(e) => eprintln!("Error: {}", e),
+    ///         Ok(check) => println!("{}: {}", check.domain, if check.available() { "available" } else { "taken" }),
+    ///         Err(e) => eprintln!("{} failed: {}", e.domain().unwrap_or_default(), e.cause()),
     ///     }
     /// }
     /// # Ok(())
@@ -351,13 +961,492 @@ impl Checker {
             .into_iter()
             .map(|domain| {
                 let checker = self.clone();
-                async move { checker.check(&domain).await }
+                async move { checker.check_for_batch(domain).await }
             })
             .collect();
 
         // Process all checks concurrently with controlled parallelism
         stream::iter(futures).buffer_unordered(self.max_parallel).collect().await
     }
+
+    /// Walk `zone`'s NSEC chain, yielding the names registered directly below it (`abc.com`
+    /// for `com`) in canonical order; see [`crate::zone_walk`].
+    ///
+    /// One query is in flight at a time, paced by the same per-TLD and per-server rate limits
+    /// as checks. The stream ends after the last name, or with one error if the zone can't be
+    /// walked: it uses NSEC3, isn't signed, or its servers stop answering.
+    pub fn zone_walk(&self, zone: &str) -> impl Stream<Item = Result<String, DomainCheckerError>> + '_ {
+        let zone = zone.trim_end_matches('.');
+        let zone = to_ascii(zone).unwrap_or_else(|| zone.to_ascii_lowercase());
+        stream::unfold(Some(zone.clone()), move |position| {
+            let zone = zone.clone();
+            async move {
+                let position = position?;
+                let Some(dns) = &self.dns else {
+                    let error = DomainCheckerError::Internal("zone walking needs the DNS backend".to_string());
+                    return Some((Err(error), None));
+                };
+                match dns.next_registered(&zone, &position).await {
+                    Ok(Some(name)) => Some((Ok(name.clone()), Some(name))),
+                    Ok(None) => None,
+                    Err(e) => Some((Err(e.into()), None)),
+                }
+            }
+        })
+    }
+
+    /// IPv4 addresses of `host` from an A query to the resolvers checks fall back to: the
+    /// system's, or those set with [`CheckerBuilder::resolvers`] or
+    /// [`CheckerBuilder::resolver_transport`].
+    ///
+    /// Counts against `max_parallel` like a check, and is paced by the same rate limits.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if DNS is turned off or no resolver answered.
+    pub async fn resolve_ipv4(&self, host: &str) -> Result<Vec<Ipv4Addr>, DomainCheckerError> {
+        let Some(dns) = &self.dns else {
+            return Err(DomainCheckerError::Internal(
+                "resolving hosts needs the DNS backend".to_string(),
+            ));
+        };
+        let _permit = self
+            .semaphore
+            .acquire()
+            .await
+            .map_err(|_| DomainCheckerError::Internal("concurrency semaphore closed unexpectedly".to_string()))?;
+
+        let answer = dns.dns_client.query_resolver(host, TYPE_A, dns.timeout_ms).await?;
+        Ok(answer
+            .addresses
+            .into_iter()
+            .filter_map(|ip| match ip {
+                IpAddr::V4(ip) => Some(ip),
+                IpAddr::V6(_) => None,
+            })
+            .collect())
+    }
+
+    /// `check`, with any error wrapped so it still names `domain` once results are reordered.
+    async fn check_for_batch(&self, domain: String) -> Result<CheckResult, DomainCheckerError> {
+        self.check(&domain).await.map_err(|source| DomainCheckerError::Check {
+            domain: domain.to_lowercase(),
+            source: Box::new(source),
+        })
+    }
+}
+
+impl DnsBackend {
+    /// Check `domain`, mapping a failure to answer into an error result.
+    async fn check_dns(&self, domain: &str) -> Result<CheckResult, DomainCheckerError> {
+        let mut attempts = 0;
+        match self.check_domain_internal(domain, &mut attempts).await {
+            Ok((answer, source)) => {
+                let status = status_from_answer(&answer, source);
+                let dnssec = match status {
+                    DomainStatus::NxDomain if self.dnssec => Some(self.validate_denial(domain, &answer).await),
+                    _ => None,
+                };
+                Ok(CheckResult {
+                    status,
+                    domain: domain.to_string(),
+                    server: answer.server.map(|server| server.to_string()),
+                    rcode: Some(answer.rcode),
+                    authoritative: answer.authoritative,
+                    rtt_ms: Some(answer.rtt.as_secs_f64() * 1000.0),
+                    attempts,
+                    nameservers: answer.nameservers,
+                    whois: None,
+                    rdap: None,
+                    dnssec,
+                })
+            }
+            Err(e @ (DomainCheckerError::Dns(_) | DomainCheckerError::Timeout)) => {
+                Ok(CheckResult::failed(domain.to_string(), attempts, e.to_string()))
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Check `domain`, adding every DNS query sent (retries and fallback included) to `attempts`.
+    async fn check_domain_internal(
+        &self,
+        domain: &str,
+        attempts: &mut u32,
+    ) -> Result<(NsAnswer, AnswerSource), DomainCheckerError> {
+        debug!("Starting check for domain: {}", domain);
+        if !is_valid_domain(domain) {
+            debug!("Domain {} failed validation", domain);
+            return Err(DomainCheckerError::InvalidDomain(domain.to_string()));
+        }
+
+        let tld_info = self.registry.tld_info(domain);
+        let (tld_servers, tld_timeout_ms) = match (self.overridden_servers(domain), tld_info) {
+            (Some(servers), info) => (&servers[..], info.map_or(DEFAULT_TIMEOUT_MS, |info| info.timeout_ms)),
+            (None, Some(info)) => (&info.servers[..], info.timeout_ms),
+            (None, None) => return Err(DomainCheckerError::UnsupportedTld(extract_tld(domain))),
+        };
+        debug!("Got TLD info for {}: {} servers", domain, tld_servers.len());
+
+        debug!(
+            "Checking {} with {} servers (checker timeout: {}ms, tld timeout: {}ms)",
+            domain,
+            tld_servers.len(),
+            self.timeout_ms,
+            tld_timeout_ms
+        );
+
+        // The servers are already pre-resolved IPs, so we can use them directly
+        // If there are no servers configured, fall back to local resolver
+        if tld_servers.is_empty() {
+            if !self.resolver_fallback {
+                return Err(DomainCheckerError::UnsupportedTld(extract_tld(domain)));
+            }
+            info!(
+                "No authoritative servers configured for {}, falling back to local resolver",
+                domain
+            );
+            *attempts += 1;
+            match self.dns_client.query_local_resolver(domain).await {
+                Ok(answer) => {
+                    info!(
+                        "Local resolver query for {} succeeded: available={}",
+                        domain, !answer.has_records
+                    );
+                    return Ok((answer, AnswerSource::Resolver));
+                }
+                Err(local_err) => {
+                    warn!("Local resolver also failed for {}: {:?}", domain, local_err);
+                    return Err(DomainCheckerError::Timeout);
+                }
+            }
+        }
+
+        // Try the pre-resolved IP addresses (already limited to 3 by update_tlds.py), or those
+        // of the domain's second-level suffix, healthiest first.
+        // Each server gets up to `attempts_per_server` queries for errors the retry policy allows
+        let suffix_servers = self.zone_servers(domain, tld_servers, tld_timeout_ms).await;
+        let zone_servers: Vec<&str> = match &suffix_servers {
+            Some(servers) => servers.iter().map(String::as_str).collect(),
+            None => tld_servers.iter().map(String::as_str).collect(),
+        };
+        let servers = self.health.order(&zone_servers);
+        let mut last_error = None;
+        for (i, server_ip) in servers.iter().enumerate() {
+            let mut attempt = 0;
+            loop {
+                attempt += 1;
+                *attempts += 1;
+                // Derived from the server's measured RTT; the TLD timeout only applies until it has answered once.
+                // Either way the user's timeout is the upper bound.
+                let per_server_timeout = self.dns_client.server_timeout(server_ip, tld_timeout_ms, self.timeout_ms);
+                debug!(
+                    "Trying server {} ({}/{}, attempt {}) for {} with timeout {}ms",
+                    server_ip,
+                    i + 1,
+                    servers.len(),
+                    attempt,
+                    domain,
+                    per_server_timeout
+                );
+                match self.dns_client.query_ns(domain, server_ip, per_server_timeout).await {
+                    Ok(answer) => {
+                        debug!(
+                            "Server {} succeeded for {}: has_ns={}, nameservers={:?}",
+                            server_ip, domain, answer.has_records, answer.nameservers
+                        );
+                        // Only the time on the wire: not waiting for our own rate limit, and
+                        // nothing for an answer the server never sent
+                        if !answer.cached {
+                            self.health.record_success(server_ip, answer.rtt);
+                        }
+                        return Ok((answer, AnswerSource::Authoritative));
+                    }
+                    Err(DnsError::NameError) => {
+                        debug!("Server {} returned NXDOMAIN for {}", server_ip, domain);
+                        return Ok((NsAnswer::nxdomain(), AnswerSource::Authoritative));
+                    }
+                    Err(e) => {
+                        debug!("Server {} failed for {}: {:?}", server_ip, domain, e);
+                        if self.retry_policy.should_retry(&e, attempt) {
+                            let delay = self.retry_policy.backoff(attempt);
+                            debug!("Retrying {} on {} in {}ms", domain, server_ip, delay.as_millis());
+                            tokio::time::sleep(delay).await;
+                            continue;
+                        }
+                        // One outcome per server once its retries are spent; our own throttling
+                        // says nothing about the server
+                        if !matches!(e, DnsError::RateLimited) {
+                            self.health.record_failure(server_ip);
+                        }
+                        last_error = Some(e);
+                        break;
+                    }
+                }
+            }
+        }
+
+        let Some(e) = last_error else {
+            // This can happen if the TLD has no servers configured
+            return Err(DomainCheckerError::UnsupportedTld(extract_tld(domain)));
+        };
+
+        warn!(
+            "All {} authoritative servers failed for {}: last error was {:?}",
+            servers.len(),
+            domain,
+            e
+        );
+        if !self.resolver_fallback {
+            return Err(e.into());
+        }
+        // All authoritative servers failed, try local resolver as fallback
+        info!("Falling back to local resolver for {}", domain);
+        *attempts += 1;
+        match self.dns_client.query_local_resolver(domain).await {
+            Ok(answer) => {
+                info!(
+                    "Local resolver query for {} succeeded: available={}",
+                    domain, !answer.has_records
+                );
+                Ok((answer, AnswerSource::Resolver))
+            }
+            Err(local_err) => {
+                warn!("Local resolver also failed for {}: {:?}", domain, local_err);
+                Err(e.into())
+            }
+        }
+    }
+
+    /// Servers from an [`CheckerBuilder::authoritative_override`] for `domain`'s public suffix
+    /// or, failing that, its TLD.
+    fn overridden_servers(&self, domain: &str) -> Option<&Arc<[String]>> {
+        let suffix = psl::public_suffix(domain)?;
+        let tld = suffix.rsplit('.').next()?;
+        self.authoritative_overrides
+            .get(suffix)
+            .or_else(|| self.authoritative_overrides.get(tld))
+    }
+
+    /// Servers of the zone `domain` is registered in, if that isn't the zone of `tld_servers`.
+    ///
+    /// Second-level suffixes without their own override or registry entry (`co.uk`, `com.au`)
+    /// are looked up once at the TLD's servers. Their nameservers are used when the answer
+    /// carries IPv4 glue for them; otherwise the TLD's servers, which often serve both zones, are.
+    async fn zone_servers(&self, domain: &str, tld_servers: &[String], tld_timeout_ms: u64) -> Option<Arc<[String]>> {
+        let suffix = psl::public_suffix(domain).filter(|suffix| suffix.contains('.'))?;
+        if self.authoritative_overrides.contains_key(suffix) || self.registry.entry(suffix).is_some() {
+            return None;
+        }
+        if let Some(servers) = self.suffix_servers.get(suffix) {
+            return servers.clone();
+        }
+
+        let servers: Vec<&str> = tld_servers.iter().map(String::as_str).collect();
+        for server in self.health.order(&servers) {
+            let timeout_ms = self.dns_client.server_timeout(server, tld_timeout_ms, self.timeout_ms);
+            let answer = match self.dns_client.query_ns(suffix, server, timeout_ms).await {
+                Ok(answer) => answer,
+                Err(DnsError::NameError) => NsAnswer::nxdomain(),
+                Err(e) => {
+                    debug!("Looking up servers of {} on {} failed: {:?}", suffix, server, e);
+                    continue;
+                }
+            };
+
+            let glue: Vec<String> = answer
+                .glue
+                .iter()
+                .filter(|(_, ip)| ip.is_ipv4())
+                .map(|(_, ip)| ip.to_string())
+                .take(3)
+                .collect();
+            debug!("Servers of {}: {:?} ({:?})", suffix, answer.nameservers, glue);
+            let servers = (!glue.is_empty()).then(|| Arc::from(glue));
+            self.suffix_servers.insert(suffix.to_string(), servers.clone());
+            return servers;
+        }
+
+        // Nobody answered; try again with the next domain
+        None
+    }
+
+    /// Validate an NXDOMAIN `answer` for `domain` with DNSSEC.
+    async fn validate_denial(&self, domain: &str, answer: &NsAnswer) -> DnssecStatus {
+        let Some(zone) = dnssec::signer(&answer.dnssec) else {
+            // No signatures: fine for an unsigned zone, suspicious for a signed one
+            let zone = extract_tld(domain);
+            return match self.zone_keys(&zone).await {
+                ZoneKeys::Secure(_) => DnssecStatus::Failed {
+                    reason: format!("{zone} is signed, but the answer carries no signatures"),
+                },
+                ZoneKeys::Insecure => DnssecStatus::Insecure,
+                ZoneKeys::Failed(reason) => DnssecStatus::Failed { reason },
+            };
+        };
+        if !domain.ends_with(&format!(".{zone}")) {
+            return DnssecStatus::Failed {
+                reason: format!("the denial is signed by {zone}, which isn't a parent of {domain}"),
+            };
+        }
+
+        match self.zone_keys(&zone).await {
+            ZoneKeys::Secure(keys) => dnssec::prove_nxdomain(domain, &zone, &answer.dnssec, &keys, dnssec::now()),
+            ZoneKeys::Insecure => DnssecStatus::Insecure,
+            ZoneKeys::Failed(reason) => DnssecStatus::Failed { reason },
+        }
+    }
+
+    /// `zone`'s validated keys, walking down from the root trust anchor one zone at a time.
+    ///
+    /// Each zone's outcome is kept for the checker's lifetime, except when its servers
+    /// couldn't be reached: that is retried on the next call.
+    async fn zone_keys(&self, zone: &str) -> ZoneKeys {
+        let labels: Vec<&str> = zone.split('.').filter(|label| !label.is_empty()).collect();
+        let chain =
+            std::iter::once(String::new()).chain((1..=labels.len()).map(|n| labels[labels.len() - n..].join(".")));
+
+        let mut keys = ZoneKeys::Insecure;
+        for zone in chain {
+            let cell = self.zone_keys.entry(zone.clone()).or_default().clone();
+            let parent_keys = keys;
+            keys = match cell.get_or_try_init(|| self.fetch_zone_keys(&zone, &parent_keys)).await {
+                Ok(keys) => keys.clone(),
+                Err(reason) => ZoneKeys::Failed(reason),
+            };
+            if !matches!(keys, ZoneKeys::Secure(_)) {
+                break;
+            }
+        }
+        keys
+    }
+
+    /// Fetch and validate `zone`'s keys: its DS records from the parent, signed with
+    /// `parent_keys` (the trust anchor for the root), then its own DNSKEY set.
+    ///
+    /// `Err` means a server couldn't be asked, as opposed to answers that don't validate.
+    async fn fetch_zone_keys(&self, zone: &str, parent_keys: &ZoneKeys) -> Result<ZoneKeys, String> {
+        let now = dnssec::now();
+        let ds = if zone.is_empty() {
+            dnssec::root_anchors()
+        } else {
+            let ZoneKeys::Secure(parent_keys) = parent_keys else {
+                return Ok(parent_keys.clone());
+            };
+            let parent = zone.split_once('.').map_or("", |(_, parent)| parent);
+            let answer = self.query_zone(parent, zone, TYPE_DS).await?;
+            match dnssec::validate_ds(zone, parent, &answer.dnssec, parent_keys, now) {
+                Ok(ds) if ds.is_empty() => return Ok(ZoneKeys::Insecure),
+                Ok(ds) => ds,
+                Err(reason) => return Ok(ZoneKeys::Failed(reason)),
+            }
+        };
+
+        let answer = self.query_zone(zone, zone, TYPE_DNSKEY).await?;
+        Ok(dnssec::validate_dnskeys(zone, &answer.dnssec, &ds, now))
+    }
+
+    /// Authoritative servers of `zone`: the root servers for `""`, else whatever checks have
+    /// used for it.
+    fn zone_nameservers(&self, zone: &str) -> Vec<String> {
+        if zone.is_empty() {
+            dnssec::ROOT_SERVERS.iter().map(ToString::to_string).collect()
+        } else if let Some(servers) = self.suffix_servers.get(zone).and_then(|servers| servers.clone()) {
+            servers.to_vec()
+        } else if let Some(servers) = self.authoritative_overrides.get(zone) {
+            servers.to_vec()
+        } else {
+            self.registry
+                .entry(zone)
+                .or_else(|| self.registry.tld_info(zone))
+                .map(|info| info.servers.clone())
+                .unwrap_or_default()
+        }
+    }
+
+    /// Ask up to 3 of `zone`'s servers, healthiest first, for `name`'s records of type `qtype`.
+    async fn query_zone(&self, zone: &str, name: &str, qtype: u16) -> Result<NsAnswer, String> {
+        let servers = self.zone_nameservers(zone);
+        let servers: Vec<&str> = servers.iter().map(String::as_str).collect();
+
+        let mut last_error = "no servers known".to_string();
+        for server in self.health.order(&servers).into_iter().take(3) {
+            let timeout_ms = self.dns_client.server_timeout(server, self.timeout_ms, self.timeout_ms);
+            match self.dns_client.query_signed(name, qtype, server, timeout_ms).await {
+                Ok(answer) if answer.rcode == 0 => return Ok(answer),
+                Ok(answer) => last_error = format!("{server} answered with RCODE {}", answer.rcode),
+                Err(e) => last_error = format!("{server}: {e}"),
+            }
+        }
+        let name = if name.is_empty() { "." } else { name };
+        Err(format!("couldn't look up the keys of {name}: {last_error}"))
+    }
+
+    /// The first name registered in `zone` after `name` and its subdomains (after nothing
+    /// for the apex), from the NSEC record covering a name just past them. `None` once the
+    /// chain wraps around to the apex.
+    async fn next_registered(&self, zone: &str, name: &str) -> Result<Option<String>, ZoneWalkError> {
+        let broken = |next: &str| ZoneWalkError::BrokenChain {
+            zone: zone.to_string(),
+            name: name.to_string(),
+            next: next.to_string(),
+        };
+        let probe = zone_walk::probe_after(name, zone).ok_or_else(|| broken(name))?;
+        let servers = self.zone_nameservers(zone);
+        if servers.is_empty() {
+            return Err(ZoneWalkError::NoServers(zone.to_string()));
+        }
+        let servers: Vec<&str> = servers.iter().map(String::as_str).collect();
+
+        let mut last_error = String::new();
+        for server in self.health.order(&servers).into_iter().take(3) {
+            let timeout_ms = self.dns_client.server_timeout(server, self.timeout_ms, self.timeout_ms);
+            let answer = match self.dns_client.query_signed(&probe, TYPE_NSEC, server, timeout_ms).await {
+                Ok(answer) if answer.rcode == 0 || answer.rcode == 3 => answer,
+                Ok(answer) => {
+                    last_error = format!("{server} answered with RCODE {}", answer.rcode);
+                    continue;
+                }
+                Err(e) => {
+                    last_error = format!("{server}: {e}");
+                    continue;
+                }
+            };
+
+            let Some(next) = dnssec::next_secure(&probe, &answer.dnssec) else {
+                return Err(if answer.dnssec.iter().any(|record| record.rtype == TYPE_NSEC3) {
+                    ZoneWalkError::Nsec3(zone.to_string())
+                } else {
+                    ZoneWalkError::Unsigned(zone.to_string())
+                });
+            };
+            if next == zone {
+                return Ok(None);
+            }
+            // Each step must move forward, or a bad record could send us round in circles
+            return match zone_walk::registered_name(&next, zone) {
+                Some(registered) if dnssec::canonical_cmp(&registered, name) == std::cmp::Ordering::Greater => {
+                    Ok(Some(registered))
+                }
+                _ => Err(broken(&next)),
+            };
+        }
+        Err(ZoneWalkError::Query {
+            zone: zone.to_string(),
+            name: name.to_string(),
+            reason: last_error,
+        })
+    }
+}
+
+impl CheckBackend for DnsBackend {
+    fn name(&self) -> &str {
+        "dns"
+    }
+
+    fn check<'a>(&'a self, domain: &'a Domain) -> BoxFuture<'a, Result<CheckResult, DomainCheckerError>> {
+        Box::pin(self.check_dns(domain.as_str()))
+    }
 }
 
 fn is_valid_domain(domain: &str) -> bool {
@@ -391,14 +1480,34 @@ fn is_valid_domain(domain: &str) -> bool {
     true
 }
 
+/// `servers` as strings, checked to be IP addresses with or without a port; `what` names
+/// them in the error.
+fn server_addresses<S: AsRef<str>>(what: &str, servers: &[S]) -> Result<Vec<String>, DomainCheckerError> {
+    if servers.is_empty() {
+        return Err(DomainCheckerError::Internal(format!("{what} must not be empty")));
+    }
+    servers
+        .iter()
+        .map(|server| {
+            let server = server.as_ref().trim();
+            if server.parse::<IpAddr>().is_ok() || server.parse::<SocketAddr>().is_ok() {
+                Ok(server.to_string())
+            } else {
+                Err(DomainCheckerError::Internal(format!(
+                    "{what}: '{server}' is not an IP address"
+                )))
+            }
+        })
+        .collect()
+}
+
 /// Extracts the TLD from a domain string.
 ///
-/// Returns the last component after splitting by '.', or "unknown" if extraction fails.
+/// Returns the public suffix (e.g. "co.uk"), or "unknown" if extraction fails.
 /// This function is infallible and safe to use after domain validation.
 fn extract_tld(domain: &str) -> String {
-    domain
-        .split('.')
-        .next_back()
+    psl::public_suffix(domain)
+        .or_else(|| domain.split('.').next_back())
         .filter(|tld| !tld.is_empty())
         .unwrap_or("unknown")
         .to_string()
@@ -435,7 +1544,7 @@ mod tests {
     #[test]
     fn extract_tld_returns_valid_tld() {
         assert_eq!(extract_tld("example.com"), "com");
-        assert_eq!(extract_tld("test.co.uk"), "uk");
+        assert_eq!(extract_tld("test.co.uk"), "co.uk");
         assert_eq!(extract_tld("domain.xyz"), "xyz");
     }
 
@@ -472,4 +1581,71 @@ mod tests {
         // Test all numeric TLD (should be valid for our purposes)
         assert!(is_valid_domain("test.123"));
     }
+
+    /// Says every domain is available after a short wait, tracking how many checks overlap.
+    #[derive(Clone, Default)]
+    struct SlowBackend {
+        in_flight: Arc<std::sync::atomic::AtomicUsize>,
+        peak: Arc<std::sync::atomic::AtomicUsize>,
+    }
+
+    impl CheckBackend for SlowBackend {
+        fn name(&self) -> &str {
+            "slow"
+        }
+
+        fn check<'a>(
+            &'a self,
+            domain: &'a Domain,
+        ) -> futures::future::BoxFuture<'a, Result<CheckResult, DomainCheckerError>> {
+            use std::sync::atomic::Ordering;
+            Box::pin(async move {
+                let in_flight = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                self.peak.fetch_max(in_flight, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_millis(20)).await;
+                self.in_flight.fetch_sub(1, Ordering::SeqCst);
+                Ok(CheckResult::new(domain.as_str(), DomainStatus::NxDomain))
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn max_parallel_limits_every_backend_across_clones() {
+        let backend = SlowBackend::default();
+        let checker = Checker::builder()
+            .dns(false)
+            .unwrap()
+            .max_parallel(2)
+            .unwrap()
+            .backend(backend.clone())
+            .unwrap()
+            .build()
+            .await
+            .unwrap();
+
+        let domains = |prefix: &str| (0..6).map(|i| format!("{prefix}{i}.com")).collect::<Vec<_>>();
+        let clone = checker.clone();
+        let (first, second) = tokio::join!(checker.check_batch(domains("a")), clone.check_batch(domains("b")));
+        assert!(first.iter().chain(&second).all(Result::is_ok));
+        assert_eq!(backend.peak.load(std::sync::atomic::Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn setting_rdap_again_replaces_its_backend() {
+        let builder = Checker::builder()
+            .rdap(RdapMode::Verify, None)
+            .unwrap()
+            .rdap(RdapMode::Primary, None)
+            .unwrap();
+        let rdap_slots = builder
+            .backends
+            .iter()
+            .filter(|slot| matches!(slot, BackendSlot::Rdap(_)))
+            .count();
+        assert_eq!(rdap_slots, 1);
+        assert!(matches!(builder.backends[0], BackendSlot::Rdap(_)));
+
+        let builder = builder.rdap(RdapMode::Off, None).unwrap();
+        assert!(matches!(builder.backends[..], [BackendSlot::Dns]));
+    }
 }
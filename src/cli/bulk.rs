@@ -1,18 +1,19 @@
 type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
-use dotchk::Checker;
 use futures::StreamExt;
 use std::path::PathBuf;
 use tokio::fs;
 
+use super::options::NetworkArgs;
 use super::output::{
     create_progress_bar, format_domain_error, format_domain_result, print_footer_note, print_info, print_warning,
 };
-use super::utils::{export_results, print_stats};
+use super::utils::{export_results, print_stats, print_whois_results, verify_whois};
 
 pub async fn bulk_check(
     file: PathBuf,
     parallel: usize,
     timeout: u64,
+    network: &NetworkArgs,
     available_only: bool,
     output: Option<PathBuf>,
     show_stats: bool,
@@ -31,7 +32,7 @@ pub async fn bulk_check(
 
     print_info(&format!("Loaded {} domains from {}", domains.len(), file.display()));
 
-    let checker = Checker::builder().max_parallel(parallel)?.timeout_ms(timeout)?.build().await?;
+    let checker = network.checker_builder(parallel, timeout)?.build().await?;
 
     let mut results = Vec::new();
     let pb = create_progress_bar(domains.len() as u64, "Checking domains");
@@ -43,20 +44,20 @@ pub async fn bulk_check(
 
         match &result {
             Ok(check) => {
-                if !available_only || check.available {
+                if !available_only || check.available() {
                     pb.suspend(|| {
                         println!("{}", format_domain_result(check));
                     });
                 }
 
-                if check.available {
+                if check.available() {
                     has_available = true;
                 }
             }
-            Err(_e) => {
+            Err(e) => {
                 if !available_only {
                     pb.suspend(|| {
-                        println!("{}", format_domain_error("unknown"));
+                        println!("{}", format_domain_error(e));
                     });
                 }
             }
@@ -66,6 +67,11 @@ pub async fn bulk_check(
     }
 
     pb.finish_and_clear();
+    network.save_cache(&checker);
+
+    if verify_whois(&mut results, network).await {
+        print_whois_results(&results);
+    }
 
     if has_available && !available_only {
         print_footer_note();
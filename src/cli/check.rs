@@ -1,22 +1,26 @@
 type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
-use dotchk::Checker;
 use std::path::PathBuf;
 
+use super::options::NetworkArgs;
 use super::output::create_spinner;
-use super::utils::{export_results, print_results};
+use super::utils::{export_results, print_results, verify_whois};
 
 pub async fn check_domains(
     domains: Vec<String>,
     parallel: usize,
     timeout: u64,
+    network: &NetworkArgs,
     available_only: bool,
     output: Option<PathBuf>,
 ) -> Result<()> {
-    let checker = Checker::builder().max_parallel(parallel)?.timeout_ms(timeout)?.build().await?;
+    let checker = network.checker_builder(parallel, timeout)?.build().await?;
 
     let spinner = create_spinner(&format!("Checking {} domains", domains.len()));
-    let results = checker.check_batch(domains).await;
+    let mut results = checker.check_batch(domains).await;
     spinner.finish_and_clear();
+    network.save_cache(&checker);
+
+    verify_whois(&mut results, network).await;
 
     print_results(&results, available_only);
 
@@ -1,11 +1,17 @@
 pub mod bulk;
 pub mod check;
+pub mod options;
 pub mod output;
 pub mod pattern;
+pub mod registry;
 pub mod tld;
 pub mod utils;
+pub mod zone_walk;
 
 pub use bulk::bulk_check;
 pub use check::check_domains;
+pub use options::NetworkArgs;
 pub use pattern::check_pattern;
+pub use registry::update_registry;
 pub use tld::check_tlds;
+pub use zone_walk::walk_zone;
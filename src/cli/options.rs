@@ -0,0 +1,161 @@
+type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+use clap::Args;
+use dotchk::checker::CheckerBuilder;
+use dotchk::{Checker, RdapClient, RdapMode, Registry, ResolverTransport, RetryPolicy, TlsUpstream};
+use std::path::PathBuf;
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use super::output::print_warning;
+
+/// The registry from `--registry` or the cache, loaded on first use; loaded entries live for the process.
+static REGISTRY: OnceLock<Registry> = OnceLock::new();
+
+/// Network flags shared by every command.
+#[derive(Args, Debug, Clone)]
+pub struct NetworkArgs {
+    /// Maximum queries per second to all nameservers of one TLD combined
+    #[arg(long, global = true, default_value = "1000")]
+    pub qps: u32,
+
+    /// Maximum queries per second to a single nameserver
+    #[arg(long, global = true, default_value = "250")]
+    pub qps_per_server: u32,
+
+    /// Extra attempts per nameserver after a timeout or SERVFAIL, before trying the next one
+    #[arg(long, global = true, default_value = "1")]
+    pub retries: u32,
+
+    /// Confirm domains DNS reports as available with the registry's WHOIS server
+    #[arg(long, global = true)]
+    pub verify_whois: bool,
+
+    /// Use RDAP: "verify" re-checks available domains, "primary" asks RDAP before DNS
+    #[arg(long, global = true, default_value = "off", value_name = "MODE")]
+    pub rdap: RdapMode,
+
+    /// With --rdap, download IANA's current RDAP bootstrap file for TLDs the built-in list has
+    /// no RDAP server for
+    #[arg(long, global = true)]
+    pub rdap_bootstrap: bool,
+
+    /// Validate NXDOMAIN answers with DNSSEC, marking names proven absent by signed NSEC/NSEC3 records
+    #[arg(long, global = true)]
+    pub dnssec: bool,
+
+    /// TLD nameservers from a root zone file (IANA's root.zone) or a dotchk JSON/TOML dump,
+    /// overriding the built-in list and the `registry update` cache
+    #[arg(long, global = true, value_name = "FILE")]
+    pub registry: Option<PathBuf>,
+
+    /// Keep DNS answers in the user cache directory for up to an hour, so reruns skip domains
+    /// that are already known
+    #[arg(long, global = true)]
+    pub cache: bool,
+
+    /// Ask these DNS-over-TLS resolvers when a TLD's nameservers all fail, instead of the
+    /// system's resolvers in the clear (HOST[:PORT][#NAME], repeatable or comma-separated)
+    #[arg(
+        long,
+        global = true,
+        value_name = "UPSTREAM",
+        value_delimiter = ',',
+        conflicts_with = "doh"
+    )]
+    pub dot: Vec<TlsUpstream>,
+
+    /// Ask these DNS-over-HTTPS endpoints when a TLD's nameservers all fail, instead of the
+    /// system's resolvers in the clear (repeatable or comma-separated)
+    #[arg(long, global = true, value_name = "URL", value_delimiter = ',')]
+    pub doh: Vec<String>,
+
+    /// Ask these resolvers instead of the system's when a TLD's nameservers all fail
+    /// (IP[:PORT], repeatable or comma-separated)
+    #[arg(long, global = true, value_name = "IP", value_delimiter = ',', conflicts_with_all = ["dot", "doh"])]
+    pub resolver: Vec<String>,
+
+    /// Query these servers for a TLD instead of its registry nameservers (TLD=IP[:PORT][,IP...],
+    /// repeatable)
+    #[arg(long, global = true, value_name = "TLD=IPS", value_parser = parse_authoritative)]
+    pub authoritative: Vec<(String, Vec<String>)>,
+
+    /// Never fall back to a recursive resolver; checks the authoritative servers can't answer fail
+    #[arg(long, global = true, conflicts_with_all = ["dot", "doh", "resolver"])]
+    pub no_fallback: bool,
+}
+
+/// Parse `--authoritative TLD=IP[,IP...]`.
+fn parse_authoritative(value: &str) -> std::result::Result<(String, Vec<String>), String> {
+    let (tld, servers) = value
+        .split_once('=')
+        .ok_or_else(|| format!("expected TLD=IP[,IP...], got '{value}'"))?;
+    Ok((tld.to_string(), servers.split(',').map(str::to_string).collect()))
+}
+
+impl NetworkArgs {
+    /// Checker builder with these flags plus the command's parallelism and timeout applied.
+    pub fn checker_builder(&self, parallel: usize, timeout: u64) -> Result<CheckerBuilder> {
+        let mut builder = Checker::builder()
+            .max_parallel(parallel)?
+            .timeout_ms(timeout)?
+            .qps(self.qps)?
+            .qps_per_server(self.qps_per_server)?
+            .retry_policy(RetryPolicy::default().attempts_per_server(self.retries.saturating_add(1)))?
+            .rdap(
+                self.rdap,
+                self.rdap_bootstrap.then(|| RdapClient::new().bootstrap_fallback(true)),
+            )?
+            .dnssec(self.dnssec)?
+            .registry(self.registry()?)?;
+        for (tld, servers) in &self.authoritative {
+            builder = builder.authoritative_override(tld, servers)?;
+        }
+        if !self.resolver.is_empty() {
+            builder = builder.resolvers(&self.resolver)?;
+        }
+        if self.no_fallback {
+            builder = builder.resolver_fallback(false)?;
+        }
+        if !self.dot.is_empty() {
+            builder = builder.resolver_transport(ResolverTransport::Tls(self.dot.clone()))?;
+        } else if !self.doh.is_empty() {
+            builder = builder.resolver_transport(ResolverTransport::Https(self.doh.clone()))?;
+        }
+        if self.cache {
+            match dotchk::cache_dir() {
+                Some(dir) => {
+                    builder = builder
+                        .cache_file(dir.join("dns-cache.json"))?
+                        .cache_ttl(Duration::from_secs(3600))?;
+                }
+                None => print_warning("No user cache directory; --cache only lasts for this run"),
+            }
+        }
+        Ok(builder)
+    }
+
+    /// Write the `--cache` file back; a failure only warns, the results are already out.
+    pub fn save_cache(&self, checker: &Checker) {
+        if let Err(e) = checker.save_cache() {
+            print_warning(&format!("Could not save the DNS cache: {e}"));
+        }
+    }
+
+    /// TLD registry data: the `--registry` file, or else the `dotchk registry update` cache,
+    /// over the built-in list.
+    pub fn registry(&self) -> Result<Registry> {
+        if let Some(registry) = REGISTRY.get() {
+            return Ok(registry.clone());
+        }
+        let registry = match &self.registry {
+            Some(path) => Some(Registry::load(path).map_err(|e| format!("{}: {e}", path.display()))?),
+            // The cache `dotchk registry update` writes; a broken one shouldn't stop checks
+            None => Registry::load_cached().unwrap_or_else(|e| {
+                print_warning(&format!("Ignoring the registry cache: {e}"));
+                None
+            }),
+        }
+        .unwrap_or_default();
+        Ok(REGISTRY.get_or_init(|| registry).clone())
+    }
+}
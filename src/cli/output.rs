@@ -1,5 +1,6 @@
 use colored::*;
-use dotchk::CheckResult;
+use dotchk::domain::to_unicode;
+use dotchk::{CheckResult, DnssecStatus, DomainCheckerError, DomainStatus, WhoisVerdict};
 use indicatif::{ProgressBar, ProgressStyle};
 use std::sync::LazyLock;
 use std::sync::atomic::{AtomicBool, Ordering};
@@ -54,36 +55,121 @@ pub fn print_warning(text: &str) {
     eprintln!("WARNING: {text}");
 }
 
+/// Status label and color for a check result
+fn status_label(result: &CheckResult) -> (&'static str, Color) {
+    match result.whois {
+        Some(WhoisVerdict::Registered) => return ("TAKEN (WHOIS)", *TAKEN_COLOR),
+        Some(WhoisVerdict::Available) => return ("AVAILABLE (WHOIS verified)", *AVAILABLE_COLOR),
+        Some(WhoisVerdict::Unknown) => return ("AVAILABLE (WHOIS unverified)", *AVAILABLE_COLOR),
+        None => {}
+    }
+    if result.status.is_available() && result.rdap.as_ref().is_some_and(|rdap| rdap.registered) {
+        return ("TAKEN (RDAP)", *TAKEN_COLOR);
+    }
+
+    match result.status {
+        DomainStatus::NxDomain if result.dnssec.as_ref().is_some_and(DnssecStatus::is_proven) => {
+            ("AVAILABLE (DNSSEC proven)", *AVAILABLE_COLOR)
+        }
+        DomainStatus::NxDomain => ("AVAILABLE", *AVAILABLE_COLOR),
+        DomainStatus::Delegated => ("TAKEN", *TAKEN_COLOR),
+        DomainStatus::NoData => ("TAKEN (no delegation)", *TAKEN_COLOR),
+        DomainStatus::Resolver { exists: false } => ("AVAILABLE (via resolver)", *AVAILABLE_COLOR),
+        DomainStatus::Resolver { exists: true } => ("TAKEN (via resolver)", *TAKEN_COLOR),
+        DomainStatus::Rdap { registered: false } => ("AVAILABLE (RDAP)", *AVAILABLE_COLOR),
+        DomainStatus::Rdap { registered: true } => ("TAKEN (RDAP)", *TAKEN_COLOR),
+        DomainStatus::Whois { registered: false } => ("AVAILABLE (WHOIS verified)", *AVAILABLE_COLOR),
+        DomainStatus::Whois { registered: true } => ("TAKEN (WHOIS)", *TAKEN_COLOR),
+        DomainStatus::Unregistrable { .. } => ("UNREGISTRABLE", *DIM_COLOR),
+        DomainStatus::Error { .. } => ("FAILED", *ERROR_COLOR),
+    }
+}
+
+/// A domain as shown to the user: internationalized names in Unicode, followed by the
+/// A-label form that was queried
+pub fn display_domain(domain: &str) -> String {
+    match to_unicode(domain) {
+        Some(unicode) => format!("{unicode} ({domain})"),
+        None => domain.to_string(),
+    }
+}
+
 /// Format a domain result with colors
 pub fn format_domain_result(result: &CheckResult) -> String {
-    let status = if result.available {
-        "AVAILABLE".color(*AVAILABLE_COLOR).bold()
+    let (label, color) = status_label(result);
+    let status = if result.available() {
+        label.color(color).bold()
     } else {
-        "TAKEN".color(*TAKEN_COLOR)
+        label.color(color)
     };
 
-    format!("{} {} {}", result.domain, "→".color(*DIM_COLOR), status)
+    match result_detail(result) {
+        Some(detail) => format!(
+            "{} {} {} {}",
+            display_domain(&result.domain),
+            "→".color(*DIM_COLOR),
+            status,
+            format!("({detail})").color(*DIM_COLOR)
+        ),
+        None => format!(
+            "{} {} {}",
+            display_domain(&result.domain),
+            "→".color(*DIM_COLOR),
+            status
+        ),
+    }
+}
+
+/// Extra context shown after the status: the error, the RDAP registration, or the delegation
+fn result_detail(result: &CheckResult) -> Option<String> {
+    if let DomainStatus::Error { message } | DomainStatus::Unregistrable { reason: message } = &result.status {
+        return Some(message.clone());
+    }
+
+    match &result.dnssec {
+        Some(DnssecStatus::Failed { reason }) => return Some(format!("DNSSEC failed: {reason}")),
+        Some(DnssecStatus::OptOut) => return Some("DNSSEC opt-out span".to_string()),
+        Some(DnssecStatus::Insecure) => return Some("unsigned zone".to_string()),
+        _ => {}
+    }
+
+    if let Some(rdap) = result.rdap.as_ref().filter(|rdap| rdap.registered) {
+        let expires = rdap
+            .expires_at
+            .as_ref()
+            .map(|date| format!("expires {}", date.get(..10).unwrap_or(date)));
+        let detail: Vec<String> = rdap.registrar.clone().into_iter().chain(expires).collect();
+        if !detail.is_empty() {
+            return Some(detail.join(", "));
+        }
+    }
+
+    // Where a taken domain is delegated, e.g. to spot parking providers
+    (!result.nameservers.is_empty()).then(|| result.nameservers.join(", "))
 }
 
-/// Format a domain error with colors
-pub fn format_domain_error(domain: &str) -> String {
-    format!("{} {} {}", domain, "→".color(*DIM_COLOR), "FAILED".color(*ERROR_COLOR))
+/// Format a failed check with colors
+pub fn format_domain_error(error: &DomainCheckerError) -> String {
+    let message = format!("({})", error.cause());
+    format!(
+        "{} {} {} {}",
+        display_domain(error.domain().unwrap_or("unknown")),
+        "→".color(*DIM_COLOR),
+        "FAILED".color(*ERROR_COLOR),
+        message.color(*DIM_COLOR)
+    )
 }
 
 /// Format a compact domain result for TLD checking
 pub fn format_tld_result(result: &CheckResult, indent: bool, max_domain_width: usize) -> String {
     let prefix = if indent { "  " } else { "" };
 
-    let (status, color) = if result.available {
-        ("AVAILABLE", *AVAILABLE_COLOR)
-    } else {
-        ("TAKEN", *TAKEN_COLOR)
-    };
+    let (status, color) = status_label(result);
 
     format!(
         "{}{:<width$} {} {}",
         prefix,
-        result.domain,
+        display_domain(&result.domain),
         "→".color(*DIM_COLOR),
         status.color(color),
         width = max_domain_width
@@ -97,7 +183,7 @@ pub fn format_tld_error(domain: &str, indent: bool, max_domain_width: usize) ->
     format!(
         "{}{:<width$} {} {}",
         prefix,
-        domain,
+        display_domain(domain),
         "→".color(*DIM_COLOR),
         "FAILED".color(*ERROR_COLOR),
         width = max_domain_width
@@ -157,6 +243,23 @@ pub fn print_statistics(stats: &dotchk::export::Stats) {
         format!("({:.1}%)", (stats.unavailable as f64 / stats.total as f64) * 100.0).color(*DIM_COLOR)
     );
 
+    if stats.undelegated > 0 {
+        println!("    no delegation  {}", stats.undelegated.to_string().color(*DIM_COLOR));
+    }
+    if stats.unregistrable > 0 {
+        println!(
+            "  Unregistrable    {} {}",
+            stats.unregistrable.to_string().color(*DIM_COLOR),
+            format!("({:.1}%)", (stats.unregistrable as f64 / stats.total as f64) * 100.0).color(*DIM_COLOR)
+        );
+    }
+    if stats.via_resolver > 0 {
+        println!(
+            "  Via resolver     {}",
+            stats.via_resolver.to_string().color(*DIM_COLOR)
+        );
+    }
+
     if stats.errors > 0 {
         println!(
             "  Errors           {} {}",
@@ -191,6 +294,23 @@ pub fn print_tld_statistics(stats: &dotchk::export::Stats) {
         format!("({:.1}%)", (stats.unavailable as f64 / stats.total as f64) * 100.0).color(*DIM_COLOR)
     );
 
+    if stats.undelegated > 0 {
+        println!("    no delegation  {}", stats.undelegated.to_string().color(*DIM_COLOR));
+    }
+    if stats.unregistrable > 0 {
+        println!(
+            "  Unregistrable    {} {}",
+            stats.unregistrable.to_string().color(*DIM_COLOR),
+            format!("({:.1}%)", (stats.unregistrable as f64 / stats.total as f64) * 100.0).color(*DIM_COLOR)
+        );
+    }
+    if stats.via_resolver > 0 {
+        println!(
+            "  Via resolver     {}",
+            stats.via_resolver.to_string().color(*DIM_COLOR)
+        );
+    }
+
     if stats.errors > 0 {
         println!(
             "  Errors           {} {}",
@@ -1,16 +1,19 @@
 type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
-use dotchk::{Checker, Pattern};
+use dotchk::Pattern;
 use futures::StreamExt;
 use std::path::PathBuf;
 
+use super::options::NetworkArgs;
 use super::output::{create_progress_bar, format_domain_error, format_domain_result, print_footer_note, print_info};
-use super::utils::{export_results, print_stats};
+use super::utils::{export_results, print_stats, print_whois_results, verify_whois};
 
+#[allow(clippy::too_many_arguments)]
 pub async fn check_pattern(
     pattern_str: String,
     limit: Option<usize>,
     parallel: usize,
     timeout: u64,
+    network: &NetworkArgs,
     available_only: bool,
     output: Option<PathBuf>,
     show_stats: bool,
@@ -20,7 +23,7 @@ pub async fn check_pattern(
 
     print_info(&format!("Generated {} domains from pattern", domains.len()));
 
-    let checker = Checker::builder().max_parallel(parallel)?.timeout_ms(timeout)?.build().await?;
+    let checker = network.checker_builder(parallel, timeout)?.build().await?;
 
     let mut results = Vec::new();
     let pb = create_progress_bar(domains.len() as u64, "Checking domains");
@@ -32,20 +35,20 @@ pub async fn check_pattern(
 
         match &result {
             Ok(check) => {
-                if !available_only || check.available {
+                if !available_only || check.available() {
                     pb.suspend(|| {
                         println!("{}", format_domain_result(check));
                     });
                 }
 
-                if check.available {
+                if check.available() {
                     has_available = true;
                 }
             }
-            Err(_e) => {
+            Err(e) => {
                 if !available_only {
                     pb.suspend(|| {
-                        println!("{}", format_domain_error("unknown"));
+                        println!("{}", format_domain_error(e));
                     });
                 }
             }
@@ -55,6 +58,11 @@ pub async fn check_pattern(
     }
 
     pb.finish_and_clear();
+    network.save_cache(&checker);
+
+    if verify_whois(&mut results, network).await {
+        print_whois_results(&results);
+    }
 
     if has_available && !available_only {
         print_footer_note();
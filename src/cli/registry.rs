@@ -0,0 +1,74 @@
+type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+use dotchk::Registry;
+use dotchk::registry::{RESOLVE_PARALLELISM, RootZone};
+use std::path::PathBuf;
+
+use super::options::NetworkArgs;
+use super::output::{create_spinner, print_info, print_success, print_warning};
+
+/// Where IANA publishes the root zone.
+pub const ROOT_ZONE_URL: &str = "https://www.internic.net/domain/root.zone";
+
+/// Fetch a root zone, resolve the nameservers it has no glue for through the `network`
+/// settings' resolvers and rate limits, and write the registry dump the other commands load
+/// on startup.
+pub async fn update_registry(
+    source: Option<String>,
+    timeout: u64,
+    network: &NetworkArgs,
+    output: Option<PathBuf>,
+) -> Result<()> {
+    let source = source.unwrap_or_else(|| ROOT_ZONE_URL.to_string());
+    let output = match output.or_else(Registry::cache_path) {
+        Some(path) => path,
+        None => return Err("no cache directory found (set XDG_CACHE_HOME or HOME), pass --output".into()),
+    };
+
+    let spinner = create_spinner(&format!("Fetching {source}"));
+    let text = if source.starts_with("http://") || source.starts_with("https://") {
+        reqwest::get(&source).await?.error_for_status()?.text().await?
+    } else {
+        std::fs::read_to_string(&source).map_err(|e| format!("{source}: {e}"))?
+    };
+    spinner.finish_and_clear();
+
+    let mut zone = RootZone::parse(&text)?;
+    print_info(&format!("Root zone delegates {} TLDs", zone.delegations.len()));
+
+    let missing = zone.missing_glue().len();
+    if missing > 0 {
+        let checker = network.checker_builder(RESOLVE_PARALLELISM, timeout)?.build().await?;
+        let spinner = create_spinner(&format!("Resolving {missing} nameservers without glue"));
+        let resolved = zone.resolve_missing_glue(&checker).await;
+        spinner.finish_and_clear();
+        print_info(&format!("Resolved {resolved} of {missing} nameservers without glue"));
+    }
+
+    let unresolved: Vec<&String> = zone
+        .delegations
+        .keys()
+        .filter(|tld| zone.ipv4_servers(tld).is_empty())
+        .collect();
+    if !unresolved.is_empty() {
+        let mut listed: Vec<&str> = unresolved.iter().take(10).map(|tld| tld.as_str()).collect();
+        if unresolved.len() > listed.len() {
+            listed.push("...");
+        }
+        print_warning(&format!(
+            "{} TLDs have no IPv4 nameserver address; they keep the built-in servers, or are left out if there are none: {}",
+            unresolved.len(),
+            listed.join(", ")
+        ));
+    }
+
+    zone.save(&output)?;
+    let registry = Registry::from_zone(&zone);
+    print_success(&format!(
+        "Wrote {} TLDs to {} ({} new, {} with changed nameservers)",
+        registry.len(),
+        output.display(),
+        registry.added().len(),
+        registry.changed().len()
+    ));
+    Ok(())
+}
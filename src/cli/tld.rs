@@ -1,13 +1,16 @@
 type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
-use dotchk::{CheckResult, Checker, DomainCheckerError, TLD_SERVERS, get_public_tlds};
+use dotchk::domain::to_ascii;
+use dotchk::{CheckResult, DomainCheckerError, Registry, psl};
 use futures::StreamExt;
 use std::collections::HashMap;
 use std::path::PathBuf;
 
+use super::options::NetworkArgs;
 use super::output::{
-    create_progress_bar, format_tld_error, format_tld_result, print_footer_note, print_header, print_info,
+    create_progress_bar, display_domain, format_tld_error, format_tld_result, print_footer_note, print_header,
+    print_info,
 };
-use super::utils::{export_results, print_tld_stats};
+use super::utils::{export_results, print_tld_stats, verify_whois};
 
 const POPULAR_TLDS: &[&str] = &["com", "net", "org", "io", "dev", "app", "co", "me", "ai", "xyz", "info", "biz"];
 
@@ -82,15 +85,17 @@ pub async fn check_tlds(
     all: bool,
     parallel: usize,
     timeout: u64,
+    network: &NetworkArgs,
     available_only: bool,
     output: Option<PathBuf>,
     show_stats: bool,
 ) -> Result<()> {
     // Determine which TLDs to check
-    let tlds_to_check = determine_tlds(popular, tech, business, creative, retail, country, tlds, all);
+    let registry = network.registry()?;
+    let tlds_to_check = determine_tlds(&registry, popular, tech, business, creative, retail, country, tlds, all);
 
     // Generate domain combinations
-    let domains_to_check = generate_domain_combinations(&domains, &tlds_to_check);
+    let domains_to_check = generate_domain_combinations(&registry, &domains, &tlds_to_check);
 
     print_info(&format!(
         "Checking {} domain combinations across {} TLDs",
@@ -98,7 +103,7 @@ pub async fn check_tlds(
         tlds_to_check.len()
     ));
 
-    let checker = Checker::builder().max_parallel(parallel)?.timeout_ms(timeout)?.build().await?;
+    let checker = network.checker_builder(parallel, timeout)?.build().await?;
 
     let mut results = Vec::new();
     let pb = create_progress_bar(domains_to_check.len() as u64, "Checking TLDs");
@@ -110,6 +115,9 @@ pub async fn check_tlds(
     }
 
     pb.finish_and_clear();
+    network.save_cache(&checker);
+
+    verify_whois(&mut results, network).await;
 
     // Print results grouped by domain
     print_grouped_results(&domains, &results, available_only);
@@ -127,6 +135,7 @@ pub async fn check_tlds(
 
 #[allow(clippy::too_many_arguments)]
 fn determine_tlds(
+    registry: &Registry,
     popular: bool,
     tech: bool,
     business: bool,
@@ -139,7 +148,7 @@ fn determine_tlds(
     use std::collections::HashSet;
 
     if all {
-        let public_tlds = get_public_tlds();
+        let public_tlds = registry.public_tlds();
         print_info(&format!(
             "Using {} public TLDs (excluding private, adult, gambling, religious, and non-ASCII TLDs)",
             public_tlds.len()
@@ -207,18 +216,24 @@ fn determine_tlds(
     }
 }
 
-fn generate_domain_combinations(domains: &[String], tlds_to_check: &[String]) -> Vec<String> {
+fn generate_domain_combinations(registry: &Registry, domains: &[String], tlds_to_check: &[String]) -> Vec<String> {
     let mut domains_to_check = Vec::new();
 
     for domain in domains {
-        // Remove any existing TLD from the domain
+        // Remove any existing TLD from the domain; Unicode labels are checked as A-labels
         let base_domain = domain.split('.').next().unwrap_or(domain);
+        let Some(base_ascii) = to_ascii(base_domain) else {
+            eprintln!("Warning: '{base_domain}' is not a valid domain label, skipping");
+            continue;
+        };
 
         for tld in tlds_to_check {
-            // Verify TLD is supported (normalize to lowercase for lookup)
-            let tld_lower = tld.to_lowercase();
-            if TLD_SERVERS.get(tld_lower.as_str()).is_some() {
-                domains_to_check.push(format!("{base_domain}.{tld_lower}"));
+            // Verify TLD is supported (normalize to lowercase A-labels for lookup); second-level
+            // suffixes like co.uk count if their TLD is
+            let tld_ascii = to_ascii(tld).unwrap_or_default();
+            let candidate = format!("{base_ascii}.{tld_ascii}");
+            if psl::is_public_suffix(&tld_ascii) && registry.tld_info(&candidate).is_some() {
+                domains_to_check.push(candidate);
             } else {
                 eprintln!("Warning: TLD '{tld}' is not supported, skipping");
             }
@@ -232,6 +247,14 @@ fn extract_base_domain(domain: &str) -> String {
     domain.split('.').next().unwrap_or(domain).to_string()
 }
 
+/// The domain a check result is for; failed checks from `check_stream` carry theirs.
+fn result_domain(result: &std::result::Result<CheckResult, DomainCheckerError>) -> &str {
+    match result {
+        Ok(check) => &check.domain,
+        Err(e) => e.domain().unwrap_or("unknown"),
+    }
+}
+
 fn print_grouped_results(
     domains: &[String],
     results: &[std::result::Result<CheckResult, DomainCheckerError>],
@@ -244,30 +267,27 @@ fn print_grouped_results(
         HashMap::new();
 
     for result in results {
-        let base_domain = match result {
-            Ok(check) => extract_base_domain(&check.domain),
-            Err(_) => "unknown".to_string(),
-        };
+        let base_domain = extract_base_domain(result_domain(result));
         grouped_results.entry(base_domain).or_default().push(result);
     }
 
     for domain in domains {
         let base_domain = domain.split('.').next().unwrap_or(domain);
-        // Convert to lowercase for lookup since domain checking normalizes to lowercase
-        let base_domain_lower = base_domain.to_lowercase();
+        // Normalize for lookup the way domain checking does: lowercase A-labels
+        let base_domain_lower = to_ascii(base_domain).unwrap_or_else(|| base_domain.to_lowercase());
 
         if let Some(domain_results) = grouped_results.get(&base_domain_lower) {
             let mut sorted_results = domain_results.to_vec();
-            sorted_results.sort_by_key(|r| match r {
-                Ok(check) => check.domain.clone(),
-                Err(_) => "unknown".to_string(),
-            });
+            sorted_results.sort_by_key(|r| result_domain(r).to_string());
 
             // Calculate max domain width for alignment
             let max_width = sorted_results
                 .iter()
                 .filter_map(|r| match r {
-                    Ok(check) if !available_only || check.available => Some(check.domain.len()),
+                    Ok(check) if !available_only || check.available() => {
+                        Some(display_domain(&check.domain).chars().count())
+                    }
+                    Err(_) if !available_only => Some(display_domain(result_domain(r)).chars().count()),
                     _ => None,
                 })
                 .max()
@@ -275,7 +295,7 @@ fn print_grouped_results(
 
             // Only print header if we have results to show
             let has_results = sorted_results.iter().any(|r| match r {
-                Ok(check) => !available_only || check.available,
+                Ok(check) => !available_only || check.available(),
                 Err(_) => !available_only,
             });
 
@@ -286,19 +306,19 @@ fn print_grouped_results(
             for result in sorted_results {
                 match result {
                     Ok(check) => {
-                        if available_only && !check.available {
+                        if available_only && !check.available() {
                             continue;
                         }
 
                         println!("{}", format_tld_result(check, true, max_width));
 
-                        if check.available {
+                        if check.available() {
                             has_available = true;
                         }
                     }
-                    Err(_e) => {
+                    Err(_) => {
                         if !available_only {
-                            println!("{}", format_tld_error("unknown", true, max_width));
+                            println!("{}", format_tld_error(result_domain(result), true, max_width));
                         }
                     }
                 }
@@ -1,30 +1,57 @@
+use crate::cli::options::NetworkArgs;
 use crate::cli::output::{
-    format_domain_error, format_domain_result, print_export_success, print_footer_note, print_statistics,
-    print_tld_statistics,
+    create_spinner, format_domain_error, format_domain_result, print_export_success, print_footer_note, print_header,
+    print_statistics, print_tld_statistics,
 };
 type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
-use dotchk::{CheckResult, CsvExporter, DomainCheckerError, export::StatsExporter};
+use dotchk::{CheckResult, CsvExporter, DomainCheckerError, WhoisClient, export::StatsExporter};
 use std::path::PathBuf;
 
+/// Re-check the available domains with WHOIS if `--verify-whois` was given.
+///
+/// Returns whether any lookups ran.
+pub async fn verify_whois(
+    results: &mut [std::result::Result<CheckResult, DomainCheckerError>],
+    network: &NetworkArgs,
+) -> bool {
+    let available_count = results.iter().filter(|r| matches!(r, Ok(check) if check.available())).count();
+    if !network.verify_whois || available_count == 0 {
+        return false;
+    }
+
+    let spinner = create_spinner(&format!("Verifying {available_count} available domains with WHOIS"));
+    WhoisClient::new().verify_available(results).await;
+    spinner.finish_and_clear();
+    true
+}
+
+/// Print the WHOIS verdicts, for commands that printed DNS results before verifying.
+pub fn print_whois_results(results: &[std::result::Result<CheckResult, DomainCheckerError>]) {
+    print_header("WHOIS verification");
+    for check in results.iter().flatten().filter(|check| check.whois.is_some()) {
+        println!("{}", format_domain_result(check));
+    }
+}
+
 pub fn print_results(results: &[std::result::Result<CheckResult, DomainCheckerError>], available_only: bool) {
     let mut has_available = false;
 
     for result in results {
         match result {
             Ok(check) => {
-                if available_only && !check.available {
+                if available_only && !check.available() {
                     continue;
                 }
 
                 println!("{}", format_domain_result(check));
 
-                if check.available {
+                if check.available() {
                     has_available = true;
                 }
             }
-            Err(_e) => {
+            Err(e) => {
                 if !available_only {
-                    println!("{}", format_domain_error("unknown"));
+                    println!("{}", format_domain_error(e));
                 }
             }
         }
@@ -44,7 +71,7 @@ pub fn export_results(
 
     if available_only {
         exporter.export_available_only(results)?;
-        let available_count = results.iter().filter(|r| matches!(r, Ok(check) if check.available)).count();
+        let available_count = results.iter().filter(|r| matches!(r, Ok(check) if check.available())).count();
         print_export_success(&path.display().to_string(), available_count);
     } else {
         exporter.export(results)?;
@@ -0,0 +1,47 @@
+type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+use futures::StreamExt;
+use std::io::{BufWriter, Write};
+use std::path::PathBuf;
+
+use super::options::NetworkArgs;
+use super::output::{create_spinner, print_success};
+
+/// Walk a TLD's NSEC chain and list its registered names, one per line, to stdout or `output`.
+pub async fn walk_zone(zone: String, timeout: u64, network: &NetworkArgs, output: Option<PathBuf>) -> Result<()> {
+    let checker = network.checker_builder(1, timeout)?.build().await?;
+    let mut names = Box::pin(checker.zone_walk(&zone));
+
+    let Some(path) = output else {
+        while let Some(name) = names.next().await {
+            println!("{}", name?);
+        }
+        return Ok(());
+    };
+
+    let mut file = BufWriter::new(std::fs::File::create(&path).map_err(|e| format!("{}: {e}", path.display()))?);
+    let spinner = create_spinner(&format!("Walking .{zone}"));
+    let mut count = 0;
+    let mut failure = None;
+    while let Some(name) = names.next().await {
+        match name {
+            Ok(name) => {
+                writeln!(file, "{name}")?;
+                count += 1;
+                spinner.set_message(format!("Walking .{zone}: {count} names, at {name}"));
+            }
+            Err(e) => failure = Some(e),
+        }
+    }
+    spinner.finish_and_clear();
+    // Whatever was walked before a failure is still worth keeping
+    file.flush()?;
+
+    if let Some(e) = failure {
+        return Err(format!("{e} ({count} names written to {})", path.display()).into());
+    }
+    print_success(&format!(
+        "Wrote {count} names registered in .{zone} to {}",
+        path.display()
+    ));
+    Ok(())
+}
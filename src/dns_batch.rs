@@ -1,66 +1,276 @@
+//! Batched UDP socket for DNS traffic.
+//!
+//! On Linux, `send_batch` and `recv_batch` move a whole batch of datagrams per system call
+//! with `sendmmsg(2)`/`recvmmsg(2)`. Other platforms fall back to one `send_to`/`recv_from`
+//! per datagram behind the same API. Received datagrams land in caller-owned, reusable
+//! buffers ([`RecvBatch`]) and send results in a reusable [`SendBatch`]. The `mmsghdr`s are
+//! kept per thread and the iovecs built on the stack, so neither hot path allocates once
+//! warmed up.
+
 use std::io;
 use std::net::SocketAddr;
+use tokio::io::Interest;
 use tokio::net::UdpSocket;
 
+#[cfg(target_os = "linux")]
+use nix::sys::socket::{MultiHeaders, SockaddrStorage};
+#[cfg(target_os = "linux")]
+use std::cell::RefCell;
+
 // 4KB handles standard DNS (512 bytes) and EDNS0 (commonly up to 4KB).
 const DNS_BUFFER_SIZE: usize = 4096;
+/// Most datagrams moved per system call; larger batches take several.
+#[cfg(target_os = "linux")]
+const MAX_MESSAGES_PER_CALL: usize = 256;
+
+#[cfg(target_os = "linux")]
+thread_local! {
+    /// `mmsghdr`s for `sendmmsg`, with their capacity. They hold raw pointers and can't move
+    /// between threads with the batches, so each thread keeps its own.
+    static SEND_HEADERS: RefCell<Option<Headers>> = const { RefCell::new(None) };
+    /// `mmsghdr`s for `recvmmsg`, one set per address family. Sends point `msg_name` at the
+    /// destination, and the kernel shrinks `msg_namelen` to the sender's address size on
+    /// receive, so neither direction nor family can share a set.
+    static RECV_HEADERS: RefCell<[Option<Headers>; 2]> = const { RefCell::new([None, None]) };
+}
+
+#[cfg(target_os = "linux")]
+type Headers = (usize, MultiHeaders<SockaddrStorage>);
 
 pub struct BatchDnsSocket {
     socket: UdpSocket,
+    ipv6: bool,
+}
+
+/// Reusable receive buffers for [`BatchDnsSocket::recv_batch`].
+pub struct RecvBatch {
+    buffers: Vec<Vec<u8>>,
+    lengths: Vec<usize>,
+    addrs: Vec<SocketAddr>,
+    count: usize,
+}
+
+impl RecvBatch {
+    /// Allocate buffers for up to `capacity` datagrams per receive call.
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            buffers: vec![vec![0u8; DNS_BUFFER_SIZE]; capacity],
+            lengths: vec![0; capacity],
+            addrs: vec![SocketAddr::from(([0, 0, 0, 0], 0)); capacity],
+            count: 0,
+        }
+    }
+
+    /// Iterate over the datagrams filled in by the last receive call.
+    pub fn iter(&self) -> impl Iterator<Item = (&[u8], SocketAddr)> {
+        (0..self.count).map(|i| (&self.buffers[i][..self.lengths[i]], self.addrs[i]))
+    }
+}
+
+/// Reusable state for [`BatchDnsSocket::send_batch`]: the lengths it reports, and on Linux
+/// the destination addresses in system call form.
+pub struct SendBatch {
+    lengths: Vec<usize>,
+    #[cfg(target_os = "linux")]
+    addrs: Vec<Option<SockaddrStorage>>,
+}
+
+impl SendBatch {
+    /// Allocate room for batches of up to `capacity` messages; larger ones grow it.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            lengths: Vec::with_capacity(capacity),
+            #[cfg(target_os = "linux")]
+            addrs: Vec::with_capacity(capacity.min(MAX_MESSAGES_PER_CALL)),
+        }
+    }
 }
 
 impl BatchDnsSocket {
     pub async fn new(bind_addr: &str) -> io::Result<Self> {
         let socket = UdpSocket::bind(bind_addr).await?;
-        Ok(Self { socket })
+        Ok(Self {
+            ipv6: socket.local_addr()?.is_ipv6(),
+            socket,
+        })
     }
 
-    pub async fn send_batch(&self, messages: &[(Vec<u8>, SocketAddr)]) -> io::Result<Vec<usize>> {
-        if messages.is_empty() {
-            return Ok(vec![]);
+    /// Send every message, waiting for the socket to become writable as needed.
+    ///
+    /// Returns the number of bytes sent for each message, in order. A message the kernel
+    /// rejected individually (e.g. no route to an IPv6 server) is reported as 0 bytes.
+    pub async fn send_batch<'b>(
+        &self,
+        messages: &[(Vec<u8>, SocketAddr)],
+        batch: &'b mut SendBatch,
+    ) -> io::Result<&'b [usize]> {
+        batch.lengths.clear();
+
+        while batch.lengths.len() < messages.len() {
+            self.socket.writable().await?;
+
+            let remaining = &messages[batch.lengths.len()..];
+            match self
+                .socket
+                .try_io(Interest::WRITABLE, || send_many(&self.socket, remaining, batch))
+            {
+                Ok(()) => {}
+                Err(e) if e.kind() == io::ErrorKind::WouldBlock => continue,
+                // The error belongs to the first unsent message; skip it and carry on with the rest
+                Err(_) => batch.lengths.push(0),
+            }
         }
 
-        let mut sent_lengths = Vec::with_capacity(messages.len());
+        Ok(&batch.lengths)
+    }
+
+    /// Wait for datagrams and receive as many as fit in `batch`.
+    ///
+    /// Returns the number of datagrams received; read them with [`RecvBatch::iter`].
+    pub async fn recv_batch(&self, batch: &mut RecvBatch) -> io::Result<usize> {
+        loop {
+            self.socket.readable().await?;
 
-        for (data, addr) in messages {
-            match self.socket.send_to(data, addr).await {
-                Ok(len) => sent_lengths.push(len),
-                Err(e) if e.kind() == io::ErrorKind::WouldBlock => {
-                    // Socket became unwritable - return what we've sent so far
-                    break;
+            match self
+                .socket
+                .try_io(Interest::READABLE, || recv_many(&self.socket, self.ipv6, batch))
+            {
+                Ok(count) => {
+                    batch.count = count;
+                    return Ok(count);
+                }
+                Err(e) if e.kind() == io::ErrorKind::WouldBlock => continue,
+                Err(e) => {
+                    batch.count = 0;
+                    return Err(e);
                 }
-                Err(e) => return Err(e),
             }
         }
+    }
+}
 
-        Ok(sent_lengths)
+/// The headers in `slot`, grown to at least `count` messages.
+#[cfg(target_os = "linux")]
+fn headers(slot: &mut Option<Headers>, count: usize) -> &mut MultiHeaders<SockaddrStorage> {
+    if slot.as_ref().is_none_or(|(capacity, _)| *capacity < count) {
+        *slot = Some((count, MultiHeaders::preallocate(count, None)));
     }
+    &mut slot.as_mut().expect("headers were just allocated").1
+}
 
-    pub async fn recv_batch(&self, max_messages: usize) -> io::Result<Vec<(Vec<u8>, SocketAddr)>> {
-        // Wait for socket to become readable
-        self.socket.readable().await?;
+/// Send as many of `messages` as the socket takes in one call, appending their lengths.
+#[cfg(target_os = "linux")]
+fn send_many(socket: &UdpSocket, messages: &[(Vec<u8>, SocketAddr)], batch: &mut SendBatch) -> io::Result<()> {
+    use nix::sys::socket::{MsgFlags, sendmmsg};
+    use std::io::IoSlice;
+    use std::os::fd::AsRawFd;
 
-        let mut messages = Vec::with_capacity(max_messages);
-        let mut buffer = vec![0u8; DNS_BUFFER_SIZE];
+    let messages = &messages[..messages.len().min(MAX_MESSAGES_PER_CALL)];
+    let mut data = messages.iter().map(|(data, _)| data.as_slice());
+    let iovecs: [[IoSlice<'_>; 1]; MAX_MESSAGES_PER_CALL] =
+        std::array::from_fn(|_| [IoSlice::new(data.next().unwrap_or_default())]);
+    batch.addrs.clear();
+    batch
+        .addrs
+        .extend(messages.iter().map(|(_, addr)| Some(SockaddrStorage::from(*addr))));
 
-        // Try to receive multiple messages while socket is readable
-        for _ in 0..max_messages {
-            match self.socket.try_recv_from(&mut buffer) {
-                Ok((len, addr)) => {
-                    let mut data = vec![0u8; len];
-                    data.copy_from_slice(&buffer[..len]);
-                    messages.push((data, addr));
-                }
-                Err(e) if e.kind() == io::ErrorKind::WouldBlock => {
-                    // No more data available
-                    break;
-                }
-                Err(e) => return Err(e),
+    SEND_HEADERS.with_borrow_mut(|slot| {
+        let results = sendmmsg(
+            socket.as_raw_fd(),
+            headers(slot, messages.len()),
+            &iovecs[..messages.len()],
+            &batch.addrs,
+            [],
+            MsgFlags::MSG_DONTWAIT,
+        )?;
+        batch.lengths.extend(results.map(|msg| msg.bytes));
+        Ok(())
+    })
+}
+
+#[cfg(target_os = "linux")]
+fn recv_many(socket: &UdpSocket, ipv6: bool, batch: &mut RecvBatch) -> io::Result<usize> {
+    use nix::sys::socket::{MsgFlags, recvmmsg};
+    use std::io::IoSliceMut;
+    use std::os::fd::AsRawFd;
+
+    let RecvBatch {
+        buffers,
+        lengths,
+        addrs,
+        ..
+    } = batch;
+
+    let capacity = buffers.len().min(MAX_MESSAGES_PER_CALL);
+    RECV_HEADERS.with_borrow_mut(|slots| {
+        // Built in here: recvmmsg borrows the iovecs for as long as the headers
+        let mut buffers = buffers.iter_mut();
+        let mut iovecs: [[IoSliceMut<'_>; 1]; MAX_MESSAGES_PER_CALL] =
+            std::array::from_fn(|_| [IoSliceMut::new(buffers.next().map_or(&mut [], |buf| buf.as_mut_slice()))]);
+        let results = recvmmsg(
+            socket.as_raw_fd(),
+            headers(&mut slots[usize::from(ipv6)], capacity),
+            iovecs[..capacity].iter_mut(),
+            MsgFlags::MSG_DONTWAIT,
+            None,
+        )?;
+
+        let mut count = 0;
+        for msg in results {
+            // Datagrams from other address families can't be DNS replies to us; give them an unmatchable source
+            let addr = msg.address.and_then(|addr| {
+                addr.as_sockaddr_in()
+                    .map(|v4| SocketAddr::from(std::net::SocketAddrV4::from(*v4)))
+                    .or_else(|| {
+                        addr.as_sockaddr_in6()
+                            .map(|v6| SocketAddr::from(std::net::SocketAddrV6::from(*v6)))
+                    })
+            });
+            lengths[count] = msg.bytes;
+            addrs[count] = addr.unwrap_or_else(|| SocketAddr::from(([0, 0, 0, 0], 0)));
+            count += 1;
+        }
+        Ok(count)
+    })
+}
+
+#[cfg(not(target_os = "linux"))]
+fn send_many(socket: &UdpSocket, messages: &[(Vec<u8>, SocketAddr)], batch: &mut SendBatch) -> io::Result<()> {
+    let mut sent = 0;
+
+    for (data, addr) in messages {
+        match socket.try_send_to(data, *addr) {
+            Ok(len) => {
+                batch.lengths.push(len);
+                sent += 1;
             }
+            // Report what went out before the socket filled up or a message failed
+            Err(_) if sent > 0 => break,
+            Err(e) => return Err(e),
         }
+    }
 
-        Ok(messages)
+    Ok(())
+}
+
+#[cfg(not(target_os = "linux"))]
+fn recv_many(socket: &UdpSocket, _ipv6: bool, batch: &mut RecvBatch) -> io::Result<usize> {
+    let mut count = 0;
+
+    while count < batch.buffers.len() {
+        match socket.try_recv_from(&mut batch.buffers[count]) {
+            Ok((len, addr)) => {
+                batch.lengths[count] = len;
+                batch.addrs[count] = addr;
+                count += 1;
+            }
+            Err(_) if count > 0 => break,
+            Err(e) => return Err(e),
+        }
     }
+
+    Ok(count)
 }
 
 #[cfg(test)]
@@ -72,4 +282,44 @@ mod tests {
         let socket = BatchDnsSocket::new("0.0.0.0:0").await;
         assert!(socket.is_ok());
     }
+
+    #[tokio::test]
+    async fn batch_socket_round_trips_many_datagrams() {
+        let sender = BatchDnsSocket::new("127.0.0.1:0").await.unwrap();
+        let receiver = BatchDnsSocket::new("127.0.0.1:0").await.unwrap();
+        let target = receiver.socket.local_addr().unwrap();
+
+        let messages: Vec<(Vec<u8>, SocketAddr)> = (0..32u8).map(|i| (vec![i; 20], target)).collect();
+        let mut send_batch = SendBatch::new(16);
+        let sent = sender.send_batch(&messages, &mut send_batch).await.unwrap();
+        assert_eq!(sent, vec![20; 32]);
+
+        let mut batch = RecvBatch::new(64);
+        let mut received = Vec::new();
+        while received.len() < messages.len() {
+            receiver.recv_batch(&mut batch).await.unwrap();
+            received.extend(batch.iter().map(|(data, _)| data[0]));
+        }
+
+        received.sort_unstable();
+        assert_eq!(received, (0..32u8).collect::<Vec<_>>());
+        assert!(batch.iter().all(|(_, addr)| addr == sender.socket.local_addr().unwrap()));
+    }
+
+    #[tokio::test]
+    async fn send_batch_reuses_its_state_across_calls_and_large_batches() {
+        let sender = BatchDnsSocket::new("127.0.0.1:0").await.unwrap();
+        let receiver = BatchDnsSocket::new("127.0.0.1:0").await.unwrap();
+        let target = receiver.socket.local_addr().unwrap();
+
+        // More messages than one system call takes, then a small batch with the same state
+        let mut batch = SendBatch::new(4);
+        let large: Vec<(Vec<u8>, SocketAddr)> = (0..300u16).map(|i| (vec![0; 12 + i as usize % 3], target)).collect();
+        let sent = sender.send_batch(&large, &mut batch).await.unwrap();
+        assert_eq!(sent.len(), 300);
+        assert!(sent.iter().enumerate().all(|(i, &len)| len == 12 + i % 3));
+
+        let small = vec![(vec![1; 7], target)];
+        assert_eq!(sender.send_batch(&small, &mut batch).await.unwrap(), [7]);
+    }
 }
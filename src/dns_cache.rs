@@ -0,0 +1,306 @@
+//! Bounded answer cache for the DNS client.
+//!
+//! Answers are cached per domain, whichever of its zone's servers gave them, for as long as
+//! DNS allows: the delegation's NS TTL for a taken domain, the SOA negative TTL (RFC 2308)
+//! for NXDOMAIN and NODATA, both capped by the configured maximum. Negative answers without
+//! an SOA aren't cached. Once `capacity` entries are stored, the least recently used one is
+//! evicted.
+//!
+//! The cache can be saved to and loaded from a file, so a rerun of the same job skips
+//! domains whose answers haven't expired yet.
+
+use crate::dns_message::Record;
+use crate::dns_pipelined::NsAnswer;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::io;
+use std::net::IpAddr;
+use std::path::Path;
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Default number of cached answers.
+pub const DEFAULT_CACHE_SIZE: usize = 100_000;
+
+struct Slot {
+    answer: NsAnswer,
+    expires: SystemTime,
+    /// Position in `Lru::recency`.
+    used: u64,
+}
+
+struct Lru {
+    slots: HashMap<String, Slot>,
+    /// Keys from least to most recently used.
+    recency: BTreeMap<u64, String>,
+    tick: u64,
+}
+
+impl Lru {
+    fn touch(&mut self, domain: &str) -> u64 {
+        self.tick += 1;
+        self.recency.insert(self.tick, domain.to_string());
+        self.tick
+    }
+
+    fn remove(&mut self, domain: &str) -> Option<Slot> {
+        let slot = self.slots.remove(domain)?;
+        self.recency.remove(&slot.used);
+        Some(slot)
+    }
+}
+
+/// One cache entry as stored on disk.
+#[derive(Serialize, Deserialize)]
+struct StoredAnswer {
+    domain: String,
+    /// Expiry as seconds since the Unix epoch.
+    expires: u64,
+    rcode: u16,
+    has_records: bool,
+    authoritative: bool,
+    nameservers: Vec<String>,
+    glue: Vec<(String, IpAddr)>,
+    answered_by: Option<IpAddr>,
+    rtt_us: u64,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    dnssec: Vec<Record>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CacheFile {
+    answers: Vec<StoredAnswer>,
+}
+
+/// LRU cache of NS answers, shared by all clones of a client.
+pub struct DnsCache {
+    lru: Mutex<Lru>,
+    capacity: usize,
+    max_ttl: Duration,
+}
+
+impl DnsCache {
+    pub fn new(capacity: usize, max_ttl: Duration) -> Self {
+        Self {
+            lru: Mutex::new(Lru {
+                slots: HashMap::new(),
+                recency: BTreeMap::new(),
+                tick: 0,
+            }),
+            capacity: capacity.max(1),
+            max_ttl,
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Lru> {
+        // A panic while holding the lock can't leave the maps inconsistent enough to matter
+        self.lru.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// The unexpired answer for `domain`, marking it recently used.
+    pub fn get(&self, domain: &str) -> Option<NsAnswer> {
+        let mut lru = self.lock();
+        let expired = lru.slots.get(domain)?.expires <= SystemTime::now();
+        if expired {
+            lru.remove(domain);
+            return None;
+        }
+
+        let used = lru.touch(domain);
+        let slot = lru.slots.get_mut(domain)?;
+        let previous = std::mem::replace(&mut slot.used, used);
+        let answer = slot.answer.clone();
+        lru.recency.remove(&previous);
+        Some(answer)
+    }
+
+    /// Cache `answer` for `domain`, if its TTL allows.
+    pub fn insert(&self, domain: &str, answer: &NsAnswer) {
+        let Some(ttl) = self.ttl_for(answer) else {
+            return;
+        };
+        self.insert_until(domain, answer.clone(), SystemTime::now() + ttl);
+    }
+
+    /// How long `answer` may be cached; `None` if not at all.
+    fn ttl_for(&self, answer: &NsAnswer) -> Option<Duration> {
+        let negative = answer.rcode == 3 || answer.nameservers.is_empty();
+        let ttl = match answer.ttl {
+            Some(seconds) => Duration::from_secs(u64::from(seconds)).min(self.max_ttl),
+            // Without an SOA there's no telling how long "doesn't exist" holds
+            None if negative => return None,
+            None => self.max_ttl,
+        };
+        (!ttl.is_zero()).then_some(ttl)
+    }
+
+    fn insert_until(&self, domain: &str, answer: NsAnswer, expires: SystemTime) {
+        let mut lru = self.lock();
+        lru.remove(domain);
+        let used = lru.touch(domain);
+        lru.slots.insert(domain.to_string(), Slot { answer, expires, used });
+
+        while lru.slots.len() > self.capacity {
+            let Some((_, oldest)) = lru.recency.pop_first() else {
+                break;
+            };
+            lru.slots.remove(&oldest);
+        }
+    }
+
+    #[cfg(test)]
+    fn len(&self) -> usize {
+        self.lock().slots.len()
+    }
+
+    /// Write the unexpired entries to `path`, least recently used first. Returns how many.
+    pub fn save(&self, path: &Path) -> io::Result<usize> {
+        let now = SystemTime::now();
+        let answers: Vec<StoredAnswer> = {
+            let lru = self.lock();
+            lru.recency
+                .values()
+                .filter_map(|domain| {
+                    let slot = lru.slots.get(domain)?;
+                    let expires = slot.expires.duration_since(UNIX_EPOCH).ok()?.as_secs();
+                    (slot.expires > now).then(|| StoredAnswer {
+                        domain: domain.clone(),
+                        expires,
+                        rcode: slot.answer.rcode,
+                        has_records: slot.answer.has_records,
+                        authoritative: slot.answer.authoritative,
+                        nameservers: slot.answer.nameservers.clone(),
+                        glue: slot.answer.glue.clone(),
+                        answered_by: slot.answer.server,
+                        rtt_us: slot.answer.rtt.as_micros().try_into().unwrap_or(u64::MAX),
+                        dnssec: slot.answer.dnssec.clone(),
+                    })
+                })
+                .collect()
+        };
+
+        if let Some(parent) = path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)?;
+        }
+        let count = answers.len();
+        // Write next to the target and rename, so an interrupted save can't corrupt the cache
+        let temporary = path.with_extension("tmp");
+        std::fs::write(&temporary, serde_json::to_vec(&CacheFile { answers })?)?;
+        std::fs::rename(&temporary, path)?;
+        Ok(count)
+    }
+
+    /// Add the unexpired entries saved at `path`. Returns how many; a missing file adds none.
+    pub fn load(&self, path: &Path) -> io::Result<usize> {
+        let data = match std::fs::read(path) {
+            Ok(data) => data,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(0),
+            Err(e) => return Err(e),
+        };
+        let file: CacheFile = serde_json::from_slice(&data)?;
+
+        let now = SystemTime::now();
+        let mut count = 0;
+        for stored in file.answers {
+            let expires = UNIX_EPOCH + Duration::from_secs(stored.expires);
+            if expires <= now {
+                continue;
+            }
+            let answer = NsAnswer {
+                has_records: stored.has_records,
+                nameservers: stored.nameservers,
+                glue: stored.glue,
+                rcode: stored.rcode,
+                authoritative: stored.authoritative,
+                server: stored.answered_by,
+                rtt: Duration::from_micros(stored.rtt_us),
+                dnssec: stored.dnssec,
+                ..NsAnswer::default()
+            };
+            // Never trust a saved entry for longer than this cache allows
+            self.insert_until(&stored.domain, answer, expires.min(now + self.max_ttl));
+            count += 1;
+        }
+        Ok(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn delegated(ttl: Option<u32>) -> NsAnswer {
+        NsAnswer {
+            has_records: true,
+            nameservers: vec!["ns1.example.net".to_string()],
+            ttl,
+            ..NsAnswer::default()
+        }
+    }
+
+    fn nxdomain(ttl: Option<u32>) -> NsAnswer {
+        NsAnswer {
+            ttl,
+            ..NsAnswer::nxdomain()
+        }
+    }
+
+    #[test]
+    fn evicts_least_recently_used() {
+        let cache = DnsCache::new(2, Duration::from_secs(300));
+        cache.insert("a.com", &delegated(None));
+        cache.insert("b.com", &delegated(None));
+        // Using a.com makes b.com the eviction candidate
+        assert!(cache.get("a.com").is_some());
+        cache.insert("c.com", &delegated(None));
+
+        assert_eq!(cache.len(), 2);
+        assert!(cache.get("a.com").is_some());
+        assert!(cache.get("b.com").is_none());
+        assert!(cache.get("c.com").is_some());
+    }
+
+    #[test]
+    fn caches_negative_answers_for_their_soa_ttl() {
+        let cache = DnsCache::new(10, Duration::from_secs(300));
+        assert_eq!(cache.ttl_for(&nxdomain(Some(900))), Some(Duration::from_secs(300)));
+        assert_eq!(cache.ttl_for(&nxdomain(Some(60))), Some(Duration::from_secs(60)));
+        assert_eq!(cache.ttl_for(&nxdomain(None)), None);
+        assert_eq!(cache.ttl_for(&nxdomain(Some(0))), None);
+        assert_eq!(cache.ttl_for(&delegated(None)), Some(Duration::from_secs(300)));
+
+        cache.insert("free.com", &nxdomain(Some(60)));
+        assert_eq!(cache.get("free.com").unwrap().rcode, 3);
+        cache.insert("unknown.com", &nxdomain(None));
+        assert!(cache.get("unknown.com").is_none());
+    }
+
+    #[test]
+    fn drops_expired_entries() {
+        let cache = DnsCache::new(10, Duration::from_secs(300));
+        cache.insert_until("old.com", delegated(None), SystemTime::now() - Duration::from_secs(1));
+        assert!(cache.get("old.com").is_none());
+        assert_eq!(cache.len(), 0);
+    }
+
+    #[test]
+    fn survives_a_save_and_load() {
+        let path = std::env::temp_dir().join(format!("dotchk-dns-cache-{}.json", std::process::id()));
+        let cache = DnsCache::new(10, Duration::from_secs(3600));
+        let mut taken = delegated(Some(172_800));
+        taken.glue = vec![("ns1.example.net".to_string(), IpAddr::from([192, 0, 2, 53]))];
+        cache.insert("taken.com", &taken);
+        cache.insert("free.com", &nxdomain(Some(900)));
+        assert_eq!(cache.save(&path).unwrap(), 2);
+
+        let reloaded = DnsCache::new(10, Duration::from_secs(3600));
+        assert_eq!(reloaded.load(&path).unwrap(), 2);
+        let answer = reloaded.get("taken.com").unwrap();
+        assert_eq!(answer.nameservers, taken.nameservers);
+        assert_eq!(answer.glue, taken.glue);
+        assert_eq!(reloaded.get("free.com").unwrap().rcode, 3);
+
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(reloaded.load(&path).unwrap(), 0);
+    }
+}
@@ -0,0 +1,115 @@
+//! DNS over HTTPS (RFC 8484) for the fallback resolver.
+//!
+//! Each query is the wire-format message POSTed as `application/dns-message`; the answer
+//! comes back the same way. The HTTP client keeps connections alive between queries.
+
+use reqwest::header::{ACCEPT, CONTENT_TYPE};
+use std::io;
+
+/// Media type of a DNS message over HTTP (RFC 8484 section 6).
+const DNS_MESSAGE: &str = "application/dns-message";
+
+pub struct DohClient {
+    http: reqwest::Client,
+}
+
+impl DohClient {
+    pub fn new() -> Self {
+        Self {
+            http: reqwest::Client::new(),
+        }
+    }
+
+    /// POST one DNS message to `url` and return the answer.
+    pub async fn query(&self, url: &str, message: &[u8]) -> io::Result<Vec<u8>> {
+        let response = self
+            .http
+            .post(url)
+            .header(CONTENT_TYPE, DNS_MESSAGE)
+            .header(ACCEPT, DNS_MESSAGE)
+            .body(message.to_vec())
+            .send()
+            .await
+            .map_err(io::Error::other)?;
+
+        let status = response.status();
+        if !status.is_success() {
+            return Err(io::Error::other(format!("{url} answered HTTP {status}")));
+        }
+        let content_type = response.headers().get(CONTENT_TYPE).and_then(|value| value.to_str().ok());
+        if content_type != Some(DNS_MESSAGE) {
+            return Err(io::Error::other(format!(
+                "{url} answered with {}, not {DNS_MESSAGE}",
+                content_type.unwrap_or("no content type")
+            )));
+        }
+        Ok(response.bytes().await.map_err(io::Error::other)?.to_vec())
+    }
+}
+
+/// Minimal HTTP/1.1 stand-in for tests: POSTs of a DNS message to /dns-query get
+/// `respond(body)` back, anything else a 415. Returns the URL to query.
+#[cfg(test)]
+pub(crate) async fn fake_server(respond: fn(&[u8]) -> Vec<u8>) -> String {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/dns-query", listener.local_addr().unwrap());
+
+    tokio::spawn(async move {
+        while let Ok((mut socket, _)) = listener.accept().await {
+            tokio::spawn(async move {
+                let mut request = Vec::new();
+                let mut buffer = vec![0; 2048];
+                // Read up to the end of the headers, then the body they announce
+                let body_at = loop {
+                    let n = socket.read(&mut buffer).await.unwrap();
+                    request.extend_from_slice(&buffer[..n]);
+                    if let Some(at) = request.windows(4).position(|window| window == b"\r\n\r\n") {
+                        break at + 4;
+                    }
+                };
+                let headers = String::from_utf8_lossy(&request[..body_at]).to_ascii_lowercase();
+                let length: usize = headers
+                    .lines()
+                    .find_map(|line| line.strip_prefix("content-length: "))
+                    .map_or(0, |length| length.trim().parse().unwrap());
+                while request.len() < body_at + length {
+                    let n = socket.read(&mut buffer).await.unwrap();
+                    request.extend_from_slice(&buffer[..n]);
+                }
+
+                let is_dns = headers.starts_with("post /dns-query ")
+                    && headers.contains(&format!("content-type: {DNS_MESSAGE}"));
+                let (status, content_type, body) = if is_dns {
+                    ("200 OK", DNS_MESSAGE, respond(&request[body_at..body_at + length]))
+                } else {
+                    ("415 Unsupported Media Type", "text/plain", b"unsupported".to_vec())
+                };
+                let head = format!(
+                    "HTTP/1.1 {status}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                    body.len()
+                );
+                socket.write_all(&[head.as_bytes(), &body].concat()).await.unwrap();
+            });
+        }
+    });
+
+    url
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn posts_dns_messages() {
+        let url = fake_server(|query| query.to_vec()).await;
+        let client = DohClient::new();
+        assert_eq!(client.query(&url, b"\x12\x34query").await.unwrap(), b"\x12\x34query");
+
+        let error = client.query(&url.replace("dns-query", "other"), b"query").await.unwrap_err();
+        assert!(error.to_string().contains("415"), "{error}");
+    }
+}
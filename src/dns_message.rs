@@ -0,0 +1,653 @@
+//! DNS wire-format parsing helpers shared by the UDP and TCP paths.
+//!
+//! [`parse_message`] decodes every section of a response (with name compression), which
+//! gives us the delegated nameservers, glue addresses and SOA of an answer.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+pub const QTYPE_NS: u16 = 2;
+pub const QCLASS_IN: u16 = 1;
+pub const TYPE_A: u16 = 1;
+pub const TYPE_SOA: u16 = 6;
+pub const TYPE_AAAA: u16 = 28;
+pub const TYPE_OPT: u16 = 41;
+pub const TYPE_DS: u16 = 43;
+pub const TYPE_RRSIG: u16 = 46;
+pub const TYPE_NSEC: u16 = 47;
+pub const TYPE_DNSKEY: u16 = 48;
+pub const TYPE_NSEC3: u16 = 50;
+
+/// The DO ("DNSSEC OK") bit in the OPT record's flags (RFC 3225).
+const EDNS_FLAG_DO: u16 = 0x8000;
+
+/// EDNS0 option code for Extended DNS Errors (RFC 8914).
+const OPTION_EDE: u16 = 15;
+
+// Guards against compression pointer loops in hostile packets
+const MAX_POINTER_JUMPS: usize = 16;
+const HEADER_LEN: usize = 12;
+
+/// The (first) question echoed back in a DNS response.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Question {
+    pub name: String,
+    pub qtype: u16,
+    pub qclass: u16,
+}
+
+impl Question {
+    /// Whether this question is the one we asked, comparing names case-insensitively.
+    pub fn matches(&self, name: &str, qtype: u16) -> bool {
+        self.qtype == qtype && self.qclass == QCLASS_IN && self.name.eq_ignore_ascii_case(name.trim_end_matches('.'))
+    }
+}
+
+/// Parse the question section of a message that must carry exactly one question.
+pub fn parse_question(message: &[u8]) -> Option<Question> {
+    if message.len() < HEADER_LEN {
+        return None;
+    }
+
+    let question_count = u16::from_be_bytes([message[4], message[5]]);
+    if question_count != 1 {
+        return None;
+    }
+
+    let (name, offset) = read_name(message, HEADER_LEN)?;
+    let fixed = message.get(offset..offset + 4)?;
+
+    Some(Question {
+        name,
+        qtype: u16::from_be_bytes([fixed[0], fixed[1]]),
+        qclass: u16::from_be_bytes([fixed[2], fixed[3]]),
+    })
+}
+
+/// An Extended DNS Error (RFC 8914) attached to a response.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExtendedError {
+    pub code: u16,
+    /// Optional free-form explanation from the server.
+    pub text: String,
+}
+
+impl ExtendedError {
+    /// IANA name of the error code, if it is one we know.
+    pub fn name(&self) -> Option<&'static str> {
+        Some(match self.code {
+            0 => "Other Error",
+            1 => "Unsupported DNSKEY Algorithm",
+            2 => "Unsupported DS Digest Type",
+            3 => "Stale Answer",
+            4 => "Forged Answer",
+            5 => "DNSSEC Indeterminate",
+            6 => "DNSSEC Bogus",
+            7 => "Signature Expired",
+            8 => "Signature Not Yet Valid",
+            9 => "DNSKEY Missing",
+            10 => "RRSIGs Missing",
+            11 => "No Zone Key Bit Set",
+            12 => "NSEC Missing",
+            13 => "Cached Error",
+            14 => "Not Ready",
+            15 => "Blocked",
+            16 => "Censored",
+            17 => "Filtered",
+            18 => "Prohibited",
+            19 => "Stale NXDOMAIN Answer",
+            20 => "Not Authoritative",
+            21 => "Not Supported",
+            22 => "No Reachable Authority",
+            23 => "Network Error",
+            24 => "Invalid Data",
+            _ => return None,
+        })
+    }
+}
+
+impl fmt::Display for ExtendedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "EDE {}", self.code)?;
+        if let Some(name) = self.name() {
+            write!(f, " ({name})")?;
+        }
+        if !self.text.is_empty() {
+            write!(f, ": {}", self.text)?;
+        }
+        Ok(())
+    }
+}
+
+/// The OPT pseudo-record (RFC 6891) of a response.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Edns {
+    /// UDP payload size the responder can receive.
+    pub udp_size: u16,
+    /// Upper 8 bits of the 12-bit RCODE.
+    pub extended_rcode: u8,
+    pub version: u8,
+    pub extended_errors: Vec<ExtendedError>,
+}
+
+impl Edns {
+    /// Full RCODE combining the header's 4 bits with the OPT record's upper 8 bits.
+    pub fn rcode(&self, header_rcode: u8) -> u16 {
+        (u16::from(self.extended_rcode) << 4) | u16::from(header_rcode & 0x0F)
+    }
+}
+
+/// Append an OPT record advertising `udp_size` to a query and bump its ARCOUNT.
+///
+/// `dnssec_ok` sets the DO bit, asking the server to include RRSIG, NSEC and NSEC3 records.
+pub fn append_opt(message: &mut Vec<u8>, udp_size: u16, dnssec_ok: bool) {
+    if message.len() < HEADER_LEN {
+        return;
+    }
+    let additional = u16::from_be_bytes([message[10], message[11]]).saturating_add(1);
+    message[10..12].copy_from_slice(&additional.to_be_bytes());
+
+    message.push(0x00); // Root owner name
+    message.extend_from_slice(&TYPE_OPT.to_be_bytes());
+    message.extend_from_slice(&udp_size.to_be_bytes()); // CLASS carries the payload size
+    message.extend_from_slice(&[0x00, 0x00]); // Extended RCODE, version 0
+    let flags = if dnssec_ok { EDNS_FLAG_DO } else { 0 };
+    message.extend_from_slice(&flags.to_be_bytes());
+    message.extend_from_slice(&[0x00, 0x00]); // No options
+}
+
+/// SOA record data (RFC 1035 section 3.3.13).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Soa {
+    pub mname: String,
+    pub rname: String,
+    pub serial: u32,
+    pub refresh: u32,
+    pub retry: u32,
+    pub expire: u32,
+    pub minimum: u32,
+}
+
+/// Decoded RDATA of the record types we care about; everything else stays raw.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RecordData {
+    Ns(String),
+    A(Ipv4Addr),
+    Aaaa(Ipv6Addr),
+    Soa(Soa),
+    Other(Vec<u8>),
+}
+
+/// A resource record from the answer, authority or additional section.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Record {
+    pub name: String,
+    pub rtype: u16,
+    pub class: u16,
+    pub ttl: u32,
+    pub data: RecordData,
+}
+
+/// A fully parsed DNS message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Message {
+    pub id: u16,
+    pub flags: u16,
+    pub questions: Vec<Question>,
+    pub answers: Vec<Record>,
+    pub authorities: Vec<Record>,
+    /// Additional records, without the OPT pseudo-record (see `edns`).
+    pub additionals: Vec<Record>,
+    pub edns: Option<Edns>,
+}
+
+impl Message {
+    /// Full RCODE, including the OPT record's extended bits when present.
+    pub fn rcode(&self) -> u16 {
+        let header_rcode = (self.flags & 0x000F) as u8;
+        self.edns
+            .as_ref()
+            .map_or(u16::from(header_rcode), |edns| edns.rcode(header_rcode))
+    }
+
+    /// Nameserver hostnames `name` is delegated to, from NS records in the answer section
+    /// (recursive resolvers) or the authority section (referrals from a TLD server).
+    pub fn nameservers(&self, name: &str) -> Vec<String> {
+        let name = name.trim_end_matches('.');
+        let mut nameservers: Vec<String> = Vec::new();
+        for record in self.answers.iter().chain(&self.authorities) {
+            if let RecordData::Ns(host) = &record.data
+                && record.name.eq_ignore_ascii_case(name)
+            {
+                let host = host.to_ascii_lowercase();
+                if !nameservers.contains(&host) {
+                    nameservers.push(host);
+                }
+            }
+        }
+        nameservers
+    }
+
+    /// Glue addresses from the additional section for the given nameserver hostnames.
+    pub fn glue(&self, nameservers: &[String]) -> Vec<(String, IpAddr)> {
+        self.additionals
+            .iter()
+            .filter_map(|record| {
+                let address = match record.data {
+                    RecordData::A(v4) => IpAddr::V4(v4),
+                    RecordData::Aaaa(v6) => IpAddr::V6(v6),
+                    _ => return None,
+                };
+                let host = record.name.to_ascii_lowercase();
+                nameservers.contains(&host).then_some((host, address))
+            })
+            .collect()
+    }
+
+    /// A and AAAA records from the answer section, e.g. a resolver's answer to an address
+    /// query (CNAMEs it followed included).
+    pub fn addresses(&self) -> Vec<IpAddr> {
+        self.answers
+            .iter()
+            .filter_map(|record| match record.data {
+                RecordData::A(v4) => Some(IpAddr::V4(v4)),
+                RecordData::Aaaa(v6) => Some(IpAddr::V6(v6)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// TTL of the NS records `name` is delegated with (the smallest, if they differ).
+    pub fn nameserver_ttl(&self, name: &str) -> Option<u32> {
+        let name = name.trim_end_matches('.');
+        self.answers
+            .iter()
+            .chain(&self.authorities)
+            .filter(|record| matches!(record.data, RecordData::Ns(_)) && record.name.eq_ignore_ascii_case(name))
+            .map(|record| record.ttl)
+            .min()
+    }
+
+    /// How long a negative answer (NXDOMAIN or NODATA) may be cached: the smaller of the SOA
+    /// record's TTL and its MINIMUM field (RFC 2308 section 5).
+    pub fn negative_ttl(&self) -> Option<u32> {
+        self.answers
+            .iter()
+            .chain(&self.authorities)
+            .find_map(|record| match &record.data {
+                RecordData::Soa(soa) => Some(record.ttl.min(soa.minimum)),
+                _ => None,
+            })
+    }
+
+    /// DNSKEY, DS, RRSIG, NSEC and NSEC3 records from the answer and authority sections.
+    ///
+    /// Their RDATA stays raw: none of these types may be name-compressed (RFC 4034), so it
+    /// is exactly what the signatures cover.
+    pub fn dnssec_records(&self) -> Vec<Record> {
+        self.answers
+            .iter()
+            .chain(&self.authorities)
+            .filter(|record| {
+                matches!(
+                    record.rtype,
+                    TYPE_DNSKEY | TYPE_DS | TYPE_RRSIG | TYPE_NSEC | TYPE_NSEC3
+                )
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// The first SOA record in the answer or authority section.
+    pub fn soa(&self) -> Option<&Soa> {
+        self.answers
+            .iter()
+            .chain(&self.authorities)
+            .find_map(|record| match &record.data {
+                RecordData::Soa(soa) => Some(soa),
+                _ => None,
+            })
+    }
+}
+
+/// Parse a complete DNS message: header, question, answer, authority and additional sections.
+///
+/// Returns `None` if any section is truncated or malformed.
+pub fn parse_message(message: &[u8]) -> Option<Message> {
+    let header = message.get(..HEADER_LEN)?;
+    let count = |at: usize| u16::from_be_bytes([header[at], header[at + 1]]) as usize;
+    let (question_count, answer_count, authority_count, additional_count) = (count(4), count(6), count(8), count(10));
+
+    let mut offset = HEADER_LEN;
+    let mut questions = Vec::with_capacity(question_count.min(4));
+    for _ in 0..question_count {
+        let (name, after_name) = read_name(message, offset)?;
+        let fixed = message.get(after_name..after_name + 4)?;
+        questions.push(Question {
+            name,
+            qtype: u16::from_be_bytes([fixed[0], fixed[1]]),
+            qclass: u16::from_be_bytes([fixed[2], fixed[3]]),
+        });
+        offset = after_name + 4;
+    }
+
+    let mut read_section = |count: usize| -> Option<Vec<Record>> {
+        let mut records = Vec::with_capacity(count.min(32));
+        for _ in 0..count {
+            let (record, next) = read_record(message, offset)?;
+            records.push(record);
+            offset = next;
+        }
+        Some(records)
+    };
+    let answers = read_section(answer_count)?;
+    let authorities = read_section(authority_count)?;
+    let mut additionals = read_section(additional_count)?;
+
+    let edns = additionals
+        .iter()
+        .position(|record| record.rtype == TYPE_OPT && record.name.is_empty())
+        .map(|index| {
+            let opt = additionals.remove(index);
+            let [extended_rcode, version, _, _] = opt.ttl.to_be_bytes();
+            Edns {
+                udp_size: opt.class,
+                extended_rcode,
+                version,
+                extended_errors: match &opt.data {
+                    RecordData::Other(options) => parse_extended_errors(options),
+                    _ => Vec::new(),
+                },
+            }
+        });
+
+    Some(Message {
+        id: u16::from_be_bytes([header[0], header[1]]),
+        flags: u16::from_be_bytes([header[2], header[3]]),
+        questions,
+        answers,
+        authorities,
+        additionals,
+        edns,
+    })
+}
+
+/// Read the resource record at `offset`, returning it and the offset just past it.
+fn read_record(message: &[u8], offset: usize) -> Option<(Record, usize)> {
+    let (name, after_name) = read_name(message, offset)?;
+    let fixed = message.get(after_name..after_name + 10)?;
+    let rtype = u16::from_be_bytes([fixed[0], fixed[1]]);
+    let class = u16::from_be_bytes([fixed[2], fixed[3]]);
+    let ttl = u32::from_be_bytes([fixed[4], fixed[5], fixed[6], fixed[7]]);
+    let rdata_start = after_name + 10;
+    let rdata_end = rdata_start + u16::from_be_bytes([fixed[8], fixed[9]]) as usize;
+    let rdata = message.get(rdata_start..rdata_end)?;
+
+    let data = match rtype {
+        TYPE_A => RecordData::A(Ipv4Addr::from(<[u8; 4]>::try_from(rdata).ok()?)),
+        TYPE_AAAA => RecordData::Aaaa(Ipv6Addr::from(<[u8; 16]>::try_from(rdata).ok()?)),
+        // Names inside RDATA may point anywhere in the message, so decode against the whole of it
+        QTYPE_NS => RecordData::Ns(read_name(message, rdata_start)?.0),
+        TYPE_SOA => {
+            let (mname, after_mname) = read_name(message, rdata_start)?;
+            let (rname, after_rname) = read_name(message, after_mname)?;
+            let numbers = message.get(after_rname..after_rname + 20)?;
+            let number = |i: usize| u32::from_be_bytes([numbers[i], numbers[i + 1], numbers[i + 2], numbers[i + 3]]);
+            RecordData::Soa(Soa {
+                mname,
+                rname,
+                serial: number(0),
+                refresh: number(4),
+                retry: number(8),
+                expire: number(12),
+                minimum: number(16),
+            })
+        }
+        _ => RecordData::Other(rdata.to_vec()),
+    };
+
+    Some((
+        Record {
+            name,
+            rtype,
+            class,
+            ttl,
+            data,
+        },
+        rdata_end,
+    ))
+}
+
+fn parse_extended_errors(mut options: &[u8]) -> Vec<ExtendedError> {
+    let mut errors = Vec::new();
+
+    while options.len() >= 4 {
+        let code = u16::from_be_bytes([options[0], options[1]]);
+        let len = u16::from_be_bytes([options[2], options[3]]) as usize;
+        let Some(data) = options.get(4..4 + len) else {
+            break;
+        };
+        if code == OPTION_EDE && data.len() >= 2 {
+            errors.push(ExtendedError {
+                code: u16::from_be_bytes([data[0], data[1]]),
+                text: String::from_utf8_lossy(&data[2..]).trim_end_matches('\0').to_string(),
+            });
+        }
+        options = &options[4 + len..];
+    }
+
+    errors
+}
+
+/// Read a possibly compressed domain name starting at `offset`.
+///
+/// Returns the dotted name (without trailing dot, empty for the root) and the offset
+/// just past the name in the original position.
+pub fn read_name(message: &[u8], offset: usize) -> Option<(String, usize)> {
+    let mut labels: Vec<String> = Vec::new();
+    let mut position = offset;
+    let mut end = None;
+    let mut jumps = 0;
+
+    loop {
+        let len = *message.get(position)? as usize;
+
+        match len & 0xC0 {
+            0x00 if len == 0 => {
+                end.get_or_insert(position + 1);
+                break;
+            }
+            0x00 => {
+                let label = message.get(position + 1..position + 1 + len)?;
+                labels.push(String::from_utf8_lossy(label).into_owned());
+                position += 1 + len;
+            }
+            0xC0 => {
+                let low = *message.get(position + 1)? as usize;
+                end.get_or_insert(position + 2);
+                jumps += 1;
+                if jumps > MAX_POINTER_JUMPS {
+                    return None;
+                }
+                position = ((len & 0x3F) << 8) | low;
+            }
+            // 0x40 and 0x80 label types are obsolete/reserved
+            _ => return None,
+        }
+    }
+
+    Some((labels.join("."), end?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message_with_question(name: &str, qtype: u16) -> Vec<u8> {
+        let mut message = vec![0x12, 0x34, 0x80, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
+        for label in name.split('.') {
+            message.push(label.len() as u8);
+            message.extend_from_slice(label.as_bytes());
+        }
+        message.push(0);
+        message.extend_from_slice(&qtype.to_be_bytes());
+        message.extend_from_slice(&QCLASS_IN.to_be_bytes());
+        message
+    }
+
+    #[test]
+    fn parse_question_reads_echoed_question() {
+        let message = message_with_question("Example.COM", QTYPE_NS);
+        let question = parse_question(&message).unwrap();
+        assert!(question.matches("example.com", QTYPE_NS));
+        assert!(!question.matches("example.net", QTYPE_NS));
+        assert!(!question.matches("example.com", 1));
+    }
+
+    #[test]
+    fn read_name_follows_compression_pointers() {
+        let mut message = message_with_question("example.com", QTYPE_NS);
+        let pointer_at = message.len();
+        // "www" + pointer to offset 12 ("example.com")
+        message.extend_from_slice(&[3, b'w', b'w', b'w', 0xC0, 12]);
+
+        let (name, next) = read_name(&message, pointer_at).unwrap();
+        assert_eq!(name, "www.example.com");
+        assert_eq!(next, message.len());
+    }
+
+    fn push_record(message: &mut Vec<u8>, owner: &[u8], rtype: u16, rdata: &[u8]) -> usize {
+        message.extend_from_slice(owner);
+        message.extend_from_slice(&rtype.to_be_bytes());
+        message.extend_from_slice(&QCLASS_IN.to_be_bytes());
+        message.extend_from_slice(&3600u32.to_be_bytes());
+        message.extend_from_slice(&(rdata.len() as u16).to_be_bytes());
+        let rdata_at = message.len();
+        message.extend_from_slice(rdata);
+        rdata_at
+    }
+
+    #[test]
+    fn parse_message_extracts_delegation_and_glue() {
+        let mut message = message_with_question("example.com", QTYPE_NS);
+        message[9] = 2; // Two authority records
+        message[11] = 1; // One additional record
+
+        // Owners point at the question name (offset 12); ns2's RDATA points into ns1's
+        let question_name = [0xC0, 12];
+        let ns1_at = push_record(
+            &mut message,
+            &question_name,
+            QTYPE_NS,
+            b"\x03ns1\x07parking\x04test\x00",
+        );
+        let suffix = [0xC0, (ns1_at + 4) as u8];
+        push_record(
+            &mut message,
+            &question_name,
+            QTYPE_NS,
+            &[b"\x03ns2".as_slice(), &suffix].concat(),
+        );
+        push_record(&mut message, &[0xC0, ns1_at as u8], TYPE_A, &[192, 0, 2, 53]);
+
+        let parsed = parse_message(&message).unwrap();
+        let nameservers = parsed.nameservers("Example.com.");
+        assert_eq!(nameservers, vec!["ns1.parking.test", "ns2.parking.test"]);
+        assert_eq!(
+            parsed.glue(&nameservers),
+            vec![("ns1.parking.test".to_string(), IpAddr::from([192, 0, 2, 53]))]
+        );
+        assert!(parsed.soa().is_none());
+        assert_eq!(parsed.nameserver_ttl("example.com"), Some(3600));
+        assert_eq!(parsed.negative_ttl(), None);
+    }
+
+    #[test]
+    fn parse_message_reads_soa() {
+        let mut message = message_with_question("example.com", QTYPE_NS);
+        message[3] = 0x03; // NXDOMAIN
+        message[9] = 1;
+
+        let mut rdata = b"\x01a\x0cgtld-servers\x03net\x00\x05nstld\x0cverisign-grs\xc0\x14".to_vec();
+        for value in [1_700_000_000u32, 1800, 900, 604_800, 900] {
+            rdata.extend_from_slice(&value.to_be_bytes());
+        }
+        push_record(&mut message, b"\x03com\x00", TYPE_SOA, &rdata);
+
+        let parsed = parse_message(&message).unwrap();
+        assert_eq!(parsed.rcode(), 3);
+        let soa = parsed.soa().unwrap();
+        assert_eq!(soa.mname, "a.gtld-servers.net");
+        assert_eq!(soa.rname, "nstld.verisign-grs.com");
+        assert_eq!(soa.serial, 1_700_000_000);
+        assert_eq!(soa.minimum, 900);
+        // The record's TTL is 3600, the MINIMUM field 900
+        assert_eq!(parsed.negative_ttl(), Some(900));
+    }
+
+    #[test]
+    fn dnssec_records_keep_raw_rdata() {
+        let mut message = message_with_question("example.com", QTYPE_NS);
+        message[3] = 0x03;
+        message[9] = 2;
+        // NSEC "com" -> "example0.com" with a type bitmap; the name must not be decoded
+        let nsec = b"\x08example0\x03com\x00\x00\x01\x62";
+        push_record(&mut message, b"\x03com\x00", TYPE_NSEC, nsec);
+        push_record(&mut message, b"\x03com\x00", TYPE_A, &[192, 0, 2, 1]);
+
+        let records = parse_message(&message).unwrap().dnssec_records();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].name, "com");
+        assert_eq!(records[0].data, RecordData::Other(nsec.to_vec()));
+    }
+
+    #[test]
+    fn parse_message_rejects_missing_records() {
+        let mut message = message_with_question("example.com", QTYPE_NS);
+        message[7] = 1; // Claims an answer that isn't there
+        assert!(parse_message(&message).is_none());
+    }
+
+    #[test]
+    fn parse_edns_reads_extended_rcode_and_errors() {
+        let mut message = message_with_question("example.com", QTYPE_NS);
+        append_opt(&mut message, 1232, false);
+        assert_eq!(u16::from_be_bytes([message[10], message[11]]), 1);
+
+        // Turn the bare OPT into one with extended RCODE 1 (BADVERS) and an EDE option
+        let opt = message.len() - 11;
+        message[opt + 5] = 1;
+        let text = b"blocked by policy";
+        let option_len = 2 + text.len();
+        let rdata_len = (4 + option_len) as u16;
+        message.truncate(message.len() - 2);
+        message.extend_from_slice(&rdata_len.to_be_bytes());
+        message.extend_from_slice(&OPTION_EDE.to_be_bytes());
+        message.extend_from_slice(&(option_len as u16).to_be_bytes());
+        message.extend_from_slice(&15u16.to_be_bytes());
+        message.extend_from_slice(text);
+
+        let edns = parse_message(&message).unwrap().edns.unwrap();
+        assert_eq!(edns.udp_size, 1232);
+        assert_eq!(edns.rcode(0), 16);
+        assert_eq!(edns.extended_errors.len(), 1);
+        assert_eq!(
+            edns.extended_errors[0].to_string(),
+            "EDE 15 (Blocked): blocked by policy"
+        );
+    }
+
+    #[test]
+    fn parse_edns_returns_none_without_opt() {
+        let message = message_with_question("example.com", QTYPE_NS);
+        assert!(parse_message(&message).unwrap().edns.is_none());
+    }
+
+    #[test]
+    fn read_name_rejects_pointer_loops() {
+        let mut message = vec![0; HEADER_LEN];
+        message.extend_from_slice(&[0xC0, 12]);
+        assert!(read_name(&message, HEADER_LEN).is_none());
+    }
+}
@@ -20,14 +20,52 @@
 //! - DNS servers can process queries concurrently
 //! - Don't wait for response before sending next query
 //!
+//! **Socket pool**: Queries are spread round-robin across several UDP sockets
+//! - Each socket has its own 16-bit transaction ID space
+//! - Pending queries are keyed by (socket, server, ID), so more than 65k can be in flight
+//! - An ID still in use for a (socket, server) pair is never handed out again
+//!
 //! **Batching**: Accumulate queries before sending to reduce per-query overhead
 //! - Amortizes async runtime overhead across many queries
 //! - Every 100μs, send accumulated batch (up to 256 queries)
-//! - Receive side drains socket buffer opportunistically
+//! - Receive side drains socket buffer opportunistically into reusable buffers
+//! - On Linux a whole batch is one `sendmmsg`/`recvmmsg` system call (see `dns_batch`)
+//!
+//! **Resolver fallback**: `query_local_resolver` asks a recursive resolver instead
+//! - Plain UDP to the system's resolvers by default
+//! - Or DNS over TLS (`dns_tls`) or DNS over HTTPS (`dns_https`) to configured upstreams
+//!
+//! **TCP fallback**: Responses with the TC (truncated) bit set are re-queried over TCP
+//! - Uses a small pool of length-prefixed connections per authoritative server
+//! - The retry inherits whatever remains of the original query's timeout
+//!
+//! **Rate limiting**: Every server IP and every TLD has a token bucket (see `dns_ratelimit`)
+//! - The send loop only sends a query when both its buckets have a token
+//! - Throttled queries stay queued; their timeout starts once they are on the wire
+//! - A query that waits longer than its own timeout for a token fails with `RateLimited`
+//!
+//! **EDNS0**: Queries carry an OPT record advertising a larger UDP payload (1232 bytes by
+//! default), so fewer answers come back truncated. Replies' OPT records are parsed for the
+//! extended RCODE and Extended DNS Errors (RFC 8914), which ride along on SERVFAIL/REFUSED errors.
+//! With `dnssec_ok` the OPT record also sets the DO bit, and answers keep their DNSSEC records
+//! for [`crate::dnssec`] to validate.
+//!
+//! # Adaptive Timeouts
+//!
+//! Every matched UDP response feeds an RFC 6298-style RTT estimator for the server's IP
+//! (see `dns_rtt`), and every timeout backs it off. Callers ask [`PipelinedDnsClient::server_timeout`]
+//! for a per-query timeout derived from those numbers, capped by the user's `--timeout`.
+//!
+//! # Response Validation
+//!
+//! A response only completes a pending query if its transaction ID, source address and
+//! echoed question (name, type, class) all match what was sent. Anything else is dropped
+//! and counted, so stray, spoofed or late replies to recycled IDs can't decide a verdict.
 //!
 //! # Performance Characteristics
 //!
-//! - **Throughput**: 50,000+ queries/second on modern hardware
+//! - **Throughput**: 50,000+ queries/second on modern Linux hardware (vectored syscalls);
+//!   other platforms pay one syscall per datagram
 //! - **Latency**: ~100-500μs per query (network + batching overhead)
 //! - **Concurrency**: Limited by semaphore in Checker, not by this module
 //!
@@ -37,11 +75,20 @@
 //! - Higher batching = better throughput, worse tail latency
 //! - Uses more memory to buffer queries (see QUERY_CHANNEL_SIZE)
 
-use crate::dns_batch::BatchDnsSocket;
+use crate::dns_batch::{BatchDnsSocket, RecvBatch, SendBatch};
+use crate::dns_cache::{DEFAULT_CACHE_SIZE, DnsCache};
+use crate::dns_https::DohClient;
+use crate::dns_message::{ExtendedError, QTYPE_NS, Record, Soa, append_opt, parse_message, parse_question};
+use crate::dns_ratelimit::{RateLimitConfig, RateLimiter};
+use crate::dns_rtt::RttTable;
+use crate::dns_tcp::TcpConnectionPool;
+use crate::dns_tls::TlsConnectionPool;
+use crate::transport::{ResolverTransport, TlsUpstream};
 use dashmap::DashMap;
-use std::net::SocketAddr;
+use dashmap::mapref::entry::Entry;
+use std::net::{IpAddr, SocketAddr};
 use std::sync::Arc;
-use std::sync::atomic::{AtomicU16, Ordering};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::{Duration, Instant};
 use thiserror::Error;
 use tokio::sync::{Mutex, mpsc, oneshot};
@@ -51,11 +98,16 @@ use tracing::{debug, warn};
 // DNS batching constants
 
 const QUERY_CHANNEL_SIZE: usize = 10_000;
-const BATCH_TRIGGER_SIZE: usize = 64;
 const MAX_BATCH_SIZE: usize = 256;
 const MAX_RECV_BATCH_SIZE: usize = 256;
 const BATCH_SEND_INTERVAL_US: u64 = 100;
 const TIMEOUT_CHECK_INTERVAL_MS: u64 = 100;
+// How long the send loop waits before rescanning a queue whose queries are all throttled
+const THROTTLED_RETRY_INTERVAL_MS: u64 = 1;
+/// How long a fallback resolver gets to answer.
+const RESOLVER_TIMEOUT_MS: u64 = 2000;
+/// Advertised EDNS0 UDP payload size; 1232 avoids IP fragmentation on virtually every path (DNS Flag Day 2020).
+pub const DEFAULT_EDNS_UDP_SIZE: u16 = 1232;
 
 /// DNS module Result type alias
 pub type Result<T> = std::result::Result<T, DnsError>;
@@ -75,89 +127,324 @@ pub enum DnsError {
     #[error("Domain does not exist (NXDOMAIN): not registered in DNS")]
     NameError,
 
-    #[error("DNS server failure (SERVFAIL): authoritative server encountered an error")]
-    ServerFailure,
+    #[error("DNS server failure (SERVFAIL): authoritative server encountered an error{}", ede_suffix(.0))]
+    ServerFailure(Option<ExtendedError>),
+
+    #[error("DNS query refused (REFUSED): server declined to answer{}", ede_suffix(.0))]
+    Refused(Option<ExtendedError>),
 
     #[error("DNS format error (FORMERR): server could not parse query")]
     FormatError,
 
+    #[error("Unsupported EDNS version (BADVERS): server does not speak EDNS version 0")]
+    BadVersion,
+
+    #[error("DNS response truncated (TC): answer must be retried over TCP")]
+    Truncated,
+
+    #[error("Query rate limit reached: query waited too long for its server or TLD to allow it")]
+    RateLimited,
+
+    #[error("Too many in-flight queries to one server: transaction ID space exhausted")]
+    IdSpaceExhausted,
+
     #[error("Internal error: DNS query channel closed unexpectedly")]
     ChannelClosed,
 }
 
+/// What an NS query found out about a domain.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NsAnswer {
+    /// Whether the server answered NOERROR with any answer or authority records, i.e. the name exists.
+    pub has_records: bool,
+    /// Hostnames of the nameservers the domain is delegated to (lowercase, no trailing dot).
+    pub nameservers: Vec<String>,
+    /// Glue addresses for those nameservers from the additional section.
+    pub glue: Vec<(String, IpAddr)>,
+    /// A and AAAA records from the answer section, which only address queries get.
+    pub addresses: Vec<IpAddr>,
+    /// SOA record from the answer or authority section (e.g. the TLD's SOA on NXDOMAIN).
+    pub soa: Option<Soa>,
+    /// Response code, including EDNS extended bits (0 = NOERROR, 3 = NXDOMAIN).
+    pub rcode: u16,
+    /// Whether the AA (authoritative answer) flag was set.
+    pub authoritative: bool,
+    /// Address of the server that answered.
+    pub server: Option<IpAddr>,
+    /// Time from sending the query to receiving this answer.
+    pub rtt: Duration,
+    /// Whether [`PipelinedDnsClient::query_ns`] took this answer from its cache instead of
+    /// asking the server; `rtt` is then the original query's.
+    pub cached: bool,
+    /// How long the answer may be cached, in seconds: the TTL of the delegation's NS records,
+    /// or for NXDOMAIN and NODATA the SOA's negative TTL. `None` if the answer has neither.
+    pub ttl: Option<u32>,
+    /// DNSKEY, DS, RRSIG, NSEC and NSEC3 records, which servers only send when the query
+    /// set the DO bit (see [`DnsClientConfig::dnssec_ok`]).
+    pub dnssec: Vec<Record>,
+}
+
+impl NsAnswer {
+    /// An NXDOMAIN answer without any further detail.
+    pub fn nxdomain() -> Self {
+        Self {
+            rcode: 3,
+            ..Self::default()
+        }
+    }
+
+    /// Record which server answered and how long it took.
+    fn answered_by(mut self, server: IpAddr, rtt: Duration) -> Self {
+        self.server = Some(server);
+        self.rtt = rtt;
+        self
+    }
+}
+
+fn ede_suffix(ede: &Option<ExtendedError>) -> String {
+    ede.as_ref().map(|ede| format!(" [{ede}]")).unwrap_or_default()
+}
+
+/// Identifies an in-flight query: the socket it was sent from, the server it was sent to
+/// and its transaction ID. IDs are only unique within a (socket, server) pair.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct PendingKey {
+    socket: usize,
+    server: SocketAddr,
+    id: u16,
+}
+
+type PendingMap = DashMap<PendingKey, PendingQuery>;
+
 #[derive(Debug)]
 struct PendingQuery {
-    response_tx: oneshot::Sender<Result<bool>>,
-    sent_at: Instant,
+    response_tx: oneshot::Sender<Result<NsAnswer>>,
+    queued_at: Instant,
+    /// Set when the query leaves the send queue; `None` while it waits for a rate limit token.
+    sent_at: Option<Instant>,
     timeout_ms: u64,
+    domain: String,
+    qtype: u16,
+    server_addr: SocketAddr,
+    edns_udp_size: Option<u16>,
+    dnssec_ok: bool,
+}
+
+impl PendingQuery {
+    /// Whether `response` echoes the question this query asked.
+    fn matches_question(&self, response: &[u8]) -> bool {
+        parse_question(response).is_some_and(|question| question.matches(&self.domain, self.qtype))
+    }
+}
+
+/// Tunables for [`PipelinedDnsClient`].
+#[derive(Debug, Clone)]
+pub struct DnsClientConfig {
+    /// Longest time an answer is cached, whatever its TTL.
+    pub cache_ttl: Duration,
+    /// Number of answers cached before the least recently used are evicted.
+    pub cache_size: usize,
+    /// Number of UDP sockets (and therefore independent transaction ID spaces) to use.
+    pub socket_count: usize,
+    pub rate_limit: RateLimitConfig,
+    /// UDP payload size advertised in an EDNS0 OPT record; `None` sends plain RFC 1035 queries.
+    pub edns_udp_size: Option<u16>,
+    /// Set the DO bit in the OPT record, so answers carry their DNSSEC records. Needs EDNS0.
+    pub dnssec_ok: bool,
+    /// How `query_local_resolver` reaches its resolvers.
+    pub resolver_transport: ResolverTransport,
+    /// Resolvers `query_local_resolver` asks over [`ResolverTransport::Udp`], instead of
+    /// the system's when not empty.
+    pub resolvers: Vec<String>,
+}
+
+impl Default for DnsClientConfig {
+    fn default() -> Self {
+        Self {
+            cache_ttl: Duration::from_secs(300),
+            cache_size: DEFAULT_CACHE_SIZE,
+            socket_count: 4,
+            rate_limit: RateLimitConfig::default(),
+            edns_udp_size: Some(DEFAULT_EDNS_UDP_SIZE),
+            dnssec_ok: false,
+            resolver_transport: ResolverTransport::Udp,
+            resolvers: Vec::new(),
+        }
+    }
+}
+
+/// The fallback resolvers, with the connections their transport keeps.
+enum Fallback {
+    /// Plain DNS to these resolvers, or the system's if there are none.
+    Udp(Vec<String>),
+    Tls(TlsConnectionPool, Vec<TlsUpstream>),
+    Https(DohClient, Vec<String>),
+}
+
+impl Fallback {
+    /// The encrypted upstreams, in the order they're tried.
+    fn upstreams(&self) -> Vec<String> {
+        match self {
+            Fallback::Udp(_) => Vec::new(),
+            Fallback::Tls(_, upstreams) => upstreams.iter().map(ToString::to_string).collect(),
+            Fallback::Https(_, urls) => urls.clone(),
+        }
+    }
+
+    /// Send `query` to encrypted upstream number `index` and return its answer.
+    async fn exchange(&self, index: usize, query: &[u8]) -> std::io::Result<Vec<u8>> {
+        match self {
+            Fallback::Udp(_) => Err(std::io::Error::other("no encrypted resolver configured")),
+            Fallback::Tls(pool, upstreams) => pool.query(&upstreams[index], query).await,
+            Fallback::Https(client, urls) => client.query(&urls[index], query).await,
+        }
+    }
 }
 
 #[derive(Clone)]
 pub struct PipelinedDnsClient {
     query_tx: mpsc::Sender<QueryRequest>,
-    cache: Arc<DashMap<String, (bool, Instant)>>,
-    cache_ttl: Duration,
+    cache: Arc<DnsCache>,
+    mismatched_responses: Arc<AtomicU64>,
+    rtt: Arc<RttTable>,
+    dnssec_ok: bool,
+    edns_udp_size: Option<u16>,
+    fallback: Arc<Fallback>,
 }
 
 struct QueryRequest {
     domain: String,
+    qtype: u16,
     server: String, // Already an IP address
     timeout_ms: u64,
-    response_tx: oneshot::Sender<Result<bool>>,
+    /// Set the DO bit; only takes effect with EDNS0.
+    dnssec_ok: bool,
+    response_tx: oneshot::Sender<Result<NsAnswer>>,
 }
 
 impl PipelinedDnsClient {
-    pub async fn new(bind_addr: &str, cache_ttl: Duration) -> Result<Self> {
-        let batch_socket = Arc::new(BatchDnsSocket::new(bind_addr).await?);
-        let cache = Arc::new(DashMap::new());
+    pub async fn new(bind_addr: &str, config: DnsClientConfig) -> Result<Self> {
+        // Each socket binds its own ephemeral port, which also spreads source ports
+        let mut sockets = Vec::with_capacity(config.socket_count.max(1));
+        for _ in 0..config.socket_count.max(1) {
+            sockets.push(Arc::new(BatchDnsSocket::new(bind_addr).await?));
+        }
+
+        let cache = Arc::new(DnsCache::new(config.cache_size, config.cache_ttl));
+        let mismatched_responses = Arc::new(AtomicU64::new(0));
+        let rtt = Arc::new(RttTable::new());
+        let limiter = Arc::new(RateLimiter::new(config.rate_limit));
+        let edns_udp_size = config.edns_udp_size;
+        let dnssec_ok = config.dnssec_ok && edns_udp_size.is_some();
+        let fallback = Arc::new(match config.resolver_transport {
+            ResolverTransport::Udp => Fallback::Udp(config.resolvers),
+            ResolverTransport::Tls(upstreams) => Fallback::Tls(TlsConnectionPool::new()?, upstreams),
+            ResolverTransport::Https(urls) => Fallback::Https(DohClient::new(), urls),
+        });
         let (query_tx, query_rx) = mpsc::channel(QUERY_CHANNEL_SIZE);
 
         // Spawn the main processing task
-        let batch_socket_clone = batch_socket.clone();
-        let cache_clone = cache.clone();
+        let mismatched_clone = mismatched_responses.clone();
+        let rtt_clone = rtt.clone();
         tokio::spawn(async move {
-            process_queries_batch(batch_socket_clone, query_rx, cache_clone).await;
+            process_queries_batch(sockets, query_rx, mismatched_clone, rtt_clone, limiter, edns_udp_size).await;
         });
 
         Ok(Self {
             query_tx,
             cache,
-            cache_ttl,
+            mismatched_responses,
+            rtt,
+            dnssec_ok,
+            edns_udp_size,
+            fallback,
         })
     }
 
-    pub async fn query_ns(&self, domain: &str, server: &str, timeout_ms: u64) -> Result<bool> {
-        let cache_key = format!("{domain}-{server}");
+    /// Timeout for the next query to `server`, derived from its measured RTT and capped at `max_ms`.
+    ///
+    /// Servers that haven't answered yet get `initial_ms` (also capped at `max_ms`).
+    pub fn server_timeout(&self, server: &str, initial_ms: u64, max_ms: u64) -> u64 {
+        match resolve_server_addr(server) {
+            Ok(addr) => self.rtt.timeout_ms(addr.ip(), initial_ms, max_ms),
+            Err(_) => initial_ms.min(max_ms),
+        }
+    }
 
-        // Check cache first
-        if let Some(entry) = self.cache.get(&cache_key) {
-            if entry.1.elapsed() < self.cache_ttl {
-                debug!("Cache hit for domain {} on server {}: {}", domain, server, entry.0);
-                return Ok(entry.0);
-            }
-            self.cache.remove(&cache_key);
-            debug!("Cache expired for domain {} on server {}", domain, server);
+    /// Number of responses dropped because they didn't complete any pending query: wrong
+    /// source address, a different echoed question, or a late reply to a finished query.
+    pub fn mismatched_responses(&self) -> u64 {
+        self.mismatched_responses.load(Ordering::Relaxed)
+    }
+
+    /// Save the cached answers that haven't expired to `path`; returns how many.
+    pub fn save_cache(&self, path: &std::path::Path) -> std::io::Result<usize> {
+        self.cache.save(path)
+    }
+
+    /// Add the unexpired answers saved at `path` to the cache; returns how many.
+    pub fn load_cache(&self, path: &std::path::Path) -> std::io::Result<usize> {
+        self.cache.load(path)
+    }
+
+    pub async fn query_ns(&self, domain: &str, server: &str, timeout_ms: u64) -> Result<NsAnswer> {
+        // A negative answer cached without DO (e.g. from an earlier run) has nothing to validate
+        let cached = self
+            .cache
+            .get(domain)
+            .filter(|answer| !self.dnssec_ok || !answer.nameservers.is_empty() || !answer.dnssec.is_empty());
+        if let Some(answer) = cached {
+            debug!(
+                "Cache hit for domain {} on server {}: rcode={}, has_records={}",
+                domain, server, answer.rcode, answer.has_records
+            );
+            return Ok(NsAnswer { cached: true, ..answer });
         }
 
+        let result = self.query(domain, QTYPE_NS, server, timeout_ms).await;
+        if let Ok(answer) = &result {
+            self.cache.insert(domain, answer);
+        }
+        result
+    }
+
+    /// Send one uncached query of type `qtype`. Only NS answers fill in the delegation fields
+    /// of [`NsAnswer`].
+    pub async fn query(&self, domain: &str, qtype: u16, server: &str, timeout_ms: u64) -> Result<NsAnswer> {
+        self.send_query(domain, qtype, server, timeout_ms, self.dnssec_ok).await
+    }
+
+    /// [`Self::query`] with the DO bit set whatever [`DnsClientConfig::dnssec_ok`] says, for
+    /// the DS, DNSKEY and NSEC records [`crate::dnssec`] and [`crate::zone_walk`] need.
+    pub async fn query_signed(&self, domain: &str, qtype: u16, server: &str, timeout_ms: u64) -> Result<NsAnswer> {
+        self.send_query(domain, qtype, server, timeout_ms, true).await
+    }
+
+    async fn send_query(
+        &self,
+        domain: &str,
+        qtype: u16,
+        server: &str,
+        timeout_ms: u64,
+        dnssec_ok: bool,
+    ) -> Result<NsAnswer> {
         // Send query request to the processing task
         let (response_tx, response_rx) = oneshot::channel();
         let request = QueryRequest {
             domain: domain.to_string(),
+            qtype,
             server: server.to_string(),
             timeout_ms,
+            dnssec_ok,
             response_tx,
         };
 
         self.query_tx.send(request).await.map_err(|_| DnsError::ChannelClosed)?;
 
-        // Wait for response
-        match timeout(Duration::from_millis(timeout_ms), response_rx).await {
-            Ok(Ok(result)) => {
-                if let Ok(has_ns_records) = result {
-                    self.cache.insert(cache_key, (has_ns_records, Instant::now()));
-                }
-                result
-            }
+        // Wait for response. The send loop and timeout checker enforce the real deadlines (waiting
+        // for a rate limit token, then `timeout_ms` on the wire); this is only a backstop
+        match timeout(Duration::from_millis(timeout_ms.saturating_mul(2)), response_rx).await {
+            Ok(Ok(result)) => result,
             Ok(Err(_)) => Err(DnsError::ChannelClosed),
             Err(_) => Err(DnsError::Timeout),
         }
@@ -165,104 +452,197 @@ impl PipelinedDnsClient {
 
     /// Query local resolver for a domain
     /// This is used as a fallback when all authoritative servers fail
-    pub async fn query_local_resolver(&self, domain: &str) -> Result<bool> {
-        // Get system DNS resolvers
-        let resolvers = get_system_resolvers();
+    pub async fn query_local_resolver(&self, domain: &str) -> Result<NsAnswer> {
+        self.query_resolver(domain, QTYPE_NS, RESOLVER_TIMEOUT_MS).await
+    }
+
+    /// Ask the fallback resolvers (see [`DnsClientConfig::resolver_transport`] and
+    /// [`DnsClientConfig::resolvers`]) for `domain`'s records of type `qtype`, giving each
+    /// `timeout_ms` to answer.
+    pub async fn query_resolver(&self, domain: &str, qtype: u16, timeout_ms: u64) -> Result<NsAnswer> {
+        let resolvers = match &*self.fallback {
+            Fallback::Udp(resolvers) if !resolvers.is_empty() => resolvers.clone(),
+            Fallback::Udp(_) => get_system_resolvers(),
+            _ => return self.query_encrypted(domain, qtype, timeout_ms).await,
+        };
         if resolvers.is_empty() {
             warn!("No system resolvers found, using default 127.0.0.1");
-            return self.query_resolver_direct(domain, "127.0.0.1").await;
+            return self.query_resolver_direct(domain, qtype, "127.0.0.1", timeout_ms).await;
         }
 
-        // Try each system resolver
+        // Try each resolver
         for resolver in &resolvers {
-            debug!("Trying system resolver {} for {}", resolver, domain);
-            match self.query_resolver_direct(domain, resolver).await {
+            debug!("Trying resolver {} for {}", resolver, domain);
+            match self.query_resolver_direct(domain, qtype, resolver, timeout_ms).await {
                 Ok(result) => {
-                    debug!("System resolver {} succeeded for {}", resolver, domain);
+                    debug!("Resolver {} succeeded for {}", resolver, domain);
                     return Ok(result);
                 }
                 Err(e) => {
-                    debug!("System resolver {} failed for {}: {:?}", resolver, domain, e);
+                    debug!("Resolver {} failed for {}: {:?}", resolver, domain, e);
                     continue;
                 }
             }
         }
 
-        warn!("All system resolvers failed for {}", domain);
-        Err(DnsError::ServerFailure)
+        warn!("All resolvers failed for {}", domain);
+        Err(DnsError::ServerFailure(None))
     }
 
     /// Query a specific resolver directly
-    async fn query_resolver_direct(&self, domain: &str, resolver_ip: &str) -> Result<bool> {
-        // Query the resolver using our existing DNS infrastructure
-        match timeout(Duration::from_millis(2000), self.query_ns(domain, resolver_ip, 2000)).await {
-            Ok(Ok(has_ns)) => Ok(has_ns),
-            Ok(Err(DnsError::NameError)) => Ok(true), // NXDOMAIN means available
+    async fn query_resolver_direct(
+        &self,
+        domain: &str,
+        qtype: u16,
+        resolver_ip: &str,
+        timeout_ms: u64,
+    ) -> Result<NsAnswer> {
+        // Query the resolver using our existing DNS infrastructure. Not cached: the cache
+        // holds what the domain's own zone says
+        match timeout(
+            Duration::from_millis(timeout_ms),
+            self.query(domain, qtype, resolver_ip, timeout_ms),
+        )
+        .await
+        {
+            Ok(Ok(answer)) => Ok(answer),
+            Ok(Err(DnsError::NameError)) => Ok(NsAnswer::nxdomain()), // NXDOMAIN means available
             Ok(Err(e)) => Err(e),
             Err(_) => Err(DnsError::Timeout),
         }
     }
+
+    /// Query of type `qtype` for `domain` to each encrypted upstream in turn, until one answers.
+    async fn query_encrypted(&self, domain: &str, qtype: u16, timeout_ms: u64) -> Result<NsAnswer> {
+        // ID 0, as RFC 8484 asks for: each exchange has its connection to itself
+        let query = build_query(domain, qtype, 0, self.edns_udp_size, false);
+        if query.is_empty() {
+            return Err(DnsError::InvalidResponse);
+        }
+
+        let mut last_error = DnsError::ServerFailure(None);
+        for (index, upstream) in self.fallback.upstreams().iter().enumerate() {
+            let started = Instant::now();
+            let exchange = self.fallback.exchange(index, &query);
+            let result = match timeout(Duration::from_millis(timeout_ms), exchange).await {
+                Ok(Ok(data))
+                    if data.get(..2) == Some(&query[..2])
+                        && parse_question(&data).is_some_and(|question| question.matches(domain, qtype)) =>
+                {
+                    match parse_ns_response(&data) {
+                        // Neither transport has a size limit, so TC means the resolver is misbehaving
+                        Err(DnsError::Truncated) => Err(DnsError::InvalidResponse),
+                        response => response,
+                    }
+                }
+                Ok(Ok(_)) => Err(DnsError::InvalidResponse),
+                Ok(Err(e)) => Err(DnsError::Socket(e)),
+                Err(_) => Err(DnsError::Timeout),
+            };
+
+            match result {
+                Ok(answer) => {
+                    debug!("Resolver {} answered for {}", upstream, domain);
+                    return Ok(NsAnswer {
+                        rtt: started.elapsed(),
+                        ..answer
+                    });
+                }
+                Err(e) => {
+                    debug!("Resolver {} failed for {}: {:?}", upstream, domain, e);
+                    last_error = e;
+                }
+            }
+        }
+
+        warn!("All encrypted resolvers failed for {}", domain);
+        Err(last_error)
+    }
+}
+
+/// A query waiting in a socket's send queue.
+struct QueuedQuery {
+    data: Vec<u8>,
+    key: PendingKey,
+    tld: String,
 }
 
-type BatchQueue = Vec<(Vec<u8>, SocketAddr, u16)>;
+type BatchQueue = Vec<QueuedQuery>;
+
+/// Per-socket state: the socket, its send queue and the next transaction ID to try.
+struct SocketSlot {
+    socket: Arc<BatchDnsSocket>,
+    queue: Arc<Mutex<BatchQueue>>,
+    next_id: u16,
+}
 
 async fn process_queries_batch(
-    batch_socket: Arc<BatchDnsSocket>,
+    sockets: Vec<Arc<BatchDnsSocket>>,
     mut query_rx: mpsc::Receiver<QueryRequest>,
-    _cache: Arc<DashMap<String, (bool, Instant)>>,
+    mismatched_responses: Arc<AtomicU64>,
+    rtt: Arc<RttTable>,
+    limiter: Arc<RateLimiter>,
+    edns_udp_size: Option<u16>,
 ) {
-    let transaction_id = Arc::new(AtomicU16::new(1));
-    let pending_queries: Arc<DashMap<u16, PendingQuery>> = Arc::new(DashMap::new());
-    let batch_queue: Arc<Mutex<BatchQueue>> = Arc::new(Mutex::new(Vec::new()));
+    let pending_queries: Arc<PendingMap> = Arc::new(DashMap::new());
+    let tcp_pool = Arc::new(TcpConnectionPool::new());
+
+    let mut slots: Vec<SocketSlot> = sockets
+        .into_iter()
+        .map(|socket| SocketSlot {
+            socket,
+            queue: Arc::new(Mutex::new(Vec::new())),
+            next_id: 1,
+        })
+        .collect();
+
+    for (index, slot) in slots.iter().enumerate() {
+        // Spawn response handler
+        let socket_clone = slot.socket.clone();
+        let pending_clone = pending_queries.clone();
+        let tcp_pool_clone = tcp_pool.clone();
+        let mismatched_clone = mismatched_responses.clone();
+        let rtt_clone = rtt.clone();
+        tokio::spawn(async move {
+            handle_responses_batch(
+                index,
+                socket_clone,
+                pending_clone,
+                tcp_pool_clone,
+                mismatched_clone,
+                rtt_clone,
+            )
+            .await;
+        });
 
-    // Spawn response handler
-    let batch_socket_clone = batch_socket.clone();
-    let pending_clone = pending_queries.clone();
-    tokio::spawn(async move {
-        handle_responses_batch(batch_socket_clone, pending_clone).await;
-    });
+        // Spawn batch sender
+        let socket_clone = slot.socket.clone();
+        let queue_clone = slot.queue.clone();
+        let pending_clone = pending_queries.clone();
+        let limiter_clone = limiter.clone();
+        tokio::spawn(async move {
+            send_batches(socket_clone, queue_clone, pending_clone, limiter_clone).await;
+        });
+    }
 
     // Spawn timeout checker
     let pending_clone = pending_queries.clone();
     tokio::spawn(async move {
-        check_timeouts(pending_clone).await;
-    });
-
-    // Spawn batch sender
-    let batch_socket_clone = batch_socket.clone();
-    let batch_queue_clone = batch_queue.clone();
-    let pending_clone = pending_queries.clone();
-    tokio::spawn(async move {
-        send_batches(batch_socket_clone, batch_queue_clone, pending_clone).await;
+        check_timeouts(pending_clone, rtt).await;
     });
 
     // Process incoming query requests
+    let mut next_slot = 0;
     while let Some(request) = query_rx.recv().await {
         let QueryRequest {
             domain,
+            qtype,
             server,
             timeout_ms,
+            dnssec_ok,
             response_tx,
         } = request;
 
-        // Generate unique transaction ID with atomic wraparound
-        // compare_exchange_weak prevents race conditions on concurrent ID generation
-        let tx_id = loop {
-            let current = transaction_id.load(Ordering::Relaxed);
-            let next = if current == u16::MAX { 1 } else { current + 1 };
-            match transaction_id.compare_exchange_weak(current, next, Ordering::Relaxed, Ordering::Relaxed) {
-                Ok(_) => break current,
-                Err(_) => continue, // Retry if another thread modified it
-            }
-        };
-
-        // Build DNS query with unique transaction ID
-        let query = build_ns_query(&domain, tx_id);
-        if query.is_empty() {
-            let _ = response_tx.send(Err(DnsError::InvalidResponse));
-            continue;
-        }
-
         // Resolve the server IP to a socket address
         let server_addr = match resolve_server_addr(&server) {
             Ok(addr) => addr,
@@ -272,30 +652,98 @@ async fn process_queries_batch(
             }
         };
 
-        // Store pending query
         let pending = PendingQuery {
             response_tx,
-            sent_at: Instant::now(),
+            queued_at: Instant::now(),
+            sent_at: None,
             timeout_ms,
+            domain,
+            qtype,
+            server_addr,
+            edns_udp_size,
+            dnssec_ok: dnssec_ok && edns_udp_size.is_some(),
         };
-        pending_queries.insert(tx_id, pending);
 
-        // Add to batch queue
-        let mut queue = batch_queue.lock().await;
-        queue.push((query, server_addr, tx_id));
+        // Round-robin over sockets; a socket whose ID space to this server is full is skipped
+        let slot_count = slots.len();
+        let mut pending = Some(pending);
+        for attempt in 0..slot_count {
+            let index = (next_slot + attempt) % slot_count;
+            if let Some(key) = reserve_id(&pending_queries, &mut slots[index], index, server_addr, &mut pending) {
+                next_slot = (index + 1) % slot_count;
+                enqueue_query(&pending_queries, &slots[index], key).await;
+                break;
+            }
+        }
+
+        if let Some(pending) = pending {
+            warn!("Transaction ID space exhausted for server {}", server_addr);
+            let _ = pending.response_tx.send(Err(DnsError::IdSpaceExhausted));
+        }
+    }
+}
+
+/// Find a transaction ID not in use for (`index`, `server`) and move `pending` into the map under it.
+///
+/// Returns `None` (leaving `pending` untouched) when all 65,535 IDs are in flight.
+fn reserve_id(
+    pending_queries: &PendingMap,
+    slot: &mut SocketSlot,
+    index: usize,
+    server: SocketAddr,
+    pending: &mut Option<PendingQuery>,
+) -> Option<PendingKey> {
+    for _ in 0..u16::MAX {
+        let id = slot.next_id;
+        slot.next_id = if id == u16::MAX { 1 } else { id + 1 };
+
+        let key = PendingKey {
+            socket: index,
+            server,
+            id,
+        };
+        if let Entry::Vacant(entry) = pending_queries.entry(key) {
+            entry.insert(pending.take()?);
+            return Some(key);
+        }
+    }
+    None
+}
+
+/// Build the wire query for a reserved key and hand it to the socket's batch sender.
+async fn enqueue_query(pending_queries: &PendingMap, slot: &SocketSlot, key: PendingKey) {
+    let (query, tld) = match pending_queries.get(&key) {
+        Some(pending) => (
+            build_query(
+                &pending.domain,
+                pending.qtype,
+                key.id,
+                pending.edns_udp_size,
+                pending.dnssec_ok,
+            ),
+            pending.domain.rsplit('.').next().unwrap_or_default().to_string(),
+        ),
+        None => return,
+    };
 
-        if queue.len() >= BATCH_TRIGGER_SIZE {
-            drop(queue); // Release lock
+    if query.is_empty() {
+        if let Some((_, pending)) = pending_queries.remove(&key) {
+            let _ = pending.response_tx.send(Err(DnsError::InvalidResponse));
         }
+        return;
     }
+
+    slot.queue.lock().await.push(QueuedQuery { data: query, key, tld });
 }
 
 async fn send_batches(
     batch_socket: Arc<BatchDnsSocket>,
     batch_queue: Arc<Mutex<BatchQueue>>,
-    pending_queries: Arc<DashMap<u16, PendingQuery>>,
+    pending_queries: Arc<PendingMap>,
+    limiter: Arc<RateLimiter>,
 ) {
     let mut send_interval = interval(Duration::from_micros(BATCH_SEND_INTERVAL_US));
+    let mut send_batch = SendBatch::new(MAX_BATCH_SIZE);
 
     loop {
         send_interval.tick().await;
@@ -305,25 +753,34 @@ async fn send_batches(
             continue;
         }
 
-        let batch_size = queue.len().min(MAX_BATCH_SIZE);
-        let items: Vec<_> = queue.drain(0..batch_size).collect();
+        let items = take_sendable(&mut queue, &pending_queries, &limiter);
         drop(queue);
 
+        if items.is_empty() {
+            // Everything left is waiting for tokens; don't rescan the queue every tick
+            tokio::time::sleep(Duration::from_millis(THROTTLED_RETRY_INTERVAL_MS)).await;
+            continue;
+        }
+
         let mut batch = Vec::with_capacity(items.len());
-        let mut tx_ids = Vec::with_capacity(items.len());
+        let mut keys = Vec::with_capacity(items.len());
 
-        for (data, addr, tx_id) in items {
-            batch.push((data, addr));
-            tx_ids.push(tx_id);
+        let now = Instant::now();
+        for QueuedQuery { data, key, .. } in items {
+            if let Some(mut pending) = pending_queries.get_mut(&key) {
+                pending.sent_at = Some(now);
+            }
+            batch.push((data, key.server));
+            keys.push(key);
         }
 
         // Send batch
-        match batch_socket.send_batch(&batch).await {
+        match batch_socket.send_batch(&batch, &mut send_batch).await {
             Ok(sent_lengths) => {
                 for (i, &len) in sent_lengths.iter().enumerate() {
-                    if len == 0 && i < tx_ids.len() {
+                    if len == 0 && i < keys.len() {
                         // Failed to send this query
-                        if let Some((_, pending)) = pending_queries.remove(&tx_ids[i]) {
+                        if let Some((_, pending)) = pending_queries.remove(&keys[i]) {
                             let _ = pending
                                 .response_tx
                                 .send(Err(DnsError::Socket(std::io::Error::other("Failed to send"))));
@@ -333,8 +790,8 @@ async fn send_batches(
             }
             Err(e) => {
                 // All queries in batch failed
-                for tx_id in tx_ids {
-                    if let Some((_, pending)) = pending_queries.remove(&tx_id) {
+                for key in keys {
+                    if let Some((_, pending)) = pending_queries.remove(&key) {
                         let _ = pending
                             .response_tx
                             .send(Err(DnsError::Socket(std::io::Error::new(e.kind(), e.to_string()))));
@@ -345,22 +802,89 @@ async fn send_batches(
     }
 }
 
-async fn handle_responses_batch(batch_socket: Arc<BatchDnsSocket>, pending_queries: Arc<DashMap<u16, PendingQuery>>) {
+/// Take up to `MAX_BATCH_SIZE` queries whose server and TLD buckets allow them, in queue order.
+///
+/// Throttled queries stay queued, except those that have waited longer than their own
+/// timeout: they are removed and fail with `RateLimited`.
+fn take_sendable(queue: &mut BatchQueue, pending_queries: &PendingMap, limiter: &RateLimiter) -> Vec<QueuedQuery> {
+    let mut items = Vec::with_capacity(queue.len().min(MAX_BATCH_SIZE));
+    let mut kept = Vec::with_capacity(queue.len());
+
+    for queued in queue.drain(..) {
+        if items.len() >= MAX_BATCH_SIZE {
+            kept.push(queued);
+        } else if limiter.try_acquire(queued.key.server.ip(), &queued.tld) {
+            items.push(queued);
+        } else if let Some((key, pending)) = pending_queries.remove_if(&queued.key, |_, pending| {
+            pending.queued_at.elapsed() > Duration::from_millis(pending.timeout_ms)
+        }) {
+            debug!(
+                "Query for {} to {} gave up waiting for rate limit",
+                pending.domain, key.server
+            );
+            let _ = pending.response_tx.send(Err(DnsError::RateLimited));
+        } else {
+            kept.push(queued);
+        }
+    }
+
+    *queue = kept;
+    items
+}
+
+async fn handle_responses_batch(
+    socket_index: usize,
+    batch_socket: Arc<BatchDnsSocket>,
+    pending_queries: Arc<PendingMap>,
+    tcp_pool: Arc<TcpConnectionPool>,
+    mismatched_responses: Arc<AtomicU64>,
+    rtt: Arc<RttTable>,
+) {
+    let mut recv_batch = RecvBatch::new(MAX_RECV_BATCH_SIZE);
+
     loop {
-        match batch_socket.recv_batch(MAX_RECV_BATCH_SIZE).await {
-            Ok(messages) => {
-                for (data, _addr) in messages {
+        match batch_socket.recv_batch(&mut recv_batch).await {
+            Ok(_) => {
+                for (data, addr) in recv_batch.iter() {
                     if data.len() < 12 {
                         continue;
                     }
 
-                    // Extract transaction ID
-                    let tx_id = u16::from_be_bytes([data[0], data[1]]);
+                    // The source address is part of the key, so replies from other hosts never match
+                    let key = PendingKey {
+                        socket: socket_index,
+                        server: addr,
+                        id: u16::from_be_bytes([data[0], data[1]]),
+                    };
+
+                    // Only a reply echoing our question may complete the query
+                    let Some((key, pending)) =
+                        pending_queries.remove_if(&key, |_, pending| pending.matches_question(data))
+                    else {
+                        mismatched_responses.fetch_add(1, Ordering::Relaxed);
+                        debug!(
+                            "Dropped response for TX ID {:04x} from {}: no matching pending query",
+                            key.id, addr
+                        );
+                        continue;
+                    };
+
+                    let elapsed = pending.sent_at.map(|sent_at| sent_at.elapsed()).unwrap_or_default();
+                    if pending.sent_at.is_some() {
+                        rtt.record_sample(addr.ip(), elapsed);
+                    }
 
-                    // Find and remove pending query
-                    if let Some((_, pending)) = pending_queries.remove(&tx_id) {
-                        let response = parse_ns_response(&data);
-                        let _ = pending.response_tx.send(response);
+                    match parse_ns_response(data).map(|answer| answer.answered_by(addr.ip(), elapsed)) {
+                        Err(DnsError::Truncated) => {
+                            debug!("Truncated response for {}, retrying over TCP", pending.domain);
+                            let tcp_pool = tcp_pool.clone();
+                            tokio::spawn(async move {
+                                retry_over_tcp(&tcp_pool, pending, key.id).await;
+                            });
+                        }
+                        response => {
+                            let _ = pending.response_tx.send(response);
+                        }
                     }
                 }
             }
@@ -373,7 +897,35 @@ async fn handle_responses_batch(batch_socket: Arc<BatchDnsSocket>, pending_queri
     }
 }
 
-async fn check_timeouts(pending_queries: Arc<DashMap<u16, PendingQuery>>) {
+/// Re-send a query whose UDP answer was truncated over a pooled TCP connection.
+async fn retry_over_tcp(tcp_pool: &TcpConnectionPool, pending: PendingQuery, tx_id: u16) {
+    let sent_at = pending.sent_at.unwrap_or(pending.queued_at);
+    let remaining = Duration::from_millis(pending.timeout_ms).saturating_sub(sent_at.elapsed());
+    let query = build_query(
+        &pending.domain,
+        pending.qtype,
+        tx_id,
+        pending.edns_udp_size,
+        pending.dnssec_ok,
+    );
+
+    let result = match timeout(remaining, tcp_pool.query(pending.server_addr, &query)).await {
+        Ok(Ok(data)) if data.len() >= 2 && data[0..2] == query[0..2] && pending.matches_question(&data) => {
+            match parse_ns_response(&data) {
+                // A TCP answer has no size limit, so TC here means the server is misbehaving
+                Err(DnsError::Truncated) => Err(DnsError::InvalidResponse),
+                response => response.map(|answer| answer.answered_by(pending.server_addr.ip(), sent_at.elapsed())),
+            }
+        }
+        Ok(Ok(_)) => Err(DnsError::InvalidResponse),
+        Ok(Err(e)) => Err(DnsError::Socket(e)),
+        Err(_) => Err(DnsError::Timeout),
+    };
+
+    let _ = pending.response_tx.send(result);
+}
+
+async fn check_timeouts(pending_queries: Arc<PendingMap>, rtt: Arc<RttTable>) {
     let mut check_interval = interval(Duration::from_millis(TIMEOUT_CHECK_INTERVAL_MS));
 
     loop {
@@ -382,19 +934,23 @@ async fn check_timeouts(pending_queries: Arc<DashMap<u16, PendingQuery>>) {
         let now = Instant::now();
         let mut timed_out = Vec::new();
 
-        // Collect timed-out transaction IDs (can't remove while iterating)
+        // Collect timed-out keys (can't remove while iterating)
         for entry in pending_queries.iter() {
-            let tx_id = *entry.key();
+            let key = *entry.key();
             let pending = entry.value();
 
-            if now.duration_since(pending.sent_at) > Duration::from_millis(pending.timeout_ms) {
-                timed_out.push(tx_id);
+            // Queries still waiting for a rate limit token are expired by the send loop instead
+            if let Some(sent_at) = pending.sent_at
+                && now.duration_since(sent_at) > Duration::from_millis(pending.timeout_ms)
+            {
+                timed_out.push(key);
             }
         }
 
         // Remove and notify
-        for tx_id in timed_out {
-            if let Some((_, pending)) = pending_queries.remove(&tx_id) {
+        for key in timed_out {
+            if let Some((_, pending)) = pending_queries.remove(&key) {
+                rtt.record_timeout(key.server.ip());
                 let _ = pending.response_tx.send(Err(DnsError::Timeout));
             }
         }
@@ -402,6 +958,10 @@ async fn check_timeouts(pending_queries: Arc<DashMap<u16, PendingQuery>>) {
 }
 
 fn resolve_server_addr(server: &str) -> Result<SocketAddr> {
+    // A server given with its port, like `192.0.2.1:5353` or `[2001:db8::1]:5353`
+    if let Ok(addr) = server.parse() {
+        return Ok(addr);
+    }
     // Since servers are already IPs, just parse them directly
     // IPv6 addresses need to be wrapped in brackets
     let addr_str = if server.contains(':') {
@@ -499,7 +1059,8 @@ fn get_system_resolvers() -> Vec<String> {
     resolvers
 }
 
-fn build_ns_query(domain: &str, transaction_id: u16) -> Vec<u8> {
+/// Build a query for `domain` (empty for the root) of type `qtype`.
+fn build_query(domain: &str, qtype: u16, transaction_id: u16, edns_udp_size: Option<u16>, dnssec_ok: bool) -> Vec<u8> {
     let mut packet = Vec::with_capacity(512);
 
     // Transaction ID (now randomized)
@@ -514,7 +1075,7 @@ fn build_ns_query(domain: &str, transaction_id: u16) -> Vec<u8> {
         "Building DNS query for domain: {} with TX ID: {:04x}",
         domain, transaction_id
     );
-    for label in domain.split('.') {
+    for label in domain.split('.').filter(|_| !domain.is_empty()) {
         if label.is_empty() {
             debug!("Empty label in domain: {}", domain);
             return vec![]; // Return empty packet for invalid domain
@@ -528,14 +1089,18 @@ fn build_ns_query(domain: &str, transaction_id: u16) -> Vec<u8> {
     }
     packet.push(0x00); // End of domain name
 
-    packet.extend_from_slice(&[0x00, 0x02]); // Type: NS
+    packet.extend_from_slice(&qtype.to_be_bytes());
     packet.extend_from_slice(&[0x00, 0x01]); // Class: IN
 
+    if let Some(udp_size) = edns_udp_size {
+        append_opt(&mut packet, udp_size, dnssec_ok);
+    }
+
     debug!("Built DNS query packet of {} bytes", packet.len());
     packet
 }
 
-fn parse_ns_response(response: &[u8]) -> Result<bool> {
+fn parse_ns_response(response: &[u8]) -> Result<NsAnswer> {
     if response.len() < 12 {
         warn!("DNS response too short: {} bytes", response.len());
         return Err(DnsError::InvalidResponse);
@@ -555,11 +1120,11 @@ fn parse_ns_response(response: &[u8]) -> Result<bool> {
     let tc = (flags >> 9) & 0x1;
     let rd = (flags >> 8) & 0x1;
     let ra = (flags >> 7) & 0x1;
-    let rcode = flags & 0x000F;
+    let header_rcode = flags & 0x000F;
 
     debug!(
         "DNS Response Header: ID={:04x}, QR={}, OPCODE={}, AA={}, TC={}, RD={}, RA={}, RCODE={}",
-        transaction_id, qr, opcode, aa, tc, rd, ra, rcode
+        transaction_id, qr, opcode, aa, tc, rd, ra, header_rcode
     );
     debug!(
         "Counts: Questions={}, Answers={}, Authority={}, Additional={}",
@@ -571,23 +1136,74 @@ fn parse_ns_response(response: &[u8]) -> Result<bool> {
         return Err(DnsError::InvalidResponse);
     }
 
+    if tc == 1 {
+        debug!("Response truncated (TC=1), counts are unreliable");
+        return Err(DnsError::Truncated);
+    }
+
+    // Only a complete answer is worth decoding past the header
+    let Some(message) = parse_message(response) else {
+        warn!("Malformed DNS response: sections don't match header counts");
+        return Err(DnsError::InvalidResponse);
+    };
+
+    // The OPT record extends RCODE to 12 bits and may explain failures with EDE codes
+    let rcode = message.rcode();
+    let ede = message.edns.as_ref().and_then(|edns| edns.extended_errors.first().cloned());
+
     match rcode {
         0 => {
-            let has_records = answer_count > 0 || authority_count > 0;
-            debug!("NOERROR response, has NS records: {}", has_records);
-            Ok(has_records)
+            let has_records = !message.answers.is_empty() || !message.authorities.is_empty();
+            let nameservers = message
+                .questions
+                .first()
+                .map(|question| message.nameservers(&question.name))
+                .unwrap_or_default();
+            debug!(
+                "NOERROR response, has NS records: {}, nameservers: {:?}",
+                has_records, nameservers
+            );
+            let ttl = match message.questions.first() {
+                Some(question) if !nameservers.is_empty() => message.nameserver_ttl(&question.name),
+                _ => message.negative_ttl(),
+            };
+            Ok(NsAnswer {
+                has_records,
+                glue: message.glue(&nameservers),
+                addresses: message.addresses(),
+                nameservers,
+                soa: message.soa().cloned(),
+                ttl,
+                authoritative: aa == 1,
+                dnssec: message.dnssec_records(),
+                ..NsAnswer::default()
+            })
         }
         3 => {
             debug!("NXDOMAIN - domain doesn't exist");
-            Ok(false)
+            Ok(NsAnswer {
+                soa: message.soa().cloned(),
+                authoritative: aa == 1,
+                ttl: message.negative_ttl(),
+                dnssec: message.dnssec_records(),
+                ..NsAnswer::nxdomain()
+            })
         }
         1 => {
             warn!("Format error (FORMERR)");
             Err(DnsError::FormatError)
         }
         2 => {
-            warn!("Server failure (SERVFAIL)");
-            Err(DnsError::ServerFailure)
+            warn!("Server failure (SERVFAIL){}", ede_suffix(&ede));
+            Err(DnsError::ServerFailure(ede))
+        }
+        5 => {
+            warn!("Query refused (REFUSED){}", ede_suffix(&ede));
+            Err(DnsError::Refused(ede))
+        }
+        16 => {
+            warn!("Bad EDNS version (BADVERS)");
+            Err(DnsError::BadVersion)
         }
         _ => {
             warn!("Unexpected RCODE: {}", rcode);
@@ -601,13 +1217,45 @@ mod tests {
     use super::*;
 
     #[test]
-    fn build_ns_query_creates_valid_packet() {
-        let query = build_ns_query("example.com", 0x1234);
+    fn build_query_creates_valid_packet() {
+        let query = build_query("example.com", QTYPE_NS, 0x1234, None, false);
         assert!(query.len() > 12);
         assert_eq!(&query[0..2], &[0x12, 0x34]); // Check transaction ID
         assert_eq!(&query[query.len() - 4..], &[0x00, 0x02, 0x00, 0x01]); // Check type and class
     }
 
+    #[test]
+    fn build_query_asks_for_dnssec_records_at_the_root() {
+        let query = build_query("", 48, 0x1234, Some(1232), true);
+        assert!(parse_question(&query).unwrap().matches("", 48));
+        // The OPT record's flags, just before its empty RDATA, carry the DO bit
+        assert_eq!(&query[query.len() - 4..query.len() - 2], &[0x80, 0x00]);
+        assert!(build_query("example..com", QTYPE_NS, 0x1234, None, false).is_empty());
+    }
+
+    #[test]
+    fn build_query_advertises_edns_payload_size() {
+        let query = build_query("example.com", QTYPE_NS, 0x1234, Some(1232), false);
+        assert_eq!(&query[10..12], &[0x00, 0x01]); // One additional record
+        assert_eq!(parse_message(&query).unwrap().edns.unwrap().udp_size, 1232);
+        assert!(parse_question(&query).unwrap().matches("example.com", QTYPE_NS));
+    }
+
+    #[test]
+    fn parse_ns_response_attaches_extended_error_to_servfail() {
+        let mut response = build_query("example.com", QTYPE_NS, 0x1234, Some(1232), false);
+        response[2] |= 0x80; // QR=1
+        response[3] = 0x02; // SERVFAIL
+        // Replace the empty OPT RDATA with one EDE option: code 22 (No Reachable Authority)
+        response.truncate(response.len() - 2);
+        response.extend_from_slice(&[0x00, 0x06, 0x00, 0x0F, 0x00, 0x02, 0x00, 0x16]);
+
+        match parse_ns_response(&response) {
+            Err(DnsError::ServerFailure(Some(ede))) => assert_eq!(ede.code, 22),
+            other => panic!("expected SERVFAIL with EDE, got {other:?}"),
+        }
+    }
+
     #[test]
     fn parse_ns_response_recognizes_nxdomain() {
         let mut response = vec![0; 12];
@@ -616,7 +1264,121 @@ mod tests {
         response[2] = 0x80; // Set QR=1 (response)
         response[3] = 0x03; // NXDOMAIN
         let result = parse_ns_response(&response).unwrap();
-        assert!(!result);
+        assert!(!result.has_records);
+    }
+
+    #[test]
+    fn parse_ns_response_flags_truncation() {
+        let mut response = vec![0; 12];
+        response[2] = 0x82; // QR=1, TC=1
+        response[7] = 0x01; // One (partial) answer
+        assert!(matches!(parse_ns_response(&response), Err(DnsError::Truncated)));
+    }
+
+    #[test]
+    fn pending_query_rejects_wrong_question() {
+        let (response_tx, _response_rx) = oneshot::channel();
+        let pending = PendingQuery {
+            response_tx,
+            queued_at: Instant::now(),
+            sent_at: None,
+            timeout_ms: 1000,
+            domain: "example.com".to_string(),
+            qtype: QTYPE_NS,
+            server_addr: "192.0.2.1:53".parse().unwrap(),
+            edns_udp_size: None,
+            dnssec_ok: false,
+        };
+
+        let mut response = build_query("example.com", QTYPE_NS, 0x1234, None, false);
+        response[2] |= 0x80; // QR=1
+        let other = build_query("example.net", QTYPE_NS, 0x1234, None, false);
+
+        assert!(pending.matches_question(&response));
+        assert!(!pending.matches_question(&other));
+    }
+
+    #[tokio::test]
+    async fn reserve_id_skips_ids_still_in_flight() {
+        let pending_queries = PendingMap::new();
+        let server: SocketAddr = "192.0.2.1:53".parse().unwrap();
+        let mut slot = SocketSlot {
+            socket: Arc::new(BatchDnsSocket::new("127.0.0.1:0").await.unwrap()),
+            queue: Arc::new(Mutex::new(Vec::new())),
+            next_id: u16::MAX,
+        };
+
+        let make_pending = || {
+            let (response_tx, _response_rx) = oneshot::channel();
+            Some(PendingQuery {
+                response_tx,
+                queued_at: Instant::now(),
+                sent_at: None,
+                timeout_ms: 1000,
+                domain: "example.com".to_string(),
+                qtype: QTYPE_NS,
+                server_addr: server,
+                edns_udp_size: None,
+                dnssec_ok: false,
+            })
+        };
+
+        let first = reserve_id(&pending_queries, &mut slot, 0, server, &mut make_pending()).unwrap();
+        assert_eq!(first.id, u16::MAX);
+
+        // Wrap around to the same ID while the first query is still pending
+        slot.next_id = u16::MAX;
+        let second = reserve_id(&pending_queries, &mut slot, 0, server, &mut make_pending()).unwrap();
+        assert_eq!(second.id, 1);
+
+        // The same ID is free on another socket
+        slot.next_id = u16::MAX;
+        let other_socket = reserve_id(&pending_queries, &mut slot, 1, server, &mut make_pending()).unwrap();
+        assert_eq!(other_socket.id, u16::MAX);
+        assert_eq!(pending_queries.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn local_resolver_fallback_goes_over_https() {
+        let url = crate::dns_https::fake_server(|query| {
+            let mut response = query.to_vec();
+            response[2] |= 0x80; // QR=1
+            response[3] = 0x03; // NXDOMAIN
+            response
+        })
+        .await;
+        let config = DnsClientConfig {
+            resolver_transport: ResolverTransport::Https(vec![url]),
+            ..DnsClientConfig::default()
+        };
+        let client = PipelinedDnsClient::new("127.0.0.1:0", config).await.unwrap();
+
+        let answer = client.query_local_resolver("free-to-register.com").await.unwrap();
+        assert_eq!(answer.rcode, 3);
+        assert!(!answer.has_records);
+    }
+
+    #[tokio::test]
+    async fn local_resolver_fallback_uses_the_configured_resolvers() {
+        let resolver = tokio::net::UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let resolver_addr = resolver.local_addr().unwrap();
+        tokio::spawn(async move {
+            let mut buffer = vec![0; 512];
+            while let Ok((n, from)) = resolver.recv_from(&mut buffer).await {
+                let mut response = buffer[..n].to_vec();
+                response[2] |= 0x80; // QR=1
+                response[3] = 0x03; // NXDOMAIN
+                resolver.send_to(&response, from).await.unwrap();
+            }
+        });
+        let config = DnsClientConfig {
+            resolvers: vec![resolver_addr.to_string()],
+            ..DnsClientConfig::default()
+        };
+        let client = PipelinedDnsClient::new("127.0.0.1:0", config).await.unwrap();
+
+        let answer = client.query_local_resolver("free-to-register.com").await.unwrap();
+        assert_eq!(answer.rcode, 3);
     }
 
     #[test]
@@ -643,6 +1405,18 @@ mod tests {
         assert_eq!(addr.to_string(), "[2001:db8::1]:53");
     }
 
+    #[test]
+    fn resolve_server_addr_keeps_an_explicit_port() {
+        assert_eq!(
+            resolve_server_addr("192.0.2.1:5353").unwrap().to_string(),
+            "192.0.2.1:5353"
+        );
+        assert_eq!(
+            resolve_server_addr("[2001:db8::1]:5353").unwrap().to_string(),
+            "[2001:db8::1]:5353"
+        );
+    }
+
     #[test]
     fn resolve_server_addr_rejects_invalid() {
         let result = resolve_server_addr("not-an-ip");
@@ -0,0 +1,173 @@
+//! Token-bucket rate limiting for outgoing DNS queries.
+//!
+//! The `Checker` semaphore caps how many queries are in flight, but not how fast they
+//! hit any one registry. Each authoritative server IP and each TLD gets its own token
+//! bucket; the send loop only puts a query on the wire when both buckets have a token,
+//! and leaves it queued otherwise.
+
+use dashmap::DashMap;
+use std::net::IpAddr;
+use std::time::Instant;
+
+/// Default queries per second to all servers of one TLD combined.
+pub const DEFAULT_QPS_PER_TLD: u32 = 1000;
+/// Default queries per second to a single server IP.
+pub const DEFAULT_QPS_PER_SERVER: u32 = 250;
+
+// A bucket holds a quarter of a second's worth of tokens, which bounds the burst
+// sent to an idle server
+const BURST_SECONDS: f64 = 0.25;
+
+/// Query-per-second limits enforced by [`RateLimiter`].
+#[derive(Debug, Clone, Copy)]
+pub struct RateLimitConfig {
+    pub qps_per_tld: u32,
+    pub qps_per_server: u32,
+}
+
+impl Default for RateLimitConfig {
+    fn default() -> Self {
+        Self {
+            qps_per_tld: DEFAULT_QPS_PER_TLD,
+            qps_per_server: DEFAULT_QPS_PER_SERVER,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct TokenBucket {
+    tokens: f64,
+    rate: f64,
+    capacity: f64,
+    updated: Instant,
+}
+
+impl TokenBucket {
+    fn new(qps: u32, now: Instant) -> Self {
+        let rate = f64::from(qps.max(1));
+        let capacity = (rate * BURST_SECONDS).max(1.0);
+        Self {
+            tokens: capacity,
+            rate,
+            capacity,
+            updated: now,
+        }
+    }
+
+    fn has_token(&mut self, now: Instant) -> bool {
+        let elapsed = now.saturating_duration_since(self.updated).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.rate).min(self.capacity);
+        self.updated = now;
+        self.tokens >= 1.0
+    }
+}
+
+/// Per-server and per-TLD token buckets shared by every send loop.
+#[derive(Debug, Default)]
+pub struct RateLimiter {
+    config: RateLimitConfig,
+    servers: DashMap<IpAddr, TokenBucket>,
+    tlds: DashMap<String, TokenBucket>,
+}
+
+impl RateLimiter {
+    pub fn new(config: RateLimitConfig) -> Self {
+        Self {
+            config,
+            servers: DashMap::new(),
+            tlds: DashMap::new(),
+        }
+    }
+
+    /// Take one token from both the server's and the TLD's bucket, if both have one.
+    ///
+    /// Leaves both buckets untouched when either is empty.
+    pub fn try_acquire(&self, server: IpAddr, tld: &str) -> bool {
+        let now = Instant::now();
+
+        // Always server then TLD, so concurrent callers lock shards in the same order
+        let mut server_bucket = self
+            .servers
+            .entry(server)
+            .or_insert_with(|| TokenBucket::new(self.config.qps_per_server, now));
+        if !server_bucket.has_token(now) {
+            return false;
+        }
+
+        let mut tld_bucket = match self.tlds.get_mut(tld) {
+            Some(bucket) => bucket,
+            None => self
+                .tlds
+                .entry(tld.to_string())
+                .or_insert_with(|| TokenBucket::new(self.config.qps_per_tld, now)),
+        };
+        if !tld_bucket.has_token(now) {
+            return false;
+        }
+
+        server_bucket.tokens -= 1.0;
+        tld_bucket.tokens -= 1.0;
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    const SERVER: IpAddr = IpAddr::V4(std::net::Ipv4Addr::new(192, 0, 2, 1));
+    const OTHER_SERVER: IpAddr = IpAddr::V4(std::net::Ipv4Addr::new(192, 0, 2, 2));
+
+    fn drain(limiter: &RateLimiter, server: IpAddr, tld: &str) -> usize {
+        (0..10_000).take_while(|_| limiter.try_acquire(server, tld)).count()
+    }
+
+    #[test]
+    fn server_bucket_limits_burst() {
+        let limiter = RateLimiter::new(RateLimitConfig {
+            qps_per_tld: 100_000,
+            qps_per_server: 40,
+        });
+        assert_eq!(drain(&limiter, SERVER, "com"), 10);
+        // Another server of the same TLD has its own bucket
+        assert_eq!(drain(&limiter, OTHER_SERVER, "com"), 10);
+    }
+
+    #[test]
+    fn tld_bucket_is_shared_across_servers() {
+        let limiter = RateLimiter::new(RateLimitConfig {
+            qps_per_tld: 40,
+            qps_per_server: 100_000,
+        });
+        assert_eq!(
+            drain(&limiter, SERVER, "com") + drain(&limiter, OTHER_SERVER, "com"),
+            10
+        );
+        assert!(limiter.try_acquire(SERVER, "net"));
+    }
+
+    #[test]
+    fn empty_tld_bucket_does_not_spend_server_token() {
+        let limiter = RateLimiter::new(RateLimitConfig {
+            qps_per_tld: 4,
+            qps_per_server: 8,
+        });
+        assert_eq!(drain(&limiter, SERVER, "com"), 1);
+        // The server bucket still holds its second token
+        assert!(limiter.try_acquire(SERVER, "net"));
+        assert!(!limiter.try_acquire(SERVER, "org"));
+    }
+
+    #[test]
+    fn bucket_refills_over_time() {
+        let now = Instant::now();
+        let mut bucket = TokenBucket::new(100, now);
+        bucket.tokens = 0.0;
+        assert!(!bucket.has_token(now));
+        assert!(bucket.has_token(now + Duration::from_millis(20)));
+        // Never refills past its burst capacity
+        assert!(bucket.has_token(now + Duration::from_secs(10)));
+        assert_eq!(bucket.tokens, bucket.capacity);
+    }
+}
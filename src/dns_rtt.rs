@@ -0,0 +1,143 @@
+//! Per-server round-trip time estimation for adaptive query timeouts.
+//!
+//! Follows the retransmission timer of RFC 6298: each authoritative IP keeps a smoothed
+//! RTT (SRTT) and RTT variance (RTTVAR), and its timeout is `SRTT + max(G, 4 * RTTVAR)`.
+//! A timeout doubles the current value (exponential backoff) until the next good sample.
+
+use dashmap::DashMap;
+use std::net::IpAddr;
+use std::time::Duration;
+
+const ALPHA: f64 = 1.0 / 8.0;
+const BETA: f64 = 1.0 / 4.0;
+const K: f64 = 4.0;
+// Clock granularity term; keeps the timeout from collapsing onto SRTT for very stable servers
+const GRANULARITY_MS: f64 = 10.0;
+// Never time out faster than this, however fast the server has been: a burst of queries can
+// briefly inflate latency well past a quiet SRTT
+const MIN_TIMEOUT_MS: u64 = 200;
+// Backoff ceiling; the caller's own timeout caps this further
+const MAX_TIMEOUT_MS: f64 = 60_000.0;
+
+/// Smoothed RTT state for one server.
+#[derive(Debug, Clone, Copy)]
+pub struct RttEstimator {
+    srtt_ms: f64,
+    rttvar_ms: f64,
+    timeout_ms: f64,
+}
+
+impl RttEstimator {
+    fn from_first_sample(rtt_ms: f64) -> Self {
+        let mut estimator = Self {
+            srtt_ms: rtt_ms,
+            rttvar_ms: rtt_ms / 2.0,
+            timeout_ms: 0.0,
+        };
+        estimator.update_timeout();
+        estimator
+    }
+
+    fn add_sample(&mut self, rtt_ms: f64) {
+        self.rttvar_ms = (1.0 - BETA) * self.rttvar_ms + BETA * (self.srtt_ms - rtt_ms).abs();
+        self.srtt_ms = (1.0 - ALPHA) * self.srtt_ms + ALPHA * rtt_ms;
+        self.update_timeout();
+    }
+
+    fn back_off(&mut self) {
+        self.timeout_ms = (self.timeout_ms * 2.0).min(MAX_TIMEOUT_MS);
+    }
+
+    fn update_timeout(&mut self) {
+        self.timeout_ms = (self.srtt_ms + GRANULARITY_MS.max(K * self.rttvar_ms)).min(MAX_TIMEOUT_MS);
+    }
+
+    /// Current timeout derived from SRTT/RTTVAR (including any backoff).
+    pub fn timeout_ms(&self) -> u64 {
+        (self.timeout_ms.ceil() as u64).max(MIN_TIMEOUT_MS)
+    }
+}
+
+/// RTT estimators for every server that has answered at least once.
+#[derive(Default)]
+pub struct RttTable {
+    servers: DashMap<IpAddr, RttEstimator>,
+}
+
+impl RttTable {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a measured round trip to `server`.
+    pub fn record_sample(&self, server: IpAddr, rtt: Duration) {
+        let rtt_ms = rtt.as_secs_f64() * 1000.0;
+        self.servers
+            .entry(server)
+            .and_modify(|estimator| estimator.add_sample(rtt_ms))
+            .or_insert_with(|| RttEstimator::from_first_sample(rtt_ms));
+    }
+
+    /// Record a query to `server` that timed out.
+    pub fn record_timeout(&self, server: IpAddr) {
+        if let Some(mut estimator) = self.servers.get_mut(&server) {
+            estimator.back_off();
+        }
+    }
+
+    fn get(&self, server: IpAddr) -> Option<RttEstimator> {
+        self.servers.get(&server).map(|estimator| *estimator)
+    }
+
+    /// Timeout for the next query to `server`, never above `max_ms`.
+    ///
+    /// Servers without any samples yet use `initial_ms`.
+    pub fn timeout_ms(&self, server: IpAddr, initial_ms: u64, max_ms: u64) -> u64 {
+        match self.get(server) {
+            Some(estimator) => estimator.timeout_ms().min(max_ms),
+            None => initial_ms.min(max_ms),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SERVER: IpAddr = IpAddr::V4(std::net::Ipv4Addr::new(192, 0, 2, 1));
+
+    #[test]
+    fn unknown_server_uses_initial_timeout() {
+        let table = RttTable::new();
+        assert_eq!(table.timeout_ms(SERVER, 1000, 3000), 1000);
+        assert_eq!(table.timeout_ms(SERVER, 1000, 500), 500);
+    }
+
+    #[test]
+    fn fast_stable_server_gets_short_timeout() {
+        let table = RttTable::new();
+        for _ in 0..20 {
+            table.record_sample(SERVER, Duration::from_millis(20));
+        }
+        let timeout = table.timeout_ms(SERVER, 1000, 3000);
+        assert_eq!(timeout, MIN_TIMEOUT_MS);
+    }
+
+    #[test]
+    fn slow_server_timeout_is_bounded_by_user_timeout() {
+        let table = RttTable::new();
+        table.record_sample(SERVER, Duration::from_millis(800));
+        table.record_sample(SERVER, Duration::from_millis(1600));
+        assert!(table.timeout_ms(SERVER, 1000, 10_000) > 1600);
+        assert_eq!(table.timeout_ms(SERVER, 1000, 1200), 1200);
+    }
+
+    #[test]
+    fn timeout_backs_off_exponentially() {
+        let table = RttTable::new();
+        table.record_sample(SERVER, Duration::from_millis(100));
+        let before = table.timeout_ms(SERVER, 1000, 60_000);
+        table.record_timeout(SERVER);
+        assert_eq!(table.timeout_ms(SERVER, 1000, 60_000), before * 2);
+    }
+}
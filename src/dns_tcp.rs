@@ -0,0 +1,141 @@
+use dashmap::DashMap;
+use std::io;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tokio::net::TcpStream;
+use tokio::sync::{Mutex, Semaphore};
+
+// Keep the per-server footprint small: a truncated answer is the exception, not the rule.
+const MAX_CONNECTIONS_PER_SERVER: usize = 4;
+// Authoritative servers typically close idle TCP connections after a few seconds (RFC 7766).
+const MAX_IDLE_TIME: Duration = Duration::from_secs(10);
+
+/// Pool of length-prefixed DNS-over-TCP connections, keyed by server address.
+///
+/// At most `MAX_CONNECTIONS_PER_SERVER` connections are open to a single server;
+/// additional queries wait for a connection to be returned to the pool.
+#[derive(Default)]
+pub struct TcpConnectionPool {
+    servers: DashMap<SocketAddr, Arc<ServerConnections>>,
+}
+
+struct ServerConnections {
+    idle: Mutex<Vec<(TcpStream, Instant)>>,
+    permits: Semaphore,
+}
+
+impl TcpConnectionPool {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Send one DNS message to `server` and return the response message (without length prefix).
+    pub async fn query(&self, server: SocketAddr, message: &[u8]) -> io::Result<Vec<u8>> {
+        if message.len() > u16::MAX as usize {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "DNS message too large for TCP",
+            ));
+        }
+
+        let connections = self
+            .servers
+            .entry(server)
+            .or_insert_with(|| {
+                Arc::new(ServerConnections {
+                    idle: Mutex::new(Vec::new()),
+                    permits: Semaphore::new(MAX_CONNECTIONS_PER_SERVER),
+                })
+            })
+            .clone();
+
+        let _permit = connections
+            .permits
+            .acquire()
+            .await
+            .map_err(|_| io::Error::other("TCP connection pool closed"))?;
+
+        // Reuse an idle connection if one is fresh enough; a stale one gets a single retry on a new socket
+        if let Some(mut stream) = take_idle(&connections).await
+            && let Ok(response) = exchange(&mut stream, message).await
+        {
+            connections.idle.lock().await.push((stream, Instant::now()));
+            return Ok(response);
+        }
+
+        let mut stream = TcpStream::connect(server).await?;
+        stream.set_nodelay(true)?;
+        let response = exchange(&mut stream, message).await?;
+        connections.idle.lock().await.push((stream, Instant::now()));
+        Ok(response)
+    }
+}
+
+async fn take_idle(connections: &ServerConnections) -> Option<TcpStream> {
+    let mut idle = connections.idle.lock().await;
+    while let Some((stream, returned_at)) = idle.pop() {
+        if returned_at.elapsed() < MAX_IDLE_TIME {
+            return Some(stream);
+        }
+    }
+    None
+}
+
+/// Send one length-prefixed message on `stream` and read the length-prefixed answer, as DNS
+/// over TCP (RFC 1035 section 4.2.2) and DNS over TLS frame them.
+pub async fn exchange<S: AsyncRead + AsyncWrite + Unpin>(stream: &mut S, message: &[u8]) -> io::Result<Vec<u8>> {
+    let mut framed = Vec::with_capacity(message.len() + 2);
+    framed.extend_from_slice(&(message.len() as u16).to_be_bytes());
+    framed.extend_from_slice(message);
+    stream.write_all(&framed).await?;
+
+    let mut len_buf = [0u8; 2];
+    stream.read_exact(&mut len_buf).await?;
+    let len = u16::from_be_bytes(len_buf) as usize;
+
+    let mut response = vec![0u8; len];
+    stream.read_exact(&mut response).await?;
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use tokio::net::TcpListener;
+
+    #[tokio::test]
+    async fn tcp_pool_reuses_connections() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let accepted = Arc::new(AtomicUsize::new(0));
+
+        let accepted_clone = accepted.clone();
+        tokio::spawn(async move {
+            loop {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                accepted_clone.fetch_add(1, Ordering::SeqCst);
+                tokio::spawn(async move {
+                    // Echo every length-prefixed message back
+                    loop {
+                        let mut len_buf = [0u8; 2];
+                        if stream.read_exact(&mut len_buf).await.is_err() {
+                            return;
+                        }
+                        let mut body = vec![0u8; u16::from_be_bytes(len_buf) as usize];
+                        stream.read_exact(&mut body).await.unwrap();
+                        stream.write_all(&len_buf).await.unwrap();
+                        stream.write_all(&body).await.unwrap();
+                    }
+                });
+            }
+        });
+
+        let pool = TcpConnectionPool::new();
+        assert_eq!(pool.query(addr, b"first").await.unwrap(), b"first");
+        assert_eq!(pool.query(addr, b"second").await.unwrap(), b"second");
+        assert_eq!(accepted.load(Ordering::SeqCst), 1);
+    }
+}
@@ -0,0 +1,209 @@
+//! DNS over TLS (RFC 7858) for the fallback resolver.
+//!
+//! Messages are framed as over TCP, inside a TLS session whose certificate is checked against
+//! the Mozilla root store. Connections are kept open for reuse, as RFC 7858 recommends, and
+//! carry one query at a time; a few are open to each upstream at most.
+
+use crate::dns_tcp::exchange;
+use crate::transport::TlsUpstream;
+use dashmap::DashMap;
+use std::io;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::net::TcpStream;
+use tokio::sync::{Mutex, Semaphore};
+use tokio_rustls::TlsConnector;
+use tokio_rustls::client::TlsStream;
+use tokio_rustls::rustls::pki_types::ServerName;
+use tokio_rustls::rustls::{ClientConfig, RootCertStore, crypto};
+
+// Fallback queries are the exception; a few sessions carry a burst of them without hammering the resolver.
+const MAX_SESSIONS_PER_UPSTREAM: usize = 4;
+// Public resolvers close idle sessions after a few seconds to a few minutes; stay on the safe side.
+const MAX_IDLE_TIME: Duration = Duration::from_secs(10);
+
+/// TLS sessions, keyed by upstream.
+///
+/// At most `MAX_SESSIONS_PER_UPSTREAM` sessions are open to a single upstream; additional
+/// queries wait for a session to be returned to the pool.
+pub struct TlsConnectionPool {
+    connector: TlsConnector,
+    upstreams: DashMap<String, Arc<UpstreamSessions>>,
+}
+
+struct UpstreamSessions {
+    /// Open sessions, with when each was last used.
+    idle: Mutex<Vec<(TlsStream<TcpStream>, Instant)>>,
+    permits: Semaphore,
+}
+
+impl TlsConnectionPool {
+    /// Pool verifying servers against the Mozilla root store.
+    pub fn new() -> io::Result<Self> {
+        let roots = RootCertStore {
+            roots: webpki_roots::TLS_SERVER_ROOTS.to_vec(),
+        };
+        Self::with_roots(roots)
+    }
+
+    fn with_roots(roots: RootCertStore) -> io::Result<Self> {
+        let config = ClientConfig::builder_with_provider(Arc::new(crypto::ring::default_provider()))
+            .with_safe_default_protocol_versions()
+            .map_err(io::Error::other)?
+            .with_root_certificates(roots)
+            .with_no_client_auth();
+        Ok(Self {
+            connector: TlsConnector::from(Arc::new(config)),
+            upstreams: DashMap::new(),
+        })
+    }
+
+    /// Send one DNS message to `upstream` and return its answer.
+    pub async fn query(&self, upstream: &TlsUpstream, message: &[u8]) -> io::Result<Vec<u8>> {
+        let sessions = self
+            .upstreams
+            .entry(upstream.to_string())
+            .or_insert_with(|| {
+                Arc::new(UpstreamSessions {
+                    idle: Mutex::new(Vec::new()),
+                    permits: Semaphore::new(MAX_SESSIONS_PER_UPSTREAM),
+                })
+            })
+            .clone();
+
+        let _permit = sessions
+            .permits
+            .acquire()
+            .await
+            .map_err(|_| io::Error::other("TLS connection pool closed"))?;
+
+        // Reuse a fresh idle session; one the server has closed in the meantime gets replaced
+        let reusable = {
+            let mut idle = sessions.idle.lock().await;
+            std::iter::from_fn(|| idle.pop()).find(|(_, returned_at)| returned_at.elapsed() < MAX_IDLE_TIME)
+        };
+        if let Some((mut stream, _)) = reusable
+            && let Ok(response) = exchange(&mut stream, message).await
+        {
+            sessions.idle.lock().await.push((stream, Instant::now()));
+            return Ok(response);
+        }
+
+        let mut stream = self.connect(upstream).await?;
+        let response = exchange(&mut stream, message).await?;
+        sessions.idle.lock().await.push((stream, Instant::now()));
+        Ok(response)
+    }
+
+    async fn connect(&self, upstream: &TlsUpstream) -> io::Result<TlsStream<TcpStream>> {
+        let server_name = ServerName::try_from(upstream.server_name.clone())
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+        let tcp = TcpStream::connect((upstream.host.as_str(), upstream.port)).await?;
+        tcp.set_nodelay(true)?;
+        self.connector.connect(server_name, tcp).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+    use tokio_rustls::TlsAcceptor;
+    use tokio_rustls::rustls::ServerConfig;
+    use tokio_rustls::rustls::pki_types::pem::PemObject;
+    use tokio_rustls::rustls::pki_types::{CertificateDer, PrivateKeyDer};
+
+    const CERT: &str = include_str!("../tests/fixtures/localhost.crt");
+    const KEY: &str = include_str!("../tests/fixtures/localhost.key");
+
+    /// TLS stand-in on 127.0.0.1 that echoes every length-prefixed message back.
+    async fn echo_server(accepted: Arc<AtomicUsize>) -> u16 {
+        let config = ServerConfig::builder_with_provider(Arc::new(crypto::ring::default_provider()))
+            .with_safe_default_protocol_versions()
+            .unwrap()
+            .with_no_client_auth()
+            .with_single_cert(
+                vec![CertificateDer::from_pem_slice(CERT.as_bytes()).unwrap()],
+                PrivateKeyDer::from_pem_slice(KEY.as_bytes()).unwrap(),
+            )
+            .unwrap();
+        let acceptor = TlsAcceptor::from(Arc::new(config));
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        tokio::spawn(async move {
+            while let Ok((tcp, _)) = listener.accept().await {
+                accepted.fetch_add(1, Ordering::SeqCst);
+                let acceptor = acceptor.clone();
+                tokio::spawn(async move {
+                    // Clients that reject the certificate abort the handshake
+                    let Ok(mut stream) = acceptor.accept(tcp).await else {
+                        return;
+                    };
+                    loop {
+                        let mut len_buf = [0u8; 2];
+                        if stream.read_exact(&mut len_buf).await.is_err() {
+                            return;
+                        }
+                        let mut body = vec![0u8; u16::from_be_bytes(len_buf) as usize];
+                        stream.read_exact(&mut body).await.unwrap();
+                        stream.write_all(&[&len_buf[..], &body].concat()).await.unwrap();
+                    }
+                });
+            }
+        });
+        port
+    }
+
+    fn trusting_test_cert() -> TlsConnectionPool {
+        let mut roots = RootCertStore::empty();
+        roots.add(CertificateDer::from_pem_slice(CERT.as_bytes()).unwrap()).unwrap();
+        TlsConnectionPool::with_roots(roots).unwrap()
+    }
+
+    #[tokio::test]
+    async fn queries_over_a_reused_tls_session() {
+        let accepted = Arc::new(AtomicUsize::new(0));
+        let port = echo_server(accepted.clone()).await;
+        let upstream: TlsUpstream = format!("127.0.0.1:{port}#localhost").parse().unwrap();
+
+        let pool = trusting_test_cert();
+        assert_eq!(pool.query(&upstream, b"first").await.unwrap(), b"first");
+        assert_eq!(pool.query(&upstream, b"second").await.unwrap(), b"second");
+        assert_eq!(accepted.load(Ordering::SeqCst), 1);
+
+        // The certificate covers 127.0.0.1 itself, too
+        let by_address: TlsUpstream = format!("127.0.0.1:{port}").parse().unwrap();
+        assert_eq!(pool.query(&by_address, b"third").await.unwrap(), b"third");
+    }
+
+    #[tokio::test]
+    async fn caps_the_sessions_per_upstream() {
+        let accepted = Arc::new(AtomicUsize::new(0));
+        let port = echo_server(accepted.clone()).await;
+        let upstream: TlsUpstream = format!("127.0.0.1:{port}#localhost").parse().unwrap();
+
+        let pool = trusting_test_cert();
+        let messages: Vec<Vec<u8>> = (0..20).map(|i| format!("query {i}").into_bytes()).collect();
+        let responses = futures::future::join_all(messages.iter().map(|message| pool.query(&upstream, message))).await;
+        for (message, response) in messages.iter().zip(responses) {
+            assert_eq!(&response.unwrap(), message);
+        }
+
+        assert!(accepted.load(Ordering::SeqCst) <= MAX_SESSIONS_PER_UPSTREAM);
+        let sessions = pool.upstreams.get(&upstream.to_string()).unwrap().clone();
+        assert!(sessions.idle.lock().await.len() <= MAX_SESSIONS_PER_UPSTREAM);
+    }
+
+    #[tokio::test]
+    async fn rejects_an_untrusted_certificate() {
+        let port = echo_server(Arc::new(AtomicUsize::new(0))).await;
+        let upstream: TlsUpstream = format!("127.0.0.1:{port}#localhost").parse().unwrap();
+        assert!(TlsConnectionPool::new().unwrap().query(&upstream, b"query").await.is_err());
+
+        let wrong_name: TlsUpstream = format!("127.0.0.1:{port}#dns.example").parse().unwrap();
+        assert!(trusting_test_cert().query(&wrong_name, b"query").await.is_err());
+    }
+}
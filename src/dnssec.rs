@@ -0,0 +1,1095 @@
+//! DNSSEC validation of NXDOMAIN answers (RFC 4033-4035, NSEC3 per RFC 5155).
+//!
+//! With [`crate::checker::CheckerBuilder::dnssec`] on, NS queries set the DO bit and an
+//! NXDOMAIN verdict is only called proven when:
+//!
+//! 1. The zone that signed the denial chains up to the root: the root's DNSKEY set is signed
+//!    by a key matching IANA's trust anchor, each child's DS set by its parent's keys, and each
+//!    child's DNSKEY set by a key one of those DS records names.
+//! 2. Every NSEC or NSEC3 record in the answer is signed by that zone's keys.
+//! 3. Those records cover the name and rule out a wildcard that could have matched it.
+//!
+//! This module only checks records; which servers to ask and caching keys per zone is up to
+//! the checker.
+
+use crate::dns_message::{
+    QTYPE_NS, Record, RecordData, TYPE_DNSKEY, TYPE_DS, TYPE_NSEC, TYPE_NSEC3, TYPE_RRSIG, TYPE_SOA, read_name,
+};
+use ring::{digest, signature};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// IPv4 addresses of the root servers (a to m), which serve the root's DNSKEY and TLDs' DS records.
+pub(crate) const ROOT_SERVERS: [&str; 13] = [
+    "198.41.0.4",
+    "170.247.170.2",
+    "192.33.4.12",
+    "199.7.91.13",
+    "192.203.230.10",
+    "192.5.5.241",
+    "192.112.36.4",
+    "198.97.190.53",
+    "192.36.148.17",
+    "192.58.128.30",
+    "193.0.14.129",
+    "199.7.83.42",
+    "202.12.27.33",
+];
+
+/// IANA's root trust anchors (KSK-2017 and KSK-2024) as DS records: key tag, algorithm and
+/// SHA-256 digest.
+const ROOT_ANCHORS: [(u16, u8, &str); 2] = [
+    (
+        20326,
+        8,
+        "e06d44b80b8f1d39a95c0b0d7c65d08458e880409bbc683457104237c7f8ec8d",
+    ),
+    (
+        38696,
+        8,
+        "683d2d0acb8c9b712a1948b27f741219298d0a450d612c483af444a4c0fb2b16",
+    ),
+];
+
+/// NSEC3 chains hashed more often than this aren't validated (RFC 9276 section 3.2).
+const MAX_NSEC3_ITERATIONS: u16 = 150;
+
+/// DNSKEY flag of keys that may sign zone data (RFC 4034 section 2.1.1).
+const FLAG_ZONE_KEY: u16 = 0x0100;
+/// NSEC3 flag of a span that may hide unsigned delegations (RFC 5155 section 3.1.2.1).
+const FLAG_OPT_OUT: u8 = 0x01;
+
+const CLASS_IN: u16 = 1;
+
+/// What DNSSEC says about a domain its zone's server answered NXDOMAIN for.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DnssecStatus {
+    /// NSEC or NSEC3 records, signed by keys chained to the root trust anchor, prove the name
+    /// isn't in its zone: cryptographically proven absent.
+    ProvenAbsent,
+    /// The denial is signed, but the name falls in an NSEC3 opt-out span, which may hide an
+    /// unsigned delegation. Common in large zones such as `com`.
+    OptOut,
+    /// The zone isn't signed (its parent has no DS record for it), so there's nothing to prove.
+    Insecure,
+    /// The denial couldn't be validated: a signature or key didn't check out, records were
+    /// missing, or the keys couldn't be fetched.
+    Failed { reason: String },
+}
+
+impl DnssecStatus {
+    /// Whether the name is cryptographically proven absent.
+    pub fn is_proven(&self) -> bool {
+        matches!(self, DnssecStatus::ProvenAbsent)
+    }
+
+    /// Short machine-readable name, as used in CSV exports.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DnssecStatus::ProvenAbsent => "proven_absent",
+            DnssecStatus::OptOut => "opt_out",
+            DnssecStatus::Insecure => "insecure",
+            DnssecStatus::Failed { .. } => "failed",
+        }
+    }
+}
+
+/// The keys a zone's records can be validated with, or why there are none.
+#[derive(Debug, Clone)]
+pub(crate) enum ZoneKeys {
+    Secure(Vec<Dnskey>),
+    Insecure,
+    Failed(String),
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Dnskey {
+    flags: u16,
+    algorithm: u8,
+    public_key: Vec<u8>,
+    rdata: Vec<u8>,
+    key_tag: u16,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Ds {
+    key_tag: u16,
+    algorithm: u8,
+    digest_type: u8,
+    digest: Vec<u8>,
+}
+
+#[derive(Debug, Clone)]
+struct Rrsig {
+    type_covered: u16,
+    algorithm: u8,
+    labels: u8,
+    original_ttl: u32,
+    expiration: u32,
+    inception: u32,
+    key_tag: u16,
+    signer: String,
+    /// RDATA up to the signature, with the signer's name in canonical form.
+    signed_prefix: Vec<u8>,
+    signature: Vec<u8>,
+}
+
+struct Nsec {
+    owner: String,
+    next: String,
+    bitmaps: Vec<u8>,
+}
+
+struct Nsec3 {
+    /// Base32hex hash from the owner name's first label.
+    hash: String,
+    algorithm: u8,
+    flags: u8,
+    iterations: u16,
+    salt: Vec<u8>,
+    /// Base32hex hash of the next owner in the chain.
+    next: String,
+    bitmaps: Vec<u8>,
+}
+
+/// The current time as the 32-bit serial number RRSIG validity periods are given in.
+pub(crate) fn now() -> u32 {
+    // Truncation is intended: RRSIG times wrap around every 136 years (RFC 4034 section 3.1.5)
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs() as u32)
+}
+
+/// IANA's root trust anchors.
+pub(crate) fn root_anchors() -> Vec<Ds> {
+    ROOT_ANCHORS
+        .iter()
+        .map(|&(key_tag, algorithm, digest)| Ds {
+            key_tag,
+            algorithm,
+            digest_type: 2,
+            digest: decode_hex(digest),
+        })
+        .collect()
+}
+
+/// The zone that signed `records`: the signer of the first RRSIG, if there is one.
+pub(crate) fn signer(records: &[Record]) -> Option<String> {
+    records
+        .iter()
+        .filter(|record| record.rtype == TYPE_RRSIG)
+        .find_map(|record| parse_rrsig(raw(record)?))
+        .map(|rrsig| rrsig.signer)
+}
+
+/// The next name in the NSEC chain after `qname`: the next owner named by the NSEC record
+/// that `qname` owns or falls between. Signatures aren't checked.
+pub(crate) fn next_secure(qname: &str, records: &[Record]) -> Option<String> {
+    let qname = normalize(qname);
+    records
+        .iter()
+        .filter(|record| record.rtype == TYPE_NSEC)
+        .filter_map(parse_nsec)
+        .find(|nsec| nsec.owner == qname || nsec_covers(nsec, &qname))
+        .map(|nsec| nsec.next)
+}
+
+/// Validate `zone`'s DNSKEY set against the DS records its parent vouches for.
+pub(crate) fn validate_dnskeys(zone: &str, records: &[Record], ds: &[Ds], now: u32) -> ZoneKeys {
+    let zone = normalize(zone);
+    let rrset = rrset(records, &zone, TYPE_DNSKEY);
+    let keys: Vec<Dnskey> = rrset.iter().filter_map(|record| parse_dnskey(raw(record)?)).collect();
+    if keys.is_empty() {
+        return ZoneKeys::Failed(format!("no DNSKEY records for {}", display(&zone)));
+    }
+    // A zone whose DS records we can't use is treated as unsigned (RFC 4035 section 5.2)
+    if !ds.iter().any(|ds| digest_algorithm(ds.digest_type).is_some()) {
+        return ZoneKeys::Insecure;
+    }
+
+    let trusted: Vec<Dnskey> = keys
+        .iter()
+        .filter(|key| ds.iter().any(|ds| ds_matches(ds, &zone, key)))
+        .cloned()
+        .collect();
+    if trusted.is_empty() {
+        return ZoneKeys::Failed(format!("no DNSKEY of {} matches its DS records", display(&zone)));
+    }
+    match verify_rrset(&rrset, records, &zone, &trusted, now) {
+        Ok(()) => ZoneKeys::Secure(keys),
+        Err(reason) => ZoneKeys::Failed(reason),
+    }
+}
+
+/// The DS records for `zone` its `parent` signed, empty if it has none (an unsigned zone).
+///
+/// An empty DS set isn't checked for a signed denial: "unsigned" never upgrades a verdict.
+pub(crate) fn validate_ds(
+    zone: &str,
+    parent: &str,
+    records: &[Record],
+    parent_keys: &[Dnskey],
+    now: u32,
+) -> Result<Vec<Ds>, String> {
+    let zone = normalize(zone);
+    let rrset = rrset(records, &zone, TYPE_DS);
+    if rrset.is_empty() {
+        return Ok(Vec::new());
+    }
+    verify_rrset(&rrset, records, &normalize(parent), parent_keys, now)?;
+    Ok(rrset.iter().filter_map(|record| parse_ds(raw(record)?)).collect())
+}
+
+/// Whether the signed NSEC or NSEC3 records `zone` sent with an NXDOMAIN prove `qname`
+/// doesn't exist.
+pub(crate) fn prove_nxdomain(qname: &str, zone: &str, records: &[Record], keys: &[Dnskey], now: u32) -> DnssecStatus {
+    let (qname, zone) = (normalize(qname), normalize(zone));
+    if qname == zone || !is_within(&qname, &zone) {
+        return DnssecStatus::Failed {
+            reason: format!("{} can't deny {qname}", display(&zone)),
+        };
+    }
+
+    let denials: Vec<&Record> = records
+        .iter()
+        .filter(|record| matches!(record.rtype, TYPE_NSEC | TYPE_NSEC3) && is_within(&normalize(&record.name), &zone))
+        .collect();
+    // Each NSEC or NSEC3 record is an RRset of its own
+    for record in &denials {
+        if let Err(reason) = verify_rrset(&[*record], records, &zone, keys, now) {
+            return DnssecStatus::Failed { reason };
+        }
+    }
+
+    let nsec3: Vec<Nsec3> = denials
+        .iter()
+        .filter(|record| record.rtype == TYPE_NSEC3)
+        .filter_map(|record| parse_nsec3(record, &zone))
+        .collect();
+    let nsec: Vec<Nsec> = denials
+        .iter()
+        .filter(|record| record.rtype == TYPE_NSEC)
+        .filter_map(|record| parse_nsec(record))
+        .collect();
+    let proof = if !nsec3.is_empty() {
+        nsec3_proof(&qname, &zone, &nsec3)
+    } else if !nsec.is_empty() {
+        nsec_proof(&qname, &nsec)
+    } else {
+        Err("the answer has no NSEC or NSEC3 records".to_string())
+    };
+    proof.unwrap_or_else(|reason| DnssecStatus::Failed { reason })
+}
+
+/// NSEC denial (RFC 4035 section 5.4): one record covers the name, one the wildcard at its
+/// closest encloser.
+fn nsec_proof(qname: &str, nsec: &[Nsec]) -> Result<DnssecStatus, String> {
+    if nsec.iter().any(|record| record.owner == qname) {
+        return Err(format!("an NSEC record shows {qname} exists"));
+    }
+    let covering = nsec
+        .iter()
+        .find(|record| nsec_covers(record, qname))
+        .ok_or_else(|| format!("no NSEC record covers {qname}"))?;
+    // Names below a delegation aren't in this zone, so its NSEC says nothing about them
+    if is_within(qname, &covering.owner)
+        && has_type(&covering.bitmaps, QTYPE_NS)
+        && !has_type(&covering.bitmaps, TYPE_SOA)
+    {
+        return Err(format!("{qname} is below the delegation {}", covering.owner));
+    }
+
+    let encloser = [&covering.owner, &covering.next]
+        .into_iter()
+        .map(|name| common_ancestor(qname, name))
+        .max_by_key(|ancestor| label_count(ancestor))
+        .unwrap_or_default();
+    if encloser == qname {
+        return Err(format!("{qname} exists as an empty non-terminal"));
+    }
+    let wildcard = wildcard_of(&encloser);
+    if !nsec.iter().any(|record| nsec_covers(record, &wildcard)) {
+        return Err(format!("no NSEC record rules out {wildcard}"));
+    }
+    Ok(DnssecStatus::ProvenAbsent)
+}
+
+/// NSEC3 denial (RFC 5155 section 8.4): a closest encloser proof plus a record covering the
+/// wildcard at the closest encloser.
+fn nsec3_proof(qname: &str, zone: &str, nsec3: &[Nsec3]) -> Result<DnssecStatus, String> {
+    let first = &nsec3[0];
+    if nsec3.iter().any(|record| {
+        record.algorithm != first.algorithm || record.iterations != first.iterations || record.salt != first.salt
+    }) {
+        return Err("the NSEC3 records use different hash parameters".to_string());
+    }
+    if first.algorithm != 1 {
+        return Err(format!("unsupported NSEC3 hash algorithm {}", first.algorithm));
+    }
+    if first.iterations > MAX_NSEC3_ITERATIONS {
+        return Err(format!(
+            "NSEC3 uses {} iterations, over {MAX_NSEC3_ITERATIONS}",
+            first.iterations
+        ));
+    }
+
+    let hash = |name: &str| nsec3_hash(name, &first.salt, first.iterations);
+    let matching = |name: &str| {
+        let hash = hash(name);
+        nsec3.iter().find(|record| record.hash == hash)
+    };
+    let covering = |name: &str| {
+        let hash = hash(name);
+        nsec3.iter().find(|record| nsec3_covers(record, &hash))
+    };
+
+    if matching(qname).is_some() {
+        return Err(format!("an NSEC3 record shows {qname} exists"));
+    }
+
+    // The closest encloser is the nearest ancestor with a matching record; the next closer
+    // name is its child on the way to `qname`
+    let mut next_closer = qname;
+    let mut encloser = parent(qname);
+    loop {
+        if let Some(record) = matching(encloser) {
+            if has_type(&record.bitmaps, QTYPE_NS) && !has_type(&record.bitmaps, TYPE_SOA) {
+                return Err(format!("{qname} is below the delegation {encloser}"));
+            }
+            break;
+        }
+        if encloser == zone || encloser.is_empty() {
+            return Err(format!("no NSEC3 record proves a closest encloser for {qname}"));
+        }
+        next_closer = encloser;
+        encloser = parent(encloser);
+    }
+
+    let span = covering(next_closer).ok_or_else(|| format!("no NSEC3 record covers {next_closer}"))?;
+    let wildcard = wildcard_of(encloser);
+    if covering(&wildcard).is_none() {
+        return Err(format!("no NSEC3 record rules out {wildcard}"));
+    }
+    Ok(if span.flags & FLAG_OPT_OUT != 0 {
+        DnssecStatus::OptOut
+    } else {
+        DnssecStatus::ProvenAbsent
+    })
+}
+
+/// Check that `rrset` (the records of one owner and type) has a valid RRSIG by `zone` made
+/// with one of `keys`; the RRSIGs are looked up in `records`.
+fn verify_rrset(rrset: &[&Record], records: &[Record], zone: &str, keys: &[Dnskey], now: u32) -> Result<(), String> {
+    let Some(first) = rrset.first() else {
+        return Err("nothing to validate".to_string());
+    };
+    let owner = normalize(&first.name);
+    let what = format!("{} {}", display(&owner), type_name(first.rtype));
+
+    let signatures: Vec<Rrsig> = rrset_signatures(records, &owner, first.rtype);
+    if signatures.is_empty() {
+        return Err(format!("no RRSIG over {what}"));
+    }
+
+    let mut reason = String::new();
+    for rrsig in &signatures {
+        if rrsig.signer != zone {
+            reason = format!("signed by {} instead of {}", display(&rrsig.signer), display(zone));
+            continue;
+        }
+        if !in_validity_period(rrsig, now) {
+            reason = "signature expired or not yet valid".to_string();
+            continue;
+        }
+        let Some(signed_owner) = signed_owner(&owner, rrsig.labels) else {
+            reason = "signature has a bad label count".to_string();
+            continue;
+        };
+
+        let data = signed_data(rrsig, &signed_owner, rrset);
+        reason = format!("no DNSKEY with tag {} made the signature", rrsig.key_tag);
+        let candidates = keys.iter().filter(|key| {
+            key.key_tag == rrsig.key_tag && key.algorithm == rrsig.algorithm && key.flags & FLAG_ZONE_KEY != 0
+        });
+        for key in candidates {
+            match verify_signature(key.algorithm, &key.public_key, &data, &rrsig.signature) {
+                Ok(()) => return Ok(()),
+                Err(e) => reason = e,
+            }
+        }
+    }
+    Err(format!("{what}: {reason}"))
+}
+
+fn rrset_signatures(records: &[Record], owner: &str, rtype: u16) -> Vec<Rrsig> {
+    records
+        .iter()
+        .filter(|record| record.rtype == TYPE_RRSIG && normalize(&record.name) == owner)
+        .filter_map(|record| parse_rrsig(raw(record)?))
+        .filter(|rrsig| rrsig.type_covered == rtype)
+        .collect()
+}
+
+/// The data an RRSIG signs (RFC 4034 section 3.1.8.1): its own RDATA without the signature,
+/// then the RRset in canonical form and order.
+fn signed_data(rrsig: &Rrsig, owner: &str, rrset: &[&Record]) -> Vec<u8> {
+    let mut rdatas: Vec<&[u8]> = rrset.iter().filter_map(|record| raw(record)).collect();
+    rdatas.sort_unstable();
+    rdatas.dedup();
+
+    let owner = wire_name(owner);
+    let mut data = rrsig.signed_prefix.clone();
+    for rdata in rdatas {
+        data.extend_from_slice(&owner);
+        data.extend_from_slice(&rrsig.type_covered.to_be_bytes());
+        data.extend_from_slice(&CLASS_IN.to_be_bytes());
+        data.extend_from_slice(&rrsig.original_ttl.to_be_bytes());
+        data.extend_from_slice(&(rdata.len() as u16).to_be_bytes());
+        data.extend_from_slice(rdata);
+    }
+    data
+}
+
+/// The owner name a signature covers: `owner`, or the wildcard it was expanded from when the
+/// RRSIG has fewer labels (RFC 4035 section 5.3.2).
+fn signed_owner(owner: &str, labels: u8) -> Option<String> {
+    let count = label_count(owner);
+    let labels = usize::from(labels);
+    match labels.cmp(&count) {
+        Ordering::Greater => None,
+        Ordering::Equal => Some(owner.to_string()),
+        Ordering::Less => {
+            let suffix: Vec<&str> = owner.split('.').skip(count - labels).collect();
+            Some(wildcard_of(&suffix.join(".")))
+        }
+    }
+}
+
+fn in_validity_period(rrsig: &Rrsig, now: u32) -> bool {
+    // Serial number arithmetic (RFC 1982): both differences must be "non-negative"
+    (now.wrapping_sub(rrsig.inception) as i32) >= 0 && (rrsig.expiration.wrapping_sub(now) as i32) >= 0
+}
+
+fn verify_signature(algorithm: u8, public_key: &[u8], message: &[u8], sig: &[u8]) -> Result<(), String> {
+    let verified = match algorithm {
+        // RSA/SHA-1, RSASHA1-NSEC3-SHA1, RSA/SHA-256 and RSA/SHA-512; zones still use 1024-bit keys
+        5 | 7 | 8 | 10 => {
+            let (e, n) = rsa_key(public_key).ok_or("malformed RSA key")?;
+            let params = match algorithm {
+                8 => &signature::RSA_PKCS1_1024_8192_SHA256_FOR_LEGACY_USE_ONLY,
+                10 => &signature::RSA_PKCS1_1024_8192_SHA512_FOR_LEGACY_USE_ONLY,
+                _ => &signature::RSA_PKCS1_1024_8192_SHA1_FOR_LEGACY_USE_ONLY,
+            };
+            signature::RsaPublicKeyComponents { n, e }.verify(params, message, sig)
+        }
+        // ECDSA keys are the bare point; ring wants it in uncompressed SEC1 form
+        13 => signature::UnparsedPublicKey::new(&signature::ECDSA_P256_SHA256_FIXED, [&[0x04], public_key].concat())
+            .verify(message, sig),
+        14 => signature::UnparsedPublicKey::new(&signature::ECDSA_P384_SHA384_FIXED, [&[0x04], public_key].concat())
+            .verify(message, sig),
+        15 => signature::UnparsedPublicKey::new(&signature::ED25519, public_key).verify(message, sig),
+        _ => return Err(format!("unsupported DNSKEY algorithm {algorithm}")),
+    };
+    verified.map_err(|_| "signature doesn't verify".to_string())
+}
+
+/// Split an RSA DNSKEY into exponent and modulus (RFC 3110 section 2).
+fn rsa_key(key: &[u8]) -> Option<(&[u8], &[u8])> {
+    let (exponent_len, rest) = match *key.first()? {
+        0 => (
+            usize::from(u16::from_be_bytes([*key.get(1)?, *key.get(2)?])),
+            key.get(3..)?,
+        ),
+        len => (usize::from(len), &key[1..]),
+    };
+    (rest.len() > exponent_len).then(|| rest.split_at(exponent_len))
+}
+
+fn ds_matches(ds: &Ds, owner: &str, key: &Dnskey) -> bool {
+    let Some(algorithm) = digest_algorithm(ds.digest_type) else {
+        return false;
+    };
+    ds.key_tag == key.key_tag
+        && ds.algorithm == key.algorithm
+        && digest::digest(algorithm, &[wire_name(owner), key.rdata.clone()].concat()).as_ref() == ds.digest
+}
+
+fn digest_algorithm(digest_type: u8) -> Option<&'static digest::Algorithm> {
+    match digest_type {
+        1 => Some(&digest::SHA1_FOR_LEGACY_USE_ONLY),
+        2 => Some(&digest::SHA256),
+        4 => Some(&digest::SHA384),
+        _ => None,
+    }
+}
+
+/// Key tag of a DNSKEY's RDATA (RFC 4034 appendix B).
+fn key_tag(rdata: &[u8]) -> u16 {
+    let mut sum: u32 = 0;
+    for (i, &byte) in rdata.iter().enumerate() {
+        sum += if i % 2 == 0 {
+            u32::from(byte) << 8
+        } else {
+            u32::from(byte)
+        };
+    }
+    sum += (sum >> 16) & 0xFFFF;
+    (sum & 0xFFFF) as u16
+}
+
+/// NSEC3 hash of `name` (RFC 5155 section 5), base32hex-encoded like owner names.
+fn nsec3_hash(name: &str, salt: &[u8], iterations: u16) -> String {
+    let algorithm = &digest::SHA1_FOR_LEGACY_USE_ONLY;
+    let mut hash = digest::digest(algorithm, &[wire_name(name).as_slice(), salt].concat());
+    for _ in 0..iterations {
+        hash = digest::digest(algorithm, &[hash.as_ref(), salt].concat());
+    }
+    base32hex(hash.as_ref())
+}
+
+/// Lowercase base32hex without padding (RFC 4648 section 7), which sorts like the raw bytes.
+fn base32hex(data: &[u8]) -> String {
+    const ALPHABET: &[u8; 32] = b"0123456789abcdefghijklmnopqrstuv";
+    let mut encoded = String::with_capacity(data.len().div_ceil(5) * 8);
+    let (mut buffer, mut bits) = (0u32, 0);
+    for &byte in data {
+        buffer = (buffer << 8) | u32::from(byte);
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            encoded.push(char::from(ALPHABET[((buffer >> bits) & 0x1F) as usize]));
+        }
+    }
+    if bits > 0 {
+        encoded.push(char::from(ALPHABET[((buffer << (5 - bits)) & 0x1F) as usize]));
+    }
+    encoded
+}
+
+fn nsec_covers(nsec: &Nsec, name: &str) -> bool {
+    let after_owner = canonical_cmp(&nsec.owner, name) == Ordering::Less;
+    let before_next = canonical_cmp(name, &nsec.next) == Ordering::Less;
+    if canonical_cmp(&nsec.owner, &nsec.next) == Ordering::Less {
+        after_owner && before_next
+    } else {
+        // The zone's last NSEC points back to the apex
+        after_owner || before_next
+    }
+}
+
+fn nsec3_covers(nsec3: &Nsec3, hash: &str) -> bool {
+    let (owner, next, hash) = (nsec3.hash.as_str(), nsec3.next.as_str(), hash);
+    if owner < next {
+        owner < hash && hash < next
+    } else {
+        // The last hash in the chain wraps around to the first
+        hash > owner || hash < next
+    }
+}
+
+/// Whether a type bitmap (RFC 4034 section 4.1.2) lists `rtype`.
+fn has_type(bitmaps: &[u8], rtype: u16) -> bool {
+    let [window, low] = rtype.to_be_bytes();
+    let mut rest = bitmaps;
+    while let [number, len, tail @ ..] = rest {
+        let Some(bitmap) = tail.get(..usize::from(*len)) else {
+            return false;
+        };
+        if *number == window {
+            return bitmap
+                .get(usize::from(low / 8))
+                .is_some_and(|byte| byte & (0x80 >> (low % 8)) != 0);
+        }
+        rest = &tail[usize::from(*len)..];
+    }
+    false
+}
+
+fn parse_dnskey(rdata: &[u8]) -> Option<Dnskey> {
+    // Protocol is always 3 (RFC 4034 section 2.1.2)
+    if rdata.len() < 5 || rdata[2] != 3 {
+        return None;
+    }
+    Some(Dnskey {
+        flags: u16::from_be_bytes([rdata[0], rdata[1]]),
+        algorithm: rdata[3],
+        public_key: rdata[4..].to_vec(),
+        rdata: rdata.to_vec(),
+        key_tag: key_tag(rdata),
+    })
+}
+
+fn parse_ds(rdata: &[u8]) -> Option<Ds> {
+    if rdata.len() < 5 {
+        return None;
+    }
+    Some(Ds {
+        key_tag: u16::from_be_bytes([rdata[0], rdata[1]]),
+        algorithm: rdata[2],
+        digest_type: rdata[3],
+        digest: rdata[4..].to_vec(),
+    })
+}
+
+fn parse_rrsig(rdata: &[u8]) -> Option<Rrsig> {
+    let fixed = rdata.get(..18)?;
+    let number = |at: usize| u32::from_be_bytes([fixed[at], fixed[at + 1], fixed[at + 2], fixed[at + 3]]);
+    // The signer's name is never compressed (RFC 4034 section 3.1.7)
+    let (signer, signature_at) = read_name(rdata, 18)?;
+    let signer = normalize(&signer);
+    let mut signed_prefix = fixed.to_vec();
+    signed_prefix.extend_from_slice(&wire_name(&signer));
+
+    Some(Rrsig {
+        type_covered: u16::from_be_bytes([fixed[0], fixed[1]]),
+        algorithm: fixed[2],
+        labels: fixed[3],
+        original_ttl: number(4),
+        expiration: number(8),
+        inception: number(12),
+        key_tag: u16::from_be_bytes([fixed[16], fixed[17]]),
+        signer,
+        signed_prefix,
+        signature: rdata[signature_at..].to_vec(),
+    })
+}
+
+fn parse_nsec(record: &Record) -> Option<Nsec> {
+    let rdata = raw(record)?;
+    let (next, bitmaps_at) = read_name(rdata, 0)?;
+    Some(Nsec {
+        owner: normalize(&record.name),
+        next: normalize(&next),
+        bitmaps: rdata[bitmaps_at..].to_vec(),
+    })
+}
+
+fn parse_nsec3(record: &Record, zone: &str) -> Option<Nsec3> {
+    let owner = normalize(&record.name);
+    let (hash, owner_zone) = owner.split_once('.').unwrap_or((&owner, ""));
+    if owner_zone != zone {
+        return None;
+    }
+
+    let rdata = raw(record)?;
+    let salt_len = usize::from(*rdata.get(4)?);
+    let salt = rdata.get(5..5 + salt_len)?;
+    let hash_len = usize::from(*rdata.get(5 + salt_len)?);
+    let next_at = 6 + salt_len;
+    let next = rdata.get(next_at..next_at + hash_len)?;
+    Some(Nsec3 {
+        hash: hash.to_string(),
+        algorithm: rdata[0],
+        flags: rdata[1],
+        iterations: u16::from_be_bytes([rdata[2], rdata[3]]),
+        salt: salt.to_vec(),
+        next: base32hex(next),
+        bitmaps: rdata[next_at + hash_len..].to_vec(),
+    })
+}
+
+fn raw(record: &Record) -> Option<&[u8]> {
+    match &record.data {
+        RecordData::Other(rdata) => Some(rdata),
+        _ => None,
+    }
+}
+
+fn rrset<'a>(records: &'a [Record], owner: &str, rtype: u16) -> Vec<&'a Record> {
+    records
+        .iter()
+        .filter(|record| record.rtype == rtype && normalize(&record.name) == owner)
+        .collect()
+}
+
+/// Names as we compare them: lowercase, no trailing dot, empty for the root.
+fn normalize(name: &str) -> String {
+    name.trim_end_matches('.').to_ascii_lowercase()
+}
+
+fn display(name: &str) -> &str {
+    if name.is_empty() { "." } else { name }
+}
+
+fn type_name(rtype: u16) -> String {
+    match rtype {
+        TYPE_DS => "DS".to_string(),
+        TYPE_DNSKEY => "DNSKEY".to_string(),
+        TYPE_NSEC => "NSEC".to_string(),
+        TYPE_NSEC3 => "NSEC3".to_string(),
+        other => format!("TYPE{other}"),
+    }
+}
+
+fn labels(name: &str) -> impl DoubleEndedIterator<Item = &str> {
+    name.split('.').filter(|label| !label.is_empty())
+}
+
+fn label_count(name: &str) -> usize {
+    // The wildcard label doesn't count (RFC 4034 section 3.1.3)
+    labels(name).filter(|label| *label != "*").count()
+}
+
+fn parent(name: &str) -> &str {
+    name.split_once('.').map_or("", |(_, parent)| parent)
+}
+
+fn wildcard_of(name: &str) -> String {
+    if name.is_empty() {
+        "*".to_string()
+    } else {
+        format!("*.{name}")
+    }
+}
+
+/// Whether `name` is `zone` or below it.
+fn is_within(name: &str, zone: &str) -> bool {
+    zone.is_empty() || name == zone || name.strip_suffix(zone).is_some_and(|rest| rest.ends_with('.'))
+}
+
+/// The longest name both `a` and `b` are within.
+fn common_ancestor(a: &str, b: &str) -> String {
+    let a_labels: Vec<&str> = labels(a).collect();
+    let shared = labels(a)
+        .rev()
+        .zip(labels(b).rev())
+        .take_while(|(x, y)| x.eq_ignore_ascii_case(y))
+        .count();
+    a_labels[a_labels.len() - shared..].join(".")
+}
+
+/// Canonical DNS name order (RFC 4034 section 6.1): label by label from the right,
+/// case-insensitively, with a name sorting before its descendants.
+pub(crate) fn canonical_cmp(a: &str, b: &str) -> Ordering {
+    labels(a)
+        .rev()
+        .map(|label| label.to_ascii_lowercase().into_bytes())
+        .cmp(labels(b).rev().map(|label| label.to_ascii_lowercase().into_bytes()))
+}
+
+/// Uncompressed wire format of a name, lowercased as signatures and digests need it.
+fn wire_name(name: &str) -> Vec<u8> {
+    let mut wire = Vec::with_capacity(name.len() + 2);
+    for label in labels(name) {
+        wire.push(label.len() as u8);
+        wire.extend(label.bytes().map(|byte| byte.to_ascii_lowercase()));
+    }
+    wire.push(0);
+    wire
+}
+
+fn decode_hex(hex: &str) -> Vec<u8> {
+    (0..hex.len())
+        .step_by(2)
+        .filter_map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ring::signature::{Ed25519KeyPair, KeyPair};
+
+    const NOW: u32 = 1_800_000_000;
+
+    /// A zone signing with one Ed25519 key.
+    struct TestZone {
+        name: String,
+        key: Ed25519KeyPair,
+    }
+
+    impl TestZone {
+        fn new(name: &str, seed: u8) -> Self {
+            Self {
+                name: name.to_string(),
+                key: Ed25519KeyPair::from_seed_unchecked(&[seed; 32]).unwrap(),
+            }
+        }
+
+        /// DNSKEY RDATA: a zone key with the SEP flag (257), protocol 3, algorithm 15.
+        fn dnskey_rdata(&self) -> Vec<u8> {
+            [&[0x01, 0x01, 3, 15], self.key.public_key().as_ref()].concat()
+        }
+
+        fn dnskey(&self) -> Record {
+            record(&self.name, TYPE_DNSKEY, self.dnskey_rdata())
+        }
+
+        fn ds(&self) -> Record {
+            let rdata = self.dnskey_rdata();
+            let digest = digest::digest(&digest::SHA256, &[wire_name(&self.name), rdata.clone()].concat());
+            let mut ds = key_tag(&rdata).to_be_bytes().to_vec();
+            ds.extend_from_slice(&[15, 2]);
+            ds.extend_from_slice(digest.as_ref());
+            record(&self.name, TYPE_DS, ds)
+        }
+
+        fn sign(&self, rrset: &[Record], valid_until: u32) -> Record {
+            let owner = normalize(&rrset[0].name);
+            let mut rdata = rrset[0].rtype.to_be_bytes().to_vec();
+            rdata.extend_from_slice(&[15, label_count(&owner) as u8]);
+            rdata.extend_from_slice(&3600u32.to_be_bytes());
+            rdata.extend_from_slice(&valid_until.to_be_bytes());
+            rdata.extend_from_slice(&(NOW - 86_400).to_be_bytes());
+            rdata.extend_from_slice(&key_tag(&self.dnskey_rdata()).to_be_bytes());
+            rdata.extend_from_slice(&wire_name(&self.name));
+
+            let rrsig = parse_rrsig(&rdata).unwrap();
+            let rrset: Vec<&Record> = rrset.iter().collect();
+            rdata.extend_from_slice(self.key.sign(&signed_data(&rrsig, &owner, &rrset)).as_ref());
+            record(&owner, TYPE_RRSIG, rdata)
+        }
+
+        /// `records` plus a valid RRSIG over each of them.
+        fn signed(&self, records: Vec<Record>) -> Vec<Record> {
+            let signatures: Vec<Record> = records
+                .iter()
+                .map(|record| self.sign(std::slice::from_ref(record), NOW + 86_400))
+                .collect();
+            records.into_iter().chain(signatures).collect()
+        }
+
+        fn keys(&self) -> Vec<Dnskey> {
+            vec![parse_dnskey(&self.dnskey_rdata()).unwrap()]
+        }
+    }
+
+    fn record(name: &str, rtype: u16, rdata: Vec<u8>) -> Record {
+        Record {
+            name: name.to_string(),
+            rtype,
+            class: CLASS_IN,
+            ttl: 3600,
+            data: RecordData::Other(rdata),
+        }
+    }
+
+    /// Type bitmap for window 0 listing `types` (all below 256).
+    fn bitmap(types: &[u16]) -> Vec<u8> {
+        let mut bits = [0u8; 32];
+        for &rtype in types {
+            bits[usize::from(rtype / 8)] |= 0x80 >> (rtype % 8);
+        }
+        let len = bits.iter().rposition(|&byte| byte != 0).map_or(0, |last| last + 1);
+        [&[0, len as u8], &bits[..len]].concat()
+    }
+
+    fn nsec(owner: &str, next: &str, types: &[u16]) -> Record {
+        record(owner, TYPE_NSEC, [wire_name(next), bitmap(types)].concat())
+    }
+
+    fn nsec3(zone: &str, owner_hash: &[u8], next_hash: &[u8], opt_out: bool, types: &[u16]) -> Record {
+        let mut rdata = vec![1, u8::from(opt_out), 0, 2, 2, 0xAB, 0xCD, next_hash.len() as u8];
+        rdata.extend_from_slice(next_hash);
+        rdata.extend_from_slice(&bitmap(types));
+        record(&format!("{}.{zone}", base32hex(owner_hash)), TYPE_NSEC3, rdata)
+    }
+
+    fn raw_nsec3_hash(name: &str) -> Vec<u8> {
+        let encoded = nsec3_hash(name, &[0xAB, 0xCD], 2);
+        // Decode base32hex by hand; the tests only need it for a handful of names
+        let mut bytes = Vec::new();
+        let (mut buffer, mut bits) = (0u32, 0);
+        for c in encoded.chars() {
+            buffer = (buffer << 5) | c.to_digit(32).unwrap();
+            bits += 5;
+            if bits >= 8 {
+                bits -= 8;
+                bytes.push((buffer >> bits) as u8);
+            }
+        }
+        bytes
+    }
+
+    /// The hash just before or after `hash`, as a big-endian number.
+    fn step(hash: &[u8], up: bool) -> Vec<u8> {
+        let mut stepped = hash.to_vec();
+        for byte in stepped.iter_mut().rev() {
+            let (value, carried) = if up {
+                byte.overflowing_add(1)
+            } else {
+                byte.overflowing_sub(1)
+            };
+            *byte = value;
+            if !carried {
+                break;
+            }
+        }
+        stepped
+    }
+
+    /// An NSEC3 record whose span contains just `name`'s hash.
+    fn nsec3_covering(zone: &str, name: &str, opt_out: bool) -> Record {
+        let hash = raw_nsec3_hash(name);
+        nsec3(zone, &step(&hash, false), &step(&hash, true), opt_out, &[QTYPE_NS])
+    }
+
+    fn failure(status: DnssecStatus) -> String {
+        match status {
+            DnssecStatus::Failed { reason } => reason,
+            other => panic!("expected a failure, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn chains_keys_from_the_trust_anchor() {
+        let root = TestZone::new("", 1);
+        let tld = TestZone::new("test", 2);
+        let anchor = vec![parse_ds(raw(&root.ds()).unwrap()).unwrap()];
+
+        let root_dnskey = vec![root.dnskey(), root.sign(&[root.dnskey()], NOW + 86_400)];
+        let ZoneKeys::Secure(root_keys) = validate_dnskeys("", &root_dnskey, &anchor, NOW) else {
+            panic!("root keys should validate");
+        };
+
+        let ds = validate_ds("test", "", &root.signed(vec![tld.ds()]), &root_keys, NOW).unwrap();
+        assert_eq!(ds.len(), 1);
+        let tld_dnskey = vec![tld.dnskey(), tld.sign(&[tld.dnskey()], NOW + 86_400)];
+        assert!(matches!(
+            validate_dnskeys("test", &tld_dnskey, &ds, NOW),
+            ZoneKeys::Secure(_)
+        ));
+
+        // A DS set the parent didn't sign, or no DS at all
+        assert!(validate_ds("test", "", &tld.signed(vec![tld.ds()]), &root_keys, NOW).is_err());
+        assert!(validate_ds("test", "", &[], &root_keys, NOW).unwrap().is_empty());
+
+        // Keys the DS doesn't name, and expired signatures
+        assert!(matches!(
+            validate_dnskeys("", &tld_dnskey, &anchor, NOW),
+            ZoneKeys::Failed(_)
+        ));
+        let expired = vec![root.dnskey(), root.sign(&[root.dnskey()], NOW - 1)];
+        assert!(matches!(
+            validate_dnskeys("", &expired, &anchor, NOW),
+            ZoneKeys::Failed(_)
+        ));
+    }
+
+    #[test]
+    fn nsec_proves_a_name_absent() {
+        let zone = TestZone::new("test", 2);
+        let apex = nsec(
+            "test",
+            "alpha.test",
+            &[QTYPE_NS, TYPE_SOA, TYPE_RRSIG, TYPE_NSEC, TYPE_DNSKEY],
+        );
+        let span = nsec("alpha.test", "zulu.test", &[QTYPE_NS, TYPE_RRSIG, TYPE_NSEC]);
+
+        let records = zone.signed(vec![apex.clone(), span.clone()]);
+        assert_eq!(
+            prove_nxdomain("mike.test", "test", &records, &zone.keys(), NOW),
+            DnssecStatus::ProvenAbsent
+        );
+        assert_eq!(signer(&records).as_deref(), Some("test"));
+
+        // Without the apex record nothing rules out *.test
+        let records = zone.signed(vec![span.clone()]);
+        assert!(failure(prove_nxdomain("mike.test", "test", &records, &zone.keys(), NOW)).contains("*.test"));
+
+        // Names that exist, or that fall outside every span
+        let records = zone.signed(vec![apex, span.clone()]);
+        assert!(failure(prove_nxdomain("alpha.test", "test", &records, &zone.keys(), NOW)).contains("exists"));
+        assert!(failure(prove_nxdomain("zz.test", "test", &records, &zone.keys(), NOW)).contains("covers"));
+
+        // A record changed after signing
+        let mut forged = zone.signed(vec![span]);
+        forged[0] = nsec("alpha.test", "zz.test", &[QTYPE_NS]);
+        assert!(failure(prove_nxdomain("mike.test", "test", &forged, &zone.keys(), NOW)).contains("verify"));
+    }
+
+    #[test]
+    fn next_secure_follows_the_record_covering_the_name() {
+        let apex = nsec("test", "alpha.test", &[QTYPE_NS, TYPE_SOA]);
+        let span = nsec("alpha.test", "zulu.test", &[QTYPE_NS]);
+        // An NXDOMAIN also carries the record ruling out the wildcard; only the covering one counts
+        let records = vec![apex.clone(), span.clone()];
+        assert_eq!(next_secure("alpha\0.test", &records).as_deref(), Some("zulu.test"));
+        assert_eq!(next_secure("\0.test", &records).as_deref(), Some("alpha.test"));
+        assert_eq!(next_secure("alpha.test", &records).as_deref(), Some("zulu.test"));
+        assert_eq!(next_secure("zz.test", &records), None);
+    }
+
+    #[test]
+    fn nsec3_proves_a_name_absent_unless_opted_out() {
+        let zone = TestZone::new("test", 2);
+        let apex_hash = raw_nsec3_hash("test");
+        let apex = nsec3(
+            "test",
+            &apex_hash,
+            &step(&apex_hash, true),
+            false,
+            &[QTYPE_NS, TYPE_SOA],
+        );
+        let wildcard = nsec3_covering("test", "*.test", false);
+
+        let records = zone.signed(vec![
+            apex.clone(),
+            nsec3_covering("test", "mike.test", false),
+            wildcard.clone(),
+        ]);
+        assert_eq!(
+            prove_nxdomain("mike.test", "test", &records, &zone.keys(), NOW),
+            DnssecStatus::ProvenAbsent
+        );
+
+        let records = zone.signed(vec![
+            apex.clone(),
+            nsec3_covering("test", "mike.test", true),
+            wildcard.clone(),
+        ]);
+        assert_eq!(
+            prove_nxdomain("mike.test", "test", &records, &zone.keys(), NOW),
+            DnssecStatus::OptOut
+        );
+
+        let records = zone.signed(vec![apex, wildcard]);
+        assert!(failure(prove_nxdomain("mike.test", "test", &records, &zone.keys(), NOW)).contains("covers mike.test"));
+
+        // Unsigned records prove nothing
+        let unsigned = vec![nsec3_covering("test", "mike.test", false)];
+        assert!(failure(prove_nxdomain("mike.test", "test", &unsigned, &zone.keys(), NOW)).contains("no RRSIG"));
+    }
+
+    #[test]
+    fn nsec3_hash_matches_rfc_5155_examples() {
+        let salt = [0xAA, 0xBB, 0xCC, 0xDD];
+        assert_eq!(nsec3_hash("example", &salt, 12), "0p9mhaveqvm6t7vbl5lop2u3t2rp3tom");
+        assert_eq!(nsec3_hash("a.example", &salt, 12), "35mthgpgcu1qg68fab165klnsnk3dpvl");
+    }
+
+    #[test]
+    fn orders_names_canonically() {
+        // RFC 4034 section 6.1
+        let names = [
+            "example",
+            "a.example",
+            "yljkjljk.a.example",
+            "Z.a.example",
+            "zABC.a.EXAMPLE",
+            "z.example",
+            "*.z.example",
+        ];
+        for pair in names.windows(2) {
+            assert_eq!(
+                canonical_cmp(pair[0], pair[1]),
+                Ordering::Less,
+                "{} < {}",
+                pair[0],
+                pair[1]
+            );
+        }
+        assert_eq!(common_ancestor("mike.test", "alpha.test"), "test");
+        assert!(is_within("a.test", "test") && !is_within("atest", "test"));
+    }
+
+    #[test]
+    fn reads_type_bitmaps() {
+        let bitmaps = bitmap(&[QTYPE_NS, TYPE_SOA, TYPE_RRSIG]);
+        assert!(has_type(&bitmaps, QTYPE_NS));
+        assert!(has_type(&bitmaps, TYPE_RRSIG));
+        assert!(!has_type(&bitmaps, TYPE_DS));
+        assert!(!has_type(&bitmaps, 256 + QTYPE_NS));
+    }
+}
@@ -4,14 +4,41 @@
 //! ensuring validation happens at construction time.
 
 use crate::DomainCheckerError;
+use crate::psl;
 use crate::tld::Tld;
+use crate::tld_registry::{LabelPolicy, get_suffix_policy};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::ops::Deref;
+use thiserror::Error;
+
+/// Why a registry would refuse to register a name, whatever DNS says about it.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum PolicyViolation {
+    #[error("'{0}' is a public suffix, not a registrable name")]
+    PublicSuffix(String),
+
+    #[error("labels under .{suffix} need at least {min} characters")]
+    TooShort { suffix: String, min: u8 },
+
+    #[error("labels under .{suffix} can have at most {max} characters")]
+    TooLong { suffix: String, max: u8 },
+
+    #[error("'--' in positions 3-4 is reserved for IDN A-labels (xn--)")]
+    ReservedHyphens,
+
+    #[error(".{0} does not accept all-numeric labels")]
+    Numeric(String),
+
+    #[error(".{0} does not accept internationalized labels")]
+    Idn(String),
+}
 
 /// A validated domain name.
 ///
 /// Domain names are normalized to lowercase and validated at construction.
+/// Internationalized names are converted to their ASCII form (A-labels, `xn--...`)
+/// following UTS #46, since that's what registries store and DNS queries carry.
 /// Invalid domains cannot be constructed, ensuring type safety throughout the API.
 ///
 /// # Examples
@@ -23,6 +50,11 @@ use std::ops::Deref;
 /// let domain = Domain::new("example.com").unwrap();
 /// assert_eq!(domain.as_str(), "example.com");
 ///
+/// // Unicode input is stored as A-labels
+/// let domain = Domain::new("München.de").unwrap();
+/// assert_eq!(domain.as_str(), "xn--mnchen-3ya.de");
+/// assert_eq!(domain.to_unicode(), "münchen.de");
+///
 /// // Invalid domain - construction fails
 /// assert!(Domain::new("").is_err());
 /// assert!(Domain::new("invalid").is_err());
@@ -33,20 +65,24 @@ pub struct Domain(String);
 impl Domain {
     /// Create a new validated domain.
     ///
-    /// The domain is normalized to lowercase and validated according to:
+    /// The domain is normalized with IDNA (UTS #46): lowercased, with Unicode labels
+    /// converted to A-labels. The result is validated according to:
     /// - Total length: 1-253 characters
     /// - Label length: 1-63 characters per label
     /// - Characters: alphanumeric and hyphens only
     /// - Hyphens: not at start or end of labels
     /// - Format: at least two labels (e.g., "example.com")
     pub fn new(domain: impl Into<String>) -> Result<Self, DomainCheckerError> {
-        let domain = domain.into().to_lowercase();
+        let domain = domain.into();
+        let Some(ascii) = to_ascii(&domain) else {
+            return Err(DomainCheckerError::InvalidDomain(domain));
+        };
 
-        if !is_valid_domain(&domain) {
+        if !is_valid_domain(&ascii) {
             return Err(DomainCheckerError::InvalidDomain(domain));
         }
 
-        Ok(Self(domain))
+        Ok(Self(ascii))
     }
 
     /// Returns the domain as a string slice.
@@ -54,7 +90,7 @@ impl Domain {
         &self.0
     }
 
-    /// Extracts the TLD from this domain.
+    /// Extracts the TLD from this domain: its public suffix, which may span several labels.
     ///
     /// # Examples
     ///
@@ -63,9 +99,98 @@ impl Domain {
     ///
     /// let domain = Domain::new("example.com").unwrap();
     /// assert_eq!(domain.tld().as_str(), "com");
+    ///
+    /// let domain = Domain::new("brand.co.uk").unwrap();
+    /// assert_eq!(domain.tld().as_str(), "co.uk");
     /// ```
     pub fn tld(&self) -> Tld {
-        Tld::extract_from(&self.0)
+        Tld::new(self.suffix())
+    }
+
+    /// The public suffix this domain is registered under, e.g. `co.uk` for `www.brand.co.uk`.
+    pub fn suffix(&self) -> &str {
+        // Validation guarantees non-empty labels, so there always is a suffix
+        psl::public_suffix(&self.0).unwrap_or(&self.0)
+    }
+
+    /// The part of this domain a registrant owns: its public suffix plus one label, e.g.
+    /// `brand.co.uk` for `www.brand.co.uk`.
+    ///
+    /// Returns `None` if the domain is a public suffix itself, like `co.uk`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dotchk::Domain;
+    ///
+    /// let domain = Domain::new("shop.brand.com.au").unwrap();
+    /// assert_eq!(domain.registrable(), Some("brand.com.au"));
+    /// assert_eq!(Domain::new("com.au").unwrap().registrable(), None);
+    /// ```
+    pub fn registrable(&self) -> Option<&str> {
+        psl::registrable_domain(&self.0)
+    }
+
+    /// The domain with its A-labels decoded, e.g. `münchen.de` for `xn--mnchen-3ya.de`.
+    pub fn to_unicode(&self) -> String {
+        to_unicode(&self.0).unwrap_or_else(|| self.0.clone())
+    }
+
+    /// Check the label under the public suffix against the registry's rules (see
+    /// [`crate::LabelPolicy`]), so names that can never be registered aren't queried.
+    ///
+    /// The rules are those of the suffix the name is registered under, so `brand.com.au`
+    /// follows `com.au`'s rather than `au`'s. Labels with `--` in positions 3-4 are reserved
+    /// everywhere unless they are A-labels; suffixes without registry data only get that check.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dotchk::Domain;
+    ///
+    /// assert!(Domain::new("brand.eu").unwrap().validate_for_tld().is_ok());
+    /// assert!(Domain::new("x.eu").unwrap().validate_for_tld().is_err());
+    /// assert!(Domain::new("ab--cd.com").unwrap().validate_for_tld().is_err());
+    /// assert!(Domain::new("x.com.au").unwrap().validate_for_tld().is_err());
+    /// ```
+    pub fn validate_for_tld(&self) -> Result<(), PolicyViolation> {
+        self.validate_against(get_suffix_policy(self.suffix()))
+    }
+
+    /// [`Domain::validate_for_tld`] with `policy` instead of the compiled-in table's for the suffix.
+    pub(crate) fn validate_against(&self, policy: Option<LabelPolicy>) -> Result<(), PolicyViolation> {
+        let suffix = self.suffix();
+        let Some(registrable) = self.registrable() else {
+            return Err(PolicyViolation::PublicSuffix(self.0.clone()));
+        };
+        let label = &registrable[..registrable.len() - suffix.len() - 1];
+
+        if label.get(2..4) == Some("--") && !label.starts_with("xn--") {
+            return Err(PolicyViolation::ReservedHyphens);
+        }
+
+        let Some(policy) = policy else {
+            return Ok(());
+        };
+        if label.len() < usize::from(policy.min_length) {
+            return Err(PolicyViolation::TooShort {
+                suffix: suffix.to_string(),
+                min: policy.min_length,
+            });
+        }
+        if label.len() > usize::from(policy.max_length) {
+            return Err(PolicyViolation::TooLong {
+                suffix: suffix.to_string(),
+                max: policy.max_length,
+            });
+        }
+        if !policy.numeric && label.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(PolicyViolation::Numeric(suffix.to_string()));
+        }
+        if !policy.idn && label.starts_with("xn--") {
+            return Err(PolicyViolation::Idn(suffix.to_string()));
+        }
+        Ok(())
     }
 
     /// Consumes self and returns the inner String.
@@ -94,6 +219,36 @@ impl AsRef<str> for Domain {
     }
 }
 
+/// The ASCII form of a domain name or TLD: lowercase, with Unicode labels converted to
+/// A-labels (UTS #46). `None` if IDNA processing rejects it.
+///
+/// # Examples
+///
+/// ```
+/// use dotchk::domain::to_ascii;
+///
+/// assert_eq!(to_ascii("例子.中国").as_deref(), Some("xn--fsqu00a.xn--fiqs8s"));
+/// assert_eq!(to_ascii("Example.COM").as_deref(), Some("example.com"));
+/// ```
+pub fn to_ascii(domain: &str) -> Option<String> {
+    if domain.is_ascii() && !domain.contains("xn--") {
+        // Nothing to convert; leave validation to the caller
+        return Some(domain.to_ascii_lowercase());
+    }
+    idna::domain_to_ascii(domain).ok()
+}
+
+/// The Unicode form of a domain name, if it has A-labels and they decode cleanly.
+pub fn to_unicode(domain: &str) -> Option<String> {
+    if !domain.split('.').any(|label| label.starts_with("xn--")) {
+        return None;
+    }
+    match idna::domain_to_unicode(domain) {
+        (unicode, Ok(())) if unicode != domain => Some(unicode),
+        _ => None,
+    }
+}
+
 /// Validates a domain name according to DNS rules.
 fn is_valid_domain(domain: &str) -> bool {
     if domain.is_empty() || domain.len() > 253 {
@@ -149,6 +304,60 @@ mod tests {
         assert!(Domain::new("exam ple.com").is_err());
     }
 
+    #[test]
+    fn domain_converts_unicode_to_a_labels() {
+        let domain = Domain::new("例子.中国").unwrap();
+        assert_eq!(domain.as_str(), "xn--fsqu00a.xn--fiqs8s");
+        assert_eq!(domain.to_unicode(), "例子.中国");
+
+        // A-labels are accepted as they are, and ASCII names have no separate Unicode form
+        assert_eq!(Domain::new("xn--mnchen-3ya.de").unwrap().to_unicode(), "münchen.de");
+        assert_eq!(to_unicode("example.com"), None);
+
+        assert!(Domain::new("xn--invalid-.de").is_err());
+        assert!(Domain::new("exam ple.de").is_err());
+    }
+
+    #[test]
+    fn validate_for_tld_applies_registry_policy() {
+        let violation = |domain: &str| Domain::new(domain).unwrap().validate_for_tld().err();
+
+        assert_eq!(violation("example.com"), None);
+        assert_eq!(violation("www.brand.co.uk"), None);
+        assert_eq!(violation("xn--mnchen-3ya.de"), None);
+
+        assert_eq!(
+            violation("co.uk"),
+            Some(PolicyViolation::PublicSuffix("co.uk".to_string()))
+        );
+        assert_eq!(violation("ab--cd.com"), Some(PolicyViolation::ReservedHyphens));
+        assert_eq!(
+            violation("x.eu"),
+            Some(PolicyViolation::TooShort {
+                suffix: "eu".to_string(),
+                min: 2
+            })
+        );
+        assert_eq!(violation("münchen.us"), Some(PolicyViolation::Idn("us".to_string())));
+
+        // Second-level suffixes follow their own registry's rules
+        assert_eq!(
+            violation("x.com.au"),
+            Some(PolicyViolation::TooShort {
+                suffix: "com.au".to_string(),
+                min: 2
+            })
+        );
+        assert_eq!(
+            violation(&format!("{}.com.br", "a".repeat(27))),
+            Some(PolicyViolation::TooLong {
+                suffix: "com.br".to_string(),
+                max: 26
+            })
+        );
+        assert_eq!(violation(&format!("{}.br", "a".repeat(27))), None);
+    }
+
     #[test]
     fn domain_normalizes_to_lowercase() {
         let domain = Domain::new("Example.COM").unwrap();
@@ -161,7 +370,14 @@ mod tests {
         assert_eq!(domain.tld().as_str(), "com");
 
         let domain = Domain::new("test.co.uk").unwrap();
-        assert_eq!(domain.tld().as_str(), "uk");
+        assert_eq!(domain.tld().as_str(), "co.uk");
+    }
+
+    #[test]
+    fn domain_exposes_suffix_and_registrable_part() {
+        let domain = Domain::new("www.brand.co.uk").unwrap();
+        assert_eq!(domain.suffix(), "co.uk");
+        assert_eq!(domain.registrable(), Some("brand.co.uk"));
     }
 
     #[test]
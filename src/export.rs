@@ -1,5 +1,6 @@
 use crate::DomainCheckerError;
-use crate::checker::CheckResult;
+use crate::checker::{CheckResult, DomainStatus};
+use crate::domain::to_unicode;
 use csv::Writer;
 use std::path::Path;
 use thiserror::Error;
@@ -17,6 +18,73 @@ pub enum ExportError {
     Io(#[from] std::io::Error),
 }
 
+const CSV_HEADER: [&str; 15] = [
+    "domain",
+    "unicode",
+    "available",
+    "status",
+    "server",
+    "rcode",
+    "authoritative",
+    "rtt_ms",
+    "attempts",
+    "nameservers",
+    "dnssec",
+    "whois",
+    "registrar",
+    "expires",
+    "error",
+];
+
+/// Write one `CSV_HEADER` row for a completed check.
+fn write_check<W: std::io::Write>(wtr: &mut Writer<W>, check: &CheckResult) -> Result<()> {
+    let error = match &check.status {
+        DomainStatus::Error { message } => message.as_str(),
+        _ => "",
+    };
+    let rdap = check.rdap.as_ref();
+    wtr.write_record([
+        check.domain.as_str(),
+        &check.unicode_domain().unwrap_or_default(),
+        &check.available().to_string(),
+        check.status.as_str(),
+        check.server.as_deref().unwrap_or_default(),
+        &check.rcode.map(|rcode| rcode.to_string()).unwrap_or_default(),
+        &check.authoritative.to_string(),
+        &check.rtt_ms.map(|rtt| format!("{rtt:.1}")).unwrap_or_default(),
+        &check.attempts.to_string(),
+        &check.nameservers.join(";"),
+        check.dnssec.as_ref().map(|dnssec| dnssec.as_str()).unwrap_or_default(),
+        check.whois.map(|verdict| verdict.as_str()).unwrap_or_default(),
+        rdap.and_then(|rdap| rdap.registrar.as_deref()).unwrap_or_default(),
+        rdap.and_then(|rdap| rdap.expires_at.as_deref()).unwrap_or_default(),
+        error,
+    ])?;
+    Ok(())
+}
+
+/// Write one `CSV_HEADER` row for a failed check, keeping its domain when the error names one.
+fn write_error<W: std::io::Write>(wtr: &mut Writer<W>, error: &DomainCheckerError) -> Result<()> {
+    wtr.write_record([
+        error.domain().unwrap_or("unknown"),
+        &error.domain().and_then(to_unicode).unwrap_or_default(),
+        "false",
+        "error",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        &error.cause().to_string(),
+    ])?;
+    Ok(())
+}
+
 /// CSV exporter for domain check results
 pub struct CsvExporter {
     path: String,
@@ -33,19 +101,13 @@ impl CsvExporter {
         let mut wtr = Writer::from_path(&self.path)?;
 
         // Write header
-        wtr.write_record(["domain", "available", "error"])?;
+        wtr.write_record(CSV_HEADER)?;
 
         // Write records
         for result in results {
             match result {
-                Ok(check) => {
-                    wtr.write_record([&check.domain, &check.available.to_string(), ""])?;
-                }
-                Err(e) => {
-                    // For errors, we don't know the domain unless we parse the error
-                    // For now, skip or we could extract domain from error message
-                    wtr.write_record(["unknown", "false", &e.to_string()])?;
-                }
+                Ok(check) => write_check(&mut wtr, check)?,
+                Err(e) => write_error(&mut wtr, e)?,
             }
         }
 
@@ -63,17 +125,13 @@ impl CsvExporter {
         let mut count = 0;
 
         // Write header
-        wtr.write_record(["domain", "available", "error"])?;
+        wtr.write_record(CSV_HEADER)?;
 
         // Write records as they come in
         while let Some(result) = results.next().await {
             match result {
-                Ok(check) => {
-                    wtr.write_record([&check.domain, &check.available.to_string(), ""])?;
-                }
-                Err(e) => {
-                    wtr.write_record(["unknown", "false", &e.to_string()])?;
-                }
+                Ok(check) => write_check(&mut wtr, &check)?,
+                Err(e) => write_error(&mut wtr, &e)?,
             }
             count += 1;
 
@@ -98,7 +156,7 @@ impl CsvExporter {
 
         // Write records - only successful checks that are available
         for check in results.iter().flatten() {
-            if check.available {
+            if check.available() {
                 wtr.write_record([&check.domain])?;
             }
         }
@@ -114,13 +172,34 @@ pub struct StatsExporter;
 impl StatsExporter {
     pub fn calculate_stats(results: &[std::result::Result<CheckResult, DomainCheckerError>]) -> Stats {
         let total = results.len();
-        let available = results.iter().filter(|r| matches!(r, Ok(check) if check.available)).count();
-        let errors = results.iter().filter(|r| r.is_err()).count();
+        let available = results.iter().filter(|r| matches!(r, Ok(check) if check.available())).count();
+        let undelegated = results
+            .iter()
+            .filter(|r| matches!(r, Ok(check) if check.status == DomainStatus::NoData))
+            .count();
+        let unregistrable = results
+            .iter()
+            .filter(|r| matches!(r, Ok(check) if matches!(check.status, DomainStatus::Unregistrable { .. })))
+            .count();
+        let via_resolver = results
+            .iter()
+            .filter(|r| matches!(r, Ok(check) if matches!(check.status, DomainStatus::Resolver { .. })))
+            .count();
+        let errors = results
+            .iter()
+            .filter(|r| match r {
+                Ok(check) => matches!(check.status, DomainStatus::Error { .. }),
+                Err(_) => true,
+            })
+            .count();
 
         Stats {
             total,
             available,
-            unavailable: total - available - errors,
+            unavailable: total - available - unregistrable - errors,
+            undelegated,
+            unregistrable,
+            via_resolver,
             errors,
         }
     }
@@ -133,6 +212,9 @@ impl StatsExporter {
         wtr.write_record(["total_checked", &stats.total.to_string()])?;
         wtr.write_record(["available", &stats.available.to_string()])?;
         wtr.write_record(["unavailable", &stats.unavailable.to_string()])?;
+        wtr.write_record(["undelegated", &stats.undelegated.to_string()])?;
+        wtr.write_record(["unregistrable", &stats.unregistrable.to_string()])?;
+        wtr.write_record(["via_resolver", &stats.via_resolver.to_string()])?;
         wtr.write_record(["errors", &stats.errors.to_string()])?;
 
         wtr.flush()?;
@@ -145,7 +227,15 @@ impl StatsExporter {
 pub struct Stats {
     pub total: usize,
     pub available: usize,
+    /// Taken domains: checks that were answered and found the name registered.
     pub unavailable: usize,
+    /// Taken domains that exist in the zone without a delegation (NOERROR, no NS records).
+    pub undelegated: usize,
+    /// Names the registry's rules exclude, which weren't queried.
+    pub unregistrable: usize,
+    /// Checks answered by the system resolver instead of an authoritative server.
+    pub via_resolver: usize,
+    /// Checks that failed: an error result or an `Error` status.
     pub errors: usize,
 }
 
@@ -153,24 +243,90 @@ pub struct Stats {
 mod tests {
     use super::*;
 
+    fn result(domain: &str, status: DomainStatus) -> CheckResult {
+        CheckResult {
+            domain: domain.to_string(),
+            status,
+            server: Some("192.0.2.1".to_string()),
+            rcode: Some(0),
+            authoritative: true,
+            rtt_ms: Some(12.5),
+            attempts: 1,
+            nameservers: Vec::new(),
+            whois: None,
+            rdap: None,
+            dnssec: None,
+        }
+    }
+
     #[test]
     fn stats_calculation_computes_correctly() {
         let results = vec![
-            Ok(CheckResult {
-                domain: "test1.com".to_string(),
-                available: true,
-            }),
-            Ok(CheckResult {
-                domain: "test2.com".to_string(),
-                available: false,
-            }),
+            Ok(result("test1.com", DomainStatus::NxDomain)),
+            Ok(result("test2.com", DomainStatus::Delegated)),
+            Ok(result("test3.com", DomainStatus::NoData)),
+            Ok(result("test4.com", DomainStatus::Resolver { exists: false })),
+            Ok(result(
+                "test5.com",
+                DomainStatus::Error {
+                    message: "timeout".to_string(),
+                },
+            )),
             Err(crate::DomainCheckerError::Timeout),
+            Ok(result(
+                "x.eu",
+                DomainStatus::Unregistrable {
+                    reason: "too short".to_string(),
+                },
+            )),
         ];
 
         let stats = StatsExporter::calculate_stats(&results);
-        assert_eq!(stats.total, 3);
-        assert_eq!(stats.available, 1);
-        assert_eq!(stats.unavailable, 1);
-        assert_eq!(stats.errors, 1);
+        assert_eq!(stats.total, 7);
+        assert_eq!(stats.available, 2);
+        assert_eq!(stats.unavailable, 2);
+        assert_eq!(stats.undelegated, 1);
+        assert_eq!(stats.unregistrable, 1);
+        assert_eq!(stats.via_resolver, 1);
+        assert_eq!(stats.errors, 2);
+    }
+
+    #[test]
+    fn csv_rows_distinguish_statuses() {
+        let mut wtr = Writer::from_writer(Vec::new());
+        let mut delegated = result("taken.com", DomainStatus::Delegated);
+        delegated.nameservers = vec!["ns1.park.test".to_string(), "ns2.park.test".to_string()];
+        write_check(&mut wtr, &delegated).unwrap();
+        write_check(&mut wtr, &result("undelegated.com", DomainStatus::NoData)).unwrap();
+        write_check(&mut wtr, &result("xn--mnchen-3ya.de", DomainStatus::NxDomain)).unwrap();
+        let mut signed = result("free.com", DomainStatus::NxDomain);
+        signed.dnssec = Some(crate::DnssecStatus::ProvenAbsent);
+        write_check(&mut wtr, &signed).unwrap();
+
+        let csv = String::from_utf8(wtr.into_inner().unwrap()).unwrap();
+        let rows: Vec<&str> = csv.lines().collect();
+        assert_eq!(
+            rows[0],
+            "taken.com,,false,delegated,192.0.2.1,0,true,12.5,1,ns1.park.test;ns2.park.test,,,,,"
+        );
+        assert_eq!(rows[1], "undelegated.com,,false,nodata,192.0.2.1,0,true,12.5,1,,,,,,");
+        assert!(rows[2].starts_with("xn--mnchen-3ya.de,münchen.de,true,nxdomain,"));
+        assert!(rows[3].ends_with(",1,,proven_absent,,,,"));
+    }
+
+    #[test]
+    fn csv_error_rows_keep_domain() {
+        let mut wtr = Writer::from_writer(Vec::new());
+        let error = DomainCheckerError::Check {
+            domain: "example.zz".to_string(),
+            source: Box::new(DomainCheckerError::UnsupportedTld("zz".to_string())),
+        };
+        write_error(&mut wtr, &error).unwrap();
+        write_error(&mut wtr, &DomainCheckerError::Timeout).unwrap();
+
+        let csv = String::from_utf8(wtr.into_inner().unwrap()).unwrap();
+        let rows: Vec<&str> = csv.lines().collect();
+        assert!(rows[0].starts_with("example.zz,,false,error,,,,,,,,,,,TLD '.zz' not supported"));
+        assert!(rows[1].starts_with("unknown,,false,error,"));
     }
 }
@@ -0,0 +1,204 @@
+//! Per-server health tracking for authoritative nameservers.
+//!
+//! The `Checker` records the outcome and latency of every query it sends to an
+//! authoritative server. Servers are then tried most reliable first, fastest first among
+//! equally reliable ones, instead of the fixed order from the TLD registry, and a server
+//! that fails repeatedly is sidelined (tried last) for a cool-down period before it gets
+//! another chance.
+
+use dashmap::DashMap;
+use serde::{Deserialize, Serialize};
+use std::cmp::{Ordering, Reverse};
+use std::time::{Duration, Instant};
+
+/// Consecutive failures after which a server is sidelined.
+const SIDELINE_AFTER_FAILURES: u32 = 3;
+/// How long a sidelined server is tried only after all healthy ones.
+const SIDELINE_DURATION: Duration = Duration::from_secs(30);
+/// Weight of the newest sample in the latency moving average.
+const LATENCY_EWMA_WEIGHT: f64 = 0.2;
+/// Number of bands the success rate is rounded into for ranking. Latency only orders
+/// servers within a band, so one failure among many successes doesn't outweigh speed.
+const RELIABILITY_BANDS: f64 = 20.0;
+
+/// Health snapshot of one authoritative server, as seen by a [`Checker`](crate::Checker).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServerHealth {
+    /// Server IP address.
+    pub server: String,
+    pub successes: u64,
+    pub failures: u64,
+    pub consecutive_failures: u32,
+    /// Exponentially weighted average latency of successful queries, if any succeeded.
+    pub avg_latency_ms: Option<f64>,
+    /// Estimated success rate between 0 (never answers) and 1 (never failed). Servers are
+    /// ranked by this first and by latency second.
+    pub score: f64,
+    /// Whether the server is currently sidelined after repeated failures.
+    pub sidelined: bool,
+}
+
+/// Sidelined, reliability band (higher is better) and average latency; lower sorts first.
+type Rank = (bool, Reverse<u32>, f64);
+
+#[derive(Debug, Default)]
+struct ServerStats {
+    successes: u64,
+    failures: u64,
+    consecutive_failures: u32,
+    avg_latency_ms: Option<f64>,
+    sidelined_until: Option<Instant>,
+}
+
+impl ServerStats {
+    fn score(&self) -> f64 {
+        // Smoothed towards success, so a server that never failed (or was never tried) scores 1
+        (self.successes as f64 + 1.0) / ((self.successes + self.failures) as f64 + 1.0)
+    }
+
+    /// Sort key, best first: reliability band, then latency. Untried servers count as
+    /// instant so they get a chance before known slow ones.
+    fn rank(&self, now: Instant) -> Rank {
+        let band = (self.score() * RELIABILITY_BANDS).round() as u32;
+        (
+            self.is_sidelined(now),
+            Reverse(band),
+            self.avg_latency_ms.unwrap_or(0.0),
+        )
+    }
+
+    fn is_sidelined(&self, now: Instant) -> bool {
+        self.sidelined_until.is_some_and(|until| now < until)
+    }
+}
+
+/// Shared health table for all servers a `Checker` talks to.
+#[derive(Debug, Default)]
+pub(crate) struct HealthTracker {
+    servers: DashMap<String, ServerStats>,
+}
+
+impl HealthTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record_success(&self, server: &str, latency: Duration) {
+        let latency_ms = latency.as_secs_f64() * 1000.0;
+        let mut stats = self.servers.entry(server.to_string()).or_default();
+        stats.successes += 1;
+        stats.consecutive_failures = 0;
+        stats.sidelined_until = None;
+        stats.avg_latency_ms = Some(match stats.avg_latency_ms {
+            Some(avg) => avg + LATENCY_EWMA_WEIGHT * (latency_ms - avg),
+            None => latency_ms,
+        });
+    }
+
+    pub fn record_failure(&self, server: &str) {
+        let mut stats = self.servers.entry(server.to_string()).or_default();
+        stats.failures += 1;
+        stats.consecutive_failures += 1;
+        // Also re-sidelines a server whose trial query after the cool-down failed
+        if stats.consecutive_failures >= SIDELINE_AFTER_FAILURES {
+            stats.sidelined_until = Some(Instant::now() + SIDELINE_DURATION);
+        }
+    }
+
+    /// Order `servers` best first: healthy servers by reliability then latency, then
+    /// sidelined ones.
+    ///
+    /// Ties keep the registry order, so fresh servers are tried in their generated order.
+    pub fn order<'a>(&self, servers: &[&'a str]) -> Vec<&'a str> {
+        let now = Instant::now();
+        let mut ranked: Vec<(_, &'a str)> = servers
+            .iter()
+            .map(|&server| match self.servers.get(server) {
+                Some(stats) => (stats.rank(now), server),
+                None => (ServerStats::default().rank(now), server),
+            })
+            .collect();
+
+        ranked.sort_by(|(a, _), (b, _)| compare_ranks(a, b));
+        ranked.into_iter().map(|(_, server)| server).collect()
+    }
+
+    /// Snapshot of every server seen so far, least reliable first.
+    pub fn snapshot(&self) -> Vec<ServerHealth> {
+        let now = Instant::now();
+        let mut health: Vec<(_, ServerHealth)> = self
+            .servers
+            .iter()
+            .map(|entry| {
+                let stats = entry.value();
+                let health = ServerHealth {
+                    server: entry.key().clone(),
+                    successes: stats.successes,
+                    failures: stats.failures,
+                    consecutive_failures: stats.consecutive_failures,
+                    avg_latency_ms: stats.avg_latency_ms,
+                    score: stats.score(),
+                    sidelined: stats.is_sidelined(now),
+                };
+                (stats.rank(now), health)
+            })
+            .collect();
+
+        health.sort_by(|(a_rank, a), (b_rank, b)| compare_ranks(b_rank, a_rank).then_with(|| a.server.cmp(&b.server)));
+        health.into_iter().map(|(_, health)| health).collect()
+    }
+}
+
+fn compare_ranks(a: &Rank, b: &Rank) -> Ordering {
+    a.0.cmp(&b.0).then(a.1.cmp(&b.1)).then(a.2.total_cmp(&b.2))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unknown_servers_keep_registry_order() {
+        let tracker = HealthTracker::new();
+        assert_eq!(tracker.order(&["a", "b", "c"]), vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn faster_servers_are_tried_first() {
+        let tracker = HealthTracker::new();
+        tracker.record_success("a", Duration::from_millis(300));
+        tracker.record_success("b", Duration::from_millis(20));
+        assert_eq!(tracker.order(&["a", "b"]), vec!["b", "a"]);
+    }
+
+    #[test]
+    fn reliable_slow_servers_beat_failing_fast_ones() {
+        let tracker = HealthTracker::new();
+        tracker.record_success("slow", Duration::from_millis(300));
+        tracker.record_success("flaky", Duration::from_millis(5));
+        tracker.record_failure("flaky");
+        tracker.record_failure("down");
+        assert_eq!(tracker.order(&["down", "flaky", "slow"]), vec!["slow", "flaky", "down"]);
+        assert_eq!(tracker.snapshot().last().unwrap().server, "slow");
+    }
+
+    #[test]
+    fn repeated_failures_sideline_a_server() {
+        let tracker = HealthTracker::new();
+        for _ in 0..SIDELINE_AFTER_FAILURES {
+            tracker.record_failure("a");
+        }
+        tracker.record_success("b", Duration::from_millis(900));
+
+        assert_eq!(tracker.order(&["a", "b", "c"]), vec!["c", "b", "a"]);
+
+        let snapshot = tracker.snapshot();
+        let a = snapshot.iter().find(|health| health.server == "a").unwrap();
+        assert!(a.sidelined);
+        assert_eq!(a.consecutive_failures, SIDELINE_AFTER_FAILURES);
+
+        // A success brings it straight back
+        tracker.record_success("a", Duration::from_millis(10));
+        assert!(!tracker.snapshot().iter().any(|health| health.sidelined));
+    }
+}
@@ -10,8 +10,18 @@
 //! - **Accurate**: Queries authoritative TLD nameservers directly
 //! - **Pattern-based**: Generate and check domains matching patterns like `[a-z]{3}.com`
 //! - **Bulk checking**: Process lists of domains from files
+//! - **Internationalized names**: Unicode input is converted to punycode (UTS #46)
 //! - **Statistics**: Track response times, availability rates
 //! - **Export**: Save results to CSV
+//! - **WHOIS verification**: Confirm available hits with the registry's WHOIS server
+//! - **RDAP**: Verify available hits, or check registration directly, over RDAP
+//! - **DNSSEC**: Prove NXDOMAIN answers with signed NSEC/NSEC3 records, up to the root trust anchor
+//! - **Zone walking**: List the registered names of TLDs signed with plain NSEC
+//! - **Pluggable backends**: Combine DNS, RDAP, WHOIS or your own [`backend::CheckBackend`]
+//! - **Encrypted fallback**: Ask the fallback resolver over DNS-over-TLS or DNS-over-HTTPS
+//! - **Resolver control**: Pick the fallback resolvers or turn the fallback off, and pin a TLD to chosen servers
+//! - **Caching**: Answers are cached for their DNS TTL, optionally across runs
+//! - **Runtime registry**: Load TLD nameservers from IANA's root zone without a new release
 //!
 //! # Examples
 //!
@@ -28,7 +38,7 @@
 //!     .await?;
 //!
 //! let result = checker.check("example.com").await?;
-//! println!("{} is {}", result.domain, if result.available { "available" } else { "taken" });
+//! println!("{} is {}", result.domain, if result.available() { "available" } else { "taken" });
 //! # Ok(())
 //! # }
 //! ```
@@ -73,22 +83,49 @@
 //! A domain showing "available" means no NS records were found. Always verify with an
 //! official registrar before purchasing, as DNS may not reflect real-time registration status.
 
+pub mod backend;
 pub mod checker;
+pub mod dnssec;
 pub mod domain;
 pub mod export;
+pub mod health;
 pub mod pattern;
+pub mod psl;
+pub mod rdap;
+pub mod registry;
+pub mod retry;
 pub mod tld;
+pub mod transport;
+pub mod whois;
+pub mod zone_walk;
 
 pub(crate) mod dns_batch;
+pub(crate) mod dns_cache;
+pub(crate) mod dns_https;
+pub(crate) mod dns_message;
 pub(crate) mod dns_pipelined;
+pub(crate) mod dns_ratelimit;
+pub(crate) mod dns_rtt;
+pub(crate) mod dns_tcp;
+pub(crate) mod dns_tls;
 pub(crate) mod tld_registry;
 
-pub use checker::{CheckResult, Checker};
-pub use domain::Domain;
+pub use backend::{CheckBackend, RdapBackend, Strategy, WhoisBackend};
+pub use checker::{CheckResult, Checker, DomainStatus};
+pub use dnssec::DnssecStatus;
+pub use domain::{Domain, PolicyViolation};
 pub use export::{CsvExporter, StatsExporter};
+pub use health::ServerHealth;
 pub use pattern::Pattern;
+pub use rdap::{RdapClient, RdapInfo, RdapMode};
+pub use registry::Registry;
+pub use retry::{RetryOn, RetryPolicy};
 pub use tld::Tld;
-pub use tld_registry::{TLD_SERVERS, get_public_tlds, get_tld_info};
+pub use tld_registry::{
+    LabelPolicy, SUFFIX_POLICIES, TLD_SERVERS, TldInfo, get_public_tlds, get_suffix_policy, get_tld_info,
+};
+pub use transport::{ResolverTransport, TlsUpstream};
+pub use whois::{WhoisClient, WhoisVerdict};
 
 use thiserror::Error;
 
@@ -104,6 +141,18 @@ pub enum DomainCheckerError {
     #[error("Export failed: {0}")]
     Export(#[from] export::ExportError),
 
+    #[error("{0}")]
+    Rdap(#[from] rdap::RdapError),
+
+    #[error("{0}")]
+    Whois(#[from] whois::WhoisError),
+
+    #[error("{0}")]
+    Registry(#[from] registry::RegistryError),
+
+    #[error("{0}")]
+    ZoneWalk(#[from] zone_walk::ZoneWalkError),
+
     #[error("Invalid domain '{0}': must be 1-253 chars, valid labels (max 63 chars each), format: label.label.tld")]
     InvalidDomain(String),
 
@@ -120,6 +169,49 @@ pub enum DomainCheckerError {
 
     #[error("I/O error: {0}")]
     Io(#[from] std::io::Error),
+
+    /// A check from [`Checker::check_stream`] or [`Checker::check_batch`] failed; `domain`
+    /// is the domain it was for.
+    #[error("{domain}: {source}")]
+    Check {
+        domain: String,
+        #[source]
+        source: Box<DomainCheckerError>,
+    },
+}
+
+impl DomainCheckerError {
+    /// The domain a failed batch or stream check was for, if this error carries one.
+    pub fn domain(&self) -> Option<&str> {
+        match self {
+            DomainCheckerError::Check { domain, .. } => Some(domain),
+            _ => None,
+        }
+    }
+
+    /// The underlying error, without the domain a batch or stream check wraps it in.
+    pub fn cause(&self) -> &DomainCheckerError {
+        match self {
+            DomainCheckerError::Check { source, .. } => source.cause(),
+            other => other,
+        }
+    }
 }
 
 pub type Result<T> = std::result::Result<T, DomainCheckerError>;
+
+/// dotchk's directory in the user's cache directory: `dotchk` under `$XDG_CACHE_HOME`,
+/// `~/.cache`, or `%LOCALAPPDATA%` on Windows.
+///
+/// `None` if none of those is set.
+pub fn cache_dir() -> Option<std::path::PathBuf> {
+    let non_empty = |name: &str| {
+        std::env::var_os(name)
+            .filter(|value| !value.is_empty())
+            .map(std::path::PathBuf::from)
+    };
+    let cache_dir = non_empty("XDG_CACHE_HOME")
+        .or_else(|| non_empty("HOME").map(|home| home.join(".cache")))
+        .or_else(|| non_empty("LOCALAPPDATA"))?;
+    Some(cache_dir.join("dotchk"))
+}
@@ -45,6 +45,9 @@ struct Cli {
     #[arg(required = false)]
     domains: Vec<String>,
 
+    #[command(flatten)]
+    network: cli::NetworkArgs,
+
     #[command(subcommand)]
     command: Option<Commands>,
 }
@@ -281,6 +284,62 @@ EXAMPLES:
         #[arg(long)]
         stats: bool,
     },
+
+    /// List the registered names of a TLD signed with plain NSEC
+    #[command(
+        name = "zone-walk",
+        about = "List the registered names of a TLD signed with plain NSEC",
+        long_about = "Walk a TLD's NSEC chain: each NXDOMAIN names the next registered domain, so one query per name lists them all. TLDs signed with NSEC3 or not at all can't be walked.",
+        after_help = r#"EXAMPLES:
+    # Print every name registered in .example
+    dotchk zone-walk example
+
+    # Save them, gently
+    dotchk zone-walk example --qps 20 --output example-names.txt"#
+    )]
+    ZoneWalk {
+        /// TLD to walk (e.g., "se")
+        zone: String,
+
+        /// Query timeout in milliseconds
+        #[arg(long, default_value = "3000")]
+        timeout: u64,
+
+        /// Write the names to a file instead of stdout
+        #[arg(long, short)]
+        output: Option<PathBuf>,
+    },
+
+    /// Manage the TLD registry data
+    #[command(subcommand)]
+    Registry(RegistryCommands),
+}
+
+#[derive(Subcommand)]
+enum RegistryCommands {
+    /// Rebuild the registry cache from IANA's root zone
+    #[command(
+        about = "Rebuild the registry cache from IANA's root zone",
+        long_about = "Download (or read) a root zone file, look up the addresses of nameservers it has no glue for (through the system's resolvers, or --resolver/--dot/--doh, within the --qps limits), and write the registry cache every command loads on startup.",
+        after_help = r#"EXAMPLES:
+    # Fetch the current root zone from internic.net
+    dotchk registry update
+
+    # Use a local copy, and write the dump somewhere else
+    dotchk registry update root.zone --output registry.toml"#
+    )]
+    Update {
+        /// Root zone file path or URL (default: https://www.internic.net/domain/root.zone)
+        source: Option<String>,
+
+        /// Where to write the registry dump (.json or .toml; default: the user cache directory)
+        #[arg(long, short)]
+        output: Option<PathBuf>,
+
+        /// Query timeout in milliseconds for resolving nameservers
+        #[arg(long, default_value = "2000")]
+        timeout: u64,
+    },
 }
 
 #[tokio::main]
@@ -324,12 +383,12 @@ async fn main() -> Result<()> {
 
                 // Handle patterns first (they show only available domains)
                 for pattern in patterns {
-                    cli::check_pattern(pattern, Some(10000), 100, 500, true, None, false).await?;
+                    cli::check_pattern(pattern, Some(10000), 100, 500, &cli.network, true, None, false).await?;
                 }
 
                 // Then handle regular domains
                 if !regular_domains.is_empty() {
-                    cli::check_domains(regular_domains, 100, 5000, false, None).await?;
+                    cli::check_domains(regular_domains, 100, 5000, &cli.network, false, None).await?;
                 }
             } else {
                 // No domains and no subcommand, show help
@@ -344,7 +403,7 @@ async fn main() -> Result<()> {
             output,
         }) => {
             // Show all results for check command
-            cli::check_domains(domains, parallel, timeout, false, output).await?;
+            cli::check_domains(domains, parallel, timeout, &cli.network, false, output).await?;
         }
         Some(Commands::Pattern {
             pattern,
@@ -355,7 +414,7 @@ async fn main() -> Result<()> {
             stats,
         }) => {
             // ALWAYS show only available for patterns
-            cli::check_pattern(pattern, limit, parallel, timeout, true, output, stats).await?;
+            cli::check_pattern(pattern, limit, parallel, timeout, &cli.network, true, output, stats).await?;
         }
         Some(Commands::Bulk {
             file,
@@ -365,7 +424,7 @@ async fn main() -> Result<()> {
             output,
             stats,
         }) => {
-            cli::bulk_check(file, parallel, timeout, available_only, output, stats).await?;
+            cli::bulk_check(file, parallel, timeout, &cli.network, available_only, output, stats).await?;
         }
         Some(Commands::Tld {
             domains,
@@ -395,12 +454,23 @@ async fn main() -> Result<()> {
                 all,
                 parallel,
                 timeout,
+                &cli.network,
                 available_only,
                 output,
                 stats,
             )
             .await?;
         }
+        Some(Commands::ZoneWalk { zone, timeout, output }) => {
+            cli::walk_zone(zone, timeout, &cli.network, output).await?;
+        }
+        Some(Commands::Registry(RegistryCommands::Update {
+            source,
+            output,
+            timeout,
+        })) => {
+            cli::update_registry(source, timeout, &cli.network, output).await?;
+        }
     }
 
     Ok(())
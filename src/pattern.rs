@@ -15,6 +15,10 @@ pub enum PatternError {
     TooComplex,
 }
 
+/// Widest character class range, e.g. `[a-z]` is 26; wider ones are rejected before
+/// they're expanded.
+const MAX_CLASS_RANGE: u32 = 1024;
+
 // Common TLDs that should be recognized for auto-escaping
 // Using LazyLock (stable since Rust 1.80) instead of lazy_static
 static COMMON_TLDS: LazyLock<Vec<&'static str>> = LazyLock::new(|| {
@@ -676,9 +680,14 @@ fn parse_pattern(pattern: &str) -> Result<Vec<PatternPart>> {
                     i += 1;
                     let end = chars[i];
                     class_chars.pop();
-                    for c in start as u8..=end as u8 {
-                        class_chars.push(c as char);
+                    // Ranges may span any script, e.g. [а-я], but not all of Unicode
+                    let width = (end as u32).checked_sub(start as u32).map(|span| span + 1);
+                    if width.is_none_or(|width| width > MAX_CLASS_RANGE) {
+                        return Err(PatternError::InvalidPattern(format!(
+                            "character range {start}-{end} must run forwards and span at most {MAX_CLASS_RANGE} characters"
+                        )));
                     }
+                    class_chars.extend(start..=end);
                 } else {
                     class_chars.push(chars[i]);
                 }
@@ -964,6 +973,28 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn pattern_accepts_unicode_labels() {
+        let pattern = Pattern::compile("café[0-9].fr").unwrap();
+        assert_eq!(pattern.generate(Some(2)), vec!["café0.fr", "café1.fr"]);
+
+        let pattern = Pattern::compile("[а-в]{2}.рф").unwrap();
+        let domains = pattern.generate(None);
+        assert_eq!(domains.len(), 9);
+        assert_eq!(domains[0], "аа.рф");
+    }
+
+    #[test]
+    fn pattern_rejects_huge_or_reversed_ranges() {
+        assert!(matches!(
+            Pattern::compile("[a-\u{10FFFF}].com"),
+            Err(PatternError::InvalidPattern(_))
+        ));
+        assert!(Pattern::compile("[z-a].com").is_err());
+        assert!(Pattern::compile("[一-龥].cn").is_err());
+        assert!(Pattern::compile("[ぁ-ゖ].jp").is_ok());
+    }
+
     #[test]
     fn pattern_handles_escaped_chars() {
         let pattern = Pattern::compile("test\\-domain\\.[a-z]{3}").unwrap();
@@ -0,0 +1,145 @@
+//! Public suffixes: the names under which domains can be registered.
+//!
+//! Registries don't only sell names directly under a TLD: `brand.co.uk`, `brand.com.au`
+//! and `brand.com.br` are registered under `co.uk`, `com.au` and `com.br`. The ICANN
+//! section of the [Public Suffix List](https://publicsuffix.org/) is embedded to tell these
+//! apart; `scripts/update_tlds.py` refreshes the snapshot.
+
+use crate::domain::to_ascii;
+use std::borrow::Cow;
+use std::collections::HashSet;
+use std::sync::LazyLock;
+
+/// Snapshot of the ICANN section of the Public Suffix List.
+const PUBLIC_SUFFIX_LIST: &str = include_str!("public_suffix_list.dat");
+
+/// Rules in ASCII form; the list spells internationalized suffixes in Unicode.
+struct Rules {
+    /// Plain rules, e.g. `co.uk`.
+    exact: HashSet<Cow<'static, str>>,
+    /// Wildcard rules without the leading `*.`, e.g. `ck` for `*.ck`.
+    wildcard: HashSet<Cow<'static, str>>,
+    /// Exception rules without the leading `!`, e.g. `www.ck`.
+    exception: HashSet<Cow<'static, str>>,
+}
+
+fn ascii_rule(rule: &'static str) -> Cow<'static, str> {
+    if rule.is_ascii() {
+        return Cow::Borrowed(rule);
+    }
+    to_ascii(rule).map_or(Cow::Borrowed(rule), Cow::Owned)
+}
+
+static RULES: LazyLock<Rules> = LazyLock::new(|| {
+    let mut rules = Rules {
+        exact: HashSet::new(),
+        wildcard: HashSet::new(),
+        exception: HashSet::new(),
+    };
+    for line in PUBLIC_SUFFIX_LIST.lines() {
+        let Some(rule) = line.split_whitespace().next() else {
+            continue;
+        };
+        if rule.starts_with("//") {
+            continue;
+        }
+        if let Some(parent) = rule.strip_prefix("*.") {
+            rules.wildcard.insert(ascii_rule(parent));
+        } else if let Some(name) = rule.strip_prefix('!') {
+            rules.exception.insert(ascii_rule(name));
+        } else {
+            rules.exact.insert(ascii_rule(rule));
+        }
+    }
+    rules
+});
+
+/// The public suffix of `domain` (lowercase, A-labels): `co.uk` for `www.brand.co.uk`.
+///
+/// Names no rule covers fall back to their last label, as the list prescribes.
+/// Returns `None` for an empty name or one with empty labels.
+///
+/// # Examples
+///
+/// ```
+/// use dotchk::psl::public_suffix;
+///
+/// assert_eq!(public_suffix("brand.co.uk"), Some("co.uk"));
+/// assert_eq!(public_suffix("example.com"), Some("com"));
+/// assert_eq!(public_suffix("example.unknowntld"), Some("unknowntld"));
+/// ```
+pub fn public_suffix(domain: &str) -> Option<&str> {
+    if domain.is_empty() || domain.split('.').any(str::is_empty) {
+        return None;
+    }
+
+    let rules = &*RULES;
+    // Candidates from longest to shortest, so the first match is the longest rule
+    let mut candidate = domain;
+    loop {
+        let parent = candidate.split_once('.').map(|(_, parent)| parent);
+        if rules.exception.contains(candidate) {
+            // An exception is registrable itself; its parent is the suffix
+            return parent;
+        }
+        if rules.exact.contains(candidate) || parent.is_some_and(|parent| rules.wildcard.contains(parent)) {
+            return Some(candidate);
+        }
+        match parent {
+            Some(parent) => candidate = parent,
+            // Default rule "*": the last label
+            None => return Some(candidate),
+        }
+    }
+}
+
+/// The registrable domain of `domain`: its public suffix plus one label, e.g. `brand.co.uk`
+/// for `www.brand.co.uk`.
+///
+/// Returns `None` if `domain` is a public suffix itself.
+pub fn registrable_domain(domain: &str) -> Option<&str> {
+    let suffix = public_suffix(domain)?;
+    let prefix = domain.strip_suffix(suffix)?.strip_suffix('.')?;
+    let label_start = prefix.rfind('.').map_or(0, |dot| dot + 1);
+    Some(&domain[label_start..])
+}
+
+/// Whether `name` is a public suffix, e.g. `com` or `co.uk`.
+pub fn is_public_suffix(name: &str) -> bool {
+    public_suffix(name) == Some(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_multi_level_suffixes() {
+        assert_eq!(public_suffix("brand.co.uk"), Some("co.uk"));
+        assert_eq!(public_suffix("www.brand.com.au"), Some("com.au"));
+        assert_eq!(public_suffix("brand.com.br"), Some("com.br"));
+        assert_eq!(public_suffix("brand.uk"), Some("uk"));
+        assert_eq!(public_suffix("co.uk"), Some("co.uk"));
+        assert_eq!(public_suffix("example..com"), None);
+        // 公司.cn, listed in Unicode
+        assert_eq!(public_suffix("brand.xn--55qx5d.cn"), Some("xn--55qx5d.cn"));
+    }
+
+    #[test]
+    fn applies_wildcards_and_exceptions() {
+        // *.ck and !www.ck
+        assert_eq!(public_suffix("brand.anything.ck"), Some("anything.ck"));
+        assert_eq!(public_suffix("www.ck"), Some("ck"));
+        assert_eq!(registrable_domain("www.ck"), Some("www.ck"));
+    }
+
+    #[test]
+    fn registrable_domain_keeps_one_label() {
+        assert_eq!(registrable_domain("shop.brand.co.uk"), Some("brand.co.uk"));
+        assert_eq!(registrable_domain("brand.co.uk"), Some("brand.co.uk"));
+        assert_eq!(registrable_domain("example.com"), Some("example.com"));
+        assert_eq!(registrable_domain("co.uk"), None);
+        assert!(is_public_suffix("com.au"));
+        assert!(!is_public_suffix("brand.com.au"));
+    }
+}
@@ -0,0 +1,356 @@
+//! RDAP registration lookups.
+//!
+//! RDAP is the JSON successor to WHOIS: `GET {base}domain/{name}` answers 404 for an
+//! unregistered domain and 200 with the registration record otherwise, so there's no
+//! free-form text to guess at. Base URLs come from the IANA bootstrap file as shipped in
+//! the TLD registry; see [`RdapClient::bootstrap_fallback`] to download the current file
+//! for TLDs that copy has none for.
+//!
+//! The `Checker` can use RDAP to verify domains DNS reports as available, or as the
+//! primary backend with DNS as fallback; see [`RdapMode`].
+
+use crate::tld_registry::get_tld_info;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+use thiserror::Error;
+use tokio::sync::OnceCell;
+use tracing::{debug, warn};
+
+const RDAP_CONTENT_TYPE: &str = "application/rdap+json";
+/// IANA bootstrap file mapping TLDs to RDAP base URLs (RFC 9224).
+const RDAP_BOOTSTRAP_URL: &str = "https://data.iana.org/rdap/dns.json";
+
+/// Errors from an RDAP lookup.
+#[derive(Error, Debug)]
+pub enum RdapError {
+    #[error("RDAP request failed: {0}")]
+    Http(#[from] reqwest::Error),
+
+    #[error("RDAP server answered HTTP {0}")]
+    Status(u16),
+
+    #[error("Invalid RDAP response: {0}")]
+    InvalidResponse(String),
+
+    #[error("No RDAP server known for TLD '.{0}'")]
+    NoServer(String),
+}
+
+/// How the `Checker` uses RDAP.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RdapMode {
+    /// DNS only.
+    #[default]
+    Off,
+    /// Look up domains DNS reports as available, and record what RDAP says.
+    Verify,
+    /// Ask RDAP first; fall back to DNS for TLDs without RDAP or when the lookup fails.
+    Primary,
+}
+
+impl FromStr for RdapMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "off" => Ok(RdapMode::Off),
+            "verify" => Ok(RdapMode::Verify),
+            "primary" => Ok(RdapMode::Primary),
+            _ => Err(format!("invalid RDAP mode '{s}' (expected off, verify or primary)")),
+        }
+    }
+}
+
+/// What a registry's RDAP server says about a domain.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RdapInfo {
+    /// False when the server answered 404.
+    pub registered: bool,
+    /// EPP-style status values, e.g. `client transfer prohibited`.
+    pub status: Vec<String>,
+    /// Name of the sponsoring registrar.
+    pub registrar: Option<String>,
+    /// Registration date as given by the server (RFC 3339).
+    pub registered_at: Option<String>,
+    /// Expiry date as given by the server (RFC 3339).
+    pub expires_at: Option<String>,
+    /// Nameserver hostnames (lowercase, no trailing dot).
+    pub nameservers: Vec<String>,
+}
+
+impl RdapInfo {
+    fn unregistered() -> Self {
+        Self::default()
+    }
+
+    /// Parse an RDAP domain object (RFC 9083).
+    pub fn from_json(body: &Value) -> Result<Self, RdapError> {
+        if body.get("objectClassName").and_then(Value::as_str) != Some("domain") {
+            return Err(RdapError::InvalidResponse("not a domain object".to_string()));
+        }
+
+        let status = body
+            .get("status")
+            .and_then(Value::as_array)
+            .map(|values| values.iter().filter_map(Value::as_str).map(str::to_string).collect())
+            .unwrap_or_default();
+
+        let event_date = |action: &str| {
+            body.get("events")?.as_array()?.iter().find_map(|event| {
+                (event.get("eventAction")?.as_str()? == action)
+                    .then(|| event.get("eventDate")?.as_str().map(str::to_string))
+                    .flatten()
+            })
+        };
+
+        let registrar = body.get("entities").and_then(Value::as_array).and_then(|entities| {
+            entities
+                .iter()
+                .find(|entity| has_role(entity, "registrar"))
+                .and_then(vcard_name)
+        });
+
+        let nameservers = body
+            .get("nameservers")
+            .and_then(Value::as_array)
+            .map(|nameservers| {
+                nameservers
+                    .iter()
+                    .filter_map(|ns| ns.get("ldhName")?.as_str())
+                    .map(|name| name.trim_end_matches('.').to_lowercase())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Ok(Self {
+            registered: true,
+            status,
+            registrar,
+            registered_at: event_date("registration"),
+            expires_at: event_date("expiration"),
+            nameservers,
+        })
+    }
+}
+
+fn has_role(entity: &Value, role: &str) -> bool {
+    entity
+        .get("roles")
+        .and_then(Value::as_array)
+        .is_some_and(|roles| roles.iter().any(|r| r.as_str() == Some(role)))
+}
+
+/// The `fn` (formatted name) property of an entity's jCard.
+fn vcard_name(entity: &Value) -> Option<String> {
+    // ["vcard", [["version", {}, "text", "4.0"], ["fn", {}, "text", "Example Registrar"], ...]]
+    let properties = entity.get("vcardArray")?.as_array()?.get(1)?.as_array()?;
+    properties.iter().find_map(|property| {
+        let property = property.as_array()?;
+        (property.first()?.as_str()? == "fn")
+            .then(|| property.get(3)?.as_str().map(str::to_string))
+            .flatten()
+    })
+}
+
+/// TLD -> RDAP base URL (ending in `/`) from an IANA bootstrap file, preferring HTTPS
+/// URLs as `scripts/update_tlds.py` does.
+fn parse_bootstrap(bootstrap: &Value) -> HashMap<String, String> {
+    let mut base_urls = HashMap::new();
+    let services = bootstrap.get("services").and_then(Value::as_array);
+    for service in services.into_iter().flatten() {
+        let (Some(tlds), Some(urls)) = (
+            service.get(0).and_then(Value::as_array),
+            service.get(1).and_then(Value::as_array),
+        ) else {
+            continue;
+        };
+        let urls: Vec<&str> = urls.iter().filter_map(Value::as_str).collect();
+        let Some(url) = urls.iter().find(|url| url.starts_with("https://")).or_else(|| urls.first()) else {
+            continue;
+        };
+        let base = format!("{}/", url.trim_end_matches('/'));
+        for tld in tlds.iter().filter_map(Value::as_str) {
+            base_urls.insert(tld.to_lowercase(), base.clone());
+        }
+    }
+    base_urls
+}
+
+/// RDAP client for domain lookups.
+#[derive(Debug, Clone)]
+pub struct RdapClient {
+    http: reqwest::Client,
+    timeout: Duration,
+    base_url: Option<String>,
+    // From RDAP_BOOTSTRAP_URL once downloaded, if `bootstrap_fallback` enabled it
+    bootstrap: Option<Arc<OnceCell<HashMap<String, String>>>>,
+}
+
+impl Default for RdapClient {
+    fn default() -> Self {
+        Self {
+            http: reqwest::Client::new(),
+            timeout: Duration::from_secs(10),
+            base_url: None,
+            bootstrap: None,
+        }
+    }
+}
+
+impl RdapClient {
+    /// Client with a 10 second timeout, using the registry's RDAP base URLs.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Timeout for one lookup, including reading the body.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    pub(crate) fn timeout_duration(&self) -> Duration {
+        self.timeout
+    }
+
+    /// Send every lookup to `base_url` instead of the TLD's RDAP server.
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = Some(base_url.into());
+        self
+    }
+
+    /// For TLDs the registry has no RDAP base URL for, download IANA's current bootstrap
+    /// file and look them up there (off by default). The file is fetched on the first such
+    /// lookup and kept for the client's lifetime; a failed download is retried next time.
+    pub fn bootstrap_fallback(mut self, enabled: bool) -> Self {
+        self.bootstrap = enabled.then(|| Arc::new(OnceCell::new()));
+        self
+    }
+
+    /// The RDAP base URL responsible for `domain`, if any.
+    pub async fn base_url_for(&self, domain: &str) -> Option<String> {
+        if let Some(base_url) = &self.base_url {
+            return Some(base_url.clone());
+        }
+        if let Some(base_url) = get_tld_info(domain).and_then(|info| info.rdap) {
+            return Some(base_url.to_string());
+        }
+
+        let tld = domain.rsplit('.').next().unwrap_or(domain).to_lowercase();
+        let bootstrap = self.bootstrap.as_ref()?;
+        match bootstrap.get_or_try_init(|| self.download_bootstrap()).await {
+            Ok(base_urls) => base_urls.get(&tld).cloned(),
+            Err(e) => {
+                warn!("Could not download the RDAP bootstrap file: {}", e);
+                None
+            }
+        }
+    }
+
+    async fn download_bootstrap(&self) -> Result<HashMap<String, String>, reqwest::Error> {
+        let response = self
+            .http
+            .get(RDAP_BOOTSTRAP_URL)
+            .timeout(self.timeout)
+            .send()
+            .await?
+            .error_for_status()?;
+        let base_urls = parse_bootstrap(&response.json::<Value>().await?);
+        debug!("RDAP bootstrap lists {} TLDs", base_urls.len());
+        Ok(base_urls)
+    }
+
+    /// Look `domain` up at its registry's RDAP server; also returns the base URL asked.
+    pub async fn lookup(&self, domain: &str) -> Result<(RdapInfo, String), RdapError> {
+        let base = self
+            .base_url_for(domain)
+            .await
+            .ok_or_else(|| RdapError::NoServer(domain.rsplit('.').next().unwrap_or(domain).to_string()))?;
+        let url = format!("{}/domain/{}", base.trim_end_matches('/'), domain);
+
+        let response = self
+            .http
+            .get(&url)
+            .header(reqwest::header::ACCEPT, RDAP_CONTENT_TYPE)
+            .timeout(self.timeout)
+            .send()
+            .await?;
+
+        let info = match response.status().as_u16() {
+            200 => RdapInfo::from_json(&response.json::<Value>().await?)?,
+            404 => RdapInfo::unregistered(),
+            code => return Err(RdapError::Status(code)),
+        };
+        Ok((info, base))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REGISTERED: &str = r#"{
+        "objectClassName": "domain",
+        "ldhName": "EXAMPLE.COM",
+        "status": ["client delete prohibited", "client transfer prohibited"],
+        "events": [
+            {"eventAction": "registration", "eventDate": "1995-08-14T04:00:00Z"},
+            {"eventAction": "expiration", "eventDate": "2026-08-13T04:00:00Z"}
+        ],
+        "entities": [{
+            "objectClassName": "entity",
+            "roles": ["registrar"],
+            "vcardArray": ["vcard", [["version", {}, "text", "4.0"], ["fn", {}, "text", "RESERVED-IANA"]]]
+        }],
+        "nameservers": [
+            {"objectClassName": "nameserver", "ldhName": "A.IANA-SERVERS.NET"},
+            {"objectClassName": "nameserver", "ldhName": "B.IANA-SERVERS.NET."}
+        ]
+    }"#;
+
+    #[test]
+    fn parses_registration_record() {
+        let info = RdapInfo::from_json(&serde_json::from_str(REGISTERED).unwrap()).unwrap();
+        assert!(info.registered);
+        assert_eq!(info.status.len(), 2);
+        assert_eq!(info.registrar.as_deref(), Some("RESERVED-IANA"));
+        assert_eq!(info.registered_at.as_deref(), Some("1995-08-14T04:00:00Z"));
+        assert_eq!(info.expires_at.as_deref(), Some("2026-08-13T04:00:00Z"));
+        assert_eq!(info.nameservers, vec!["a.iana-servers.net", "b.iana-servers.net"]);
+
+        assert!(RdapInfo::from_json(&serde_json::json!({"objectClassName": "entity"})).is_err());
+    }
+
+    #[tokio::test]
+    async fn base_urls_come_from_registry() {
+        let client = RdapClient::new();
+        assert_eq!(
+            client.base_url_for("example.com").await.as_deref(),
+            Some("https://rdap.verisign.com/com/v1/")
+        );
+        // Nothing is downloaded unless asked for
+        assert_eq!(client.base_url_for("example.invalid").await, None);
+        assert_eq!("Verify".parse::<RdapMode>().unwrap(), RdapMode::Verify);
+    }
+
+    #[test]
+    fn parses_bootstrap_file() {
+        let bootstrap = serde_json::json!({
+            "version": "1.0",
+            "services": [
+                [["COM", "net"], ["http://rdap.example/v1", "https://rdap.example/v1"]],
+                [["org"], ["https://rdap.org.example/"]],
+                [["broken"]]
+            ]
+        });
+        let base_urls = parse_bootstrap(&bootstrap);
+        assert_eq!(base_urls["com"], "https://rdap.example/v1/");
+        assert_eq!(base_urls["net"], "https://rdap.example/v1/");
+        assert_eq!(base_urls["org"], "https://rdap.org.example/");
+        assert_eq!(base_urls.len(), 3);
+    }
+}
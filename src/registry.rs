@@ -0,0 +1,816 @@
+//! TLD registry data loaded at runtime.
+//!
+//! The compiled-in [`TLD_SERVERS`] table only changes when `scripts/update_tlds.py` is run and
+//! dotchk is rebuilt. A [`Registry`] layers entries loaded at runtime on top of it, so new
+//! gTLDs and renumbered nameservers don't need a release. It reads either IANA's
+//! [`root.zone`](https://www.internic.net/domain/root.zone) or a dotchk dump in JSON or TOML:
+//!
+//! ```toml
+//! [tlds.example]
+//! servers = ["192.0.2.1", "198.51.100.1"]
+//! timeout_ms = 1000
+//! policy = { min_length = 2 }
+//! ```
+//!
+//! Loaded entries override compiled-in ones of the same name; every field left out (or a
+//! root zone TLD without IPv4 glue) keeps the compiled-in value. An entry that ends up without
+//! servers is rejected. WHOIS and RDAP servers always come from the compiled-in table.
+//!
+//! `dotchk registry update` turns a root zone into such a dump at [`Registry::cache_path`],
+//! after resolving the nameservers the zone has no glue for ([`RootZone::resolve_missing_glue`]);
+//! the CLI loads it on startup.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use dotchk::{Checker, Registry};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let registry = Registry::load("root.zone")?;
+//! let checker = Checker::builder().registry(registry)?.build().await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::Checker;
+use crate::domain::to_ascii;
+use crate::psl;
+use crate::tld_registry::{LabelPolicy, TLD_SERVERS, TldInfo, get_suffix_policy};
+use futures::stream::{self, StreamExt};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::net::{IpAddr, Ipv4Addr};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, LazyLock};
+use thiserror::Error;
+use tracing::debug;
+
+/// Query timeout for TLDs the compiled-in table doesn't know, as `update_tlds.py` uses.
+pub(crate) const DEFAULT_TIMEOUT_MS: u64 = 1000;
+
+/// Nameserver IPs kept per TLD, as in the compiled-in table.
+const MAX_SERVERS: usize = 3;
+
+/// Address lookups in flight at once in [`RootZone::resolve_missing_glue`].
+pub const RESOLVE_PARALLELISM: usize = 32;
+
+/// Errors from loading registry data.
+#[derive(Error, Debug)]
+pub enum RegistryError {
+    #[error("Could not read registry file: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Invalid registry JSON: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("Invalid registry TOML: {0}")]
+    Toml(#[from] toml::de::Error),
+
+    #[error("Could not write registry TOML: {0}")]
+    TomlWrite(#[from] toml::ser::Error),
+
+    #[error("Invalid root zone, line {line}: {message}")]
+    RootZone { line: usize, message: String },
+
+    #[error("Invalid registry entry for '.{tld}': {message}")]
+    Entry { tld: String, message: String },
+
+    #[error("Registry data contains no TLDs")]
+    Empty,
+}
+
+/// TLD delegations and nameserver glue parsed from a root zone file.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RootZone {
+    /// Nameserver hostnames of each TLD (lowercase, no trailing dot), in file order.
+    pub delegations: BTreeMap<String, Vec<String>>,
+    /// Addresses of nameserver hosts, from the zone's A and AAAA records.
+    pub glue: HashMap<String, Vec<IpAddr>>,
+}
+
+impl RootZone {
+    /// Parse a zone file in the format IANA publishes `root.zone` in: one record per line,
+    /// `owner [ttl] [class] type rdata`.
+    ///
+    /// Only NS records of TLDs and A/AAAA records are kept; other types, comments, `$`
+    /// directives and parenthesized continuations are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for an NS, A or AAAA record without valid data, or a file without
+    /// any TLD delegation.
+    pub fn parse(text: &str) -> Result<Self, RegistryError> {
+        let mut zone = RootZone::default();
+        let mut owner = String::new();
+        let mut in_parens = false;
+
+        for (index, line) in text.lines().enumerate() {
+            let line_number = index + 1;
+            let line = line.split(';').next().unwrap_or_default();
+            if in_parens {
+                in_parens = !line.contains(')');
+                continue;
+            }
+            if line.trim().is_empty() || line.starts_with('$') {
+                continue;
+            }
+            in_parens = line.contains('(') && !line.contains(')');
+
+            let mut fields = line.split_whitespace();
+            // A record starting with whitespace belongs to the previous owner
+            if !line.starts_with(char::is_whitespace) {
+                owner = fields.next().unwrap_or_default().to_ascii_lowercase();
+            }
+            let Some(record_type) = fields.find(|field| !is_ttl_or_class(field)) else {
+                continue;
+            };
+            let record_type = record_type.to_ascii_uppercase();
+            if !matches!(record_type.as_str(), "NS" | "A" | "AAAA") {
+                continue;
+            }
+
+            let error = |message: String| RegistryError::RootZone {
+                line: line_number,
+                message,
+            };
+            let data = fields
+                .next()
+                .ok_or_else(|| error(format!("{record_type} record without data")))?;
+            let name = owner.trim_end_matches('.');
+            if record_type == "NS" {
+                // The root's own servers, and delegations below TLDs, aren't registries
+                if name.is_empty() || name == "@" || name.contains('.') {
+                    continue;
+                }
+                let host = data.trim_end_matches('.').to_ascii_lowercase();
+                zone.delegations.entry(name.to_string()).or_default().push(host);
+            } else {
+                let ip: IpAddr = data
+                    .parse()
+                    .map_err(|_| error(format!("invalid {record_type} address '{data}'")))?;
+                let addresses = zone.glue.entry(name.to_string()).or_default();
+                if !addresses.contains(&ip) {
+                    addresses.push(ip);
+                }
+            }
+        }
+
+        if zone.delegations.is_empty() {
+            return Err(RegistryError::Empty);
+        }
+        Ok(zone)
+    }
+
+    /// Up to 3 IPv4 addresses of `tld`'s nameservers from the zone's glue, taken from as
+    /// many different hosts as possible.
+    ///
+    /// Empty if none of its nameservers has IPv4 glue in the zone.
+    pub fn ipv4_servers(&self, tld: &str) -> Vec<String> {
+        let hosts: Vec<Vec<IpAddr>> = self
+            .delegations
+            .get(tld)
+            .into_iter()
+            .flatten()
+            .map(|host| {
+                self.glue
+                    .get(host)
+                    .into_iter()
+                    .flatten()
+                    .filter(|ip| ip.is_ipv4())
+                    .copied()
+                    .collect()
+            })
+            .collect();
+
+        // First address of every host, then the second of every host, and so on
+        let mut servers = Vec::new();
+        let rounds = hosts.iter().map(Vec::len).max().unwrap_or(0);
+        for round in 0..rounds {
+            for ip in hosts.iter().filter_map(|addresses| addresses.get(round)) {
+                let ip = ip.to_string();
+                if servers.len() < MAX_SERVERS && !servers.contains(&ip) {
+                    servers.push(ip);
+                }
+            }
+        }
+        servers
+    }
+
+    /// Nameserver hosts of TLDs that have no IPv4 glue at all in the zone, sorted.
+    ///
+    /// Those are typically out-of-bailiwick names like `ns1.example.net` serving another TLD.
+    pub fn missing_glue(&self) -> Vec<&str> {
+        let mut hosts: Vec<&str> = self
+            .delegations
+            .iter()
+            .filter(|(tld, _)| self.ipv4_servers(tld).is_empty())
+            .flat_map(|(_, hosts)| hosts.iter().map(String::as_str))
+            .collect();
+        hosts.sort_unstable();
+        hosts.dedup();
+        hosts
+    }
+
+    /// Look up addresses for [`RootZone::missing_glue`] with A queries through `checker`'s
+    /// resolvers (see [`Checker::resolve_ipv4`]), and add them as glue. Returns how many hosts
+    /// were resolved; the others stay without glue.
+    pub async fn resolve_missing_glue(&mut self, checker: &Checker) -> usize {
+        let hosts: Vec<String> = self.missing_glue().into_iter().map(str::to_string).collect();
+        let lookups = hosts.into_iter().map(|host| async move {
+            let addresses = checker.resolve_ipv4(&host).await.unwrap_or_else(|e| {
+                debug!("Could not resolve {}: {}", host, e);
+                Vec::new()
+            });
+            debug!("Addresses of {}: {:?}", host, addresses);
+            (host, addresses)
+        });
+        let resolved: Vec<(String, Vec<Ipv4Addr>)> =
+            stream::iter(lookups).buffer_unordered(RESOLVE_PARALLELISM).collect().await;
+
+        let mut count = 0;
+        for (host, addresses) in resolved {
+            if addresses.is_empty() {
+                continue;
+            }
+            count += 1;
+            let glue = self.glue.entry(host).or_default();
+            for ip in addresses.into_iter().map(IpAddr::V4) {
+                if !glue.contains(&ip) {
+                    glue.push(ip);
+                }
+            }
+        }
+        count
+    }
+
+    /// Write the zone as a dotchk dump (see [`RootZone::to_json`]): TOML for a `.toml`
+    /// extension, JSON otherwise. Missing parent directories are created.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can't be written.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), RegistryError> {
+        let path = path.as_ref();
+        let text = match path.extension().and_then(|ext| ext.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("toml") => self.to_toml()?,
+            _ => self.to_json()?,
+        };
+        if let Some(parent) = path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, text)?;
+        Ok(())
+    }
+
+    /// The zone as a dotchk JSON dump: every TLD with its [`RootZone::ipv4_servers`].
+    ///
+    /// Only what the zone says is written, so the rest keeps following the compiled-in
+    /// table when dotchk is upgraded. TLDs without servers in either are left out, as
+    /// [`Registry::from_zone`] does.
+    pub fn to_json(&self) -> Result<String, RegistryError> {
+        Ok(serde_json::to_string_pretty(&self.dump())?)
+    }
+
+    /// The zone as a dotchk TOML dump, like [`RootZone::to_json`].
+    pub fn to_toml(&self) -> Result<String, RegistryError> {
+        Ok(toml::to_string(&self.dump())?)
+    }
+
+    fn dump(&self) -> RegistryFile {
+        let tlds = self
+            .delegations
+            .keys()
+            .map(|tld| {
+                let entry = EntryFile {
+                    servers: self.ipv4_servers(tld),
+                    ..EntryFile::default()
+                };
+                (tld.clone(), entry)
+            })
+            .filter(|(tld, entry)| !entry.servers.is_empty() || TLD_SERVERS.contains_key(tld.as_str()))
+            .collect();
+        RegistryFile { tlds }
+    }
+}
+
+fn is_ttl_or_class(field: &str) -> bool {
+    field.bytes().all(|b| b.is_ascii_digit())
+        || matches!(field.to_ascii_uppercase().as_str(), "IN" | "CH" | "HS" | "CS")
+}
+
+/// A dotchk registry dump: TLD (or second-level suffix, like `co.uk`) to entry.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RegistryFile {
+    tlds: BTreeMap<String, EntryFile>,
+}
+
+/// One TLD in a registry dump; fields left out keep their compiled-in value.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct EntryFile {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    servers: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    timeout_ms: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    excluded_from_all: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    categories: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    policy: Option<PolicyFile>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct PolicyFile {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    min_length: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_length: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    numeric: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    idn: Option<bool>,
+}
+
+/// A TLD's (or second-level suffix's) data in a [`Registry`]: a loaded entry, or the
+/// compiled-in [`TldInfo`] of that name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TldEntry {
+    /// Nameserver IPs to query.
+    pub servers: Vec<String>,
+    pub timeout_ms: u64,
+    pub excluded_from_all: bool,
+    pub categories: Vec<String>,
+    /// Registry WHOIS server, always from the compiled-in table.
+    pub whois: Option<&'static str>,
+    /// RDAP base URL, always from the compiled-in table.
+    pub rdap: Option<&'static str>,
+    /// Which labels the registry accepts directly under this name.
+    pub policy: LabelPolicy,
+}
+
+impl From<&TldInfo> for TldEntry {
+    fn from(info: &TldInfo) -> Self {
+        Self {
+            servers: owned(info.servers),
+            timeout_ms: info.timeout_ms,
+            excluded_from_all: info.excluded_from_all,
+            categories: owned(info.categories),
+            whois: info.whois,
+            rdap: info.rdap,
+            policy: info.policy,
+        }
+    }
+}
+
+/// [`TLD_SERVERS`] as [`TldEntry`]s, converted once on first use.
+static BUILTIN: LazyLock<HashMap<&'static str, TldEntry>> =
+    LazyLock::new(|| TLD_SERVERS.entries().map(|(tld, info)| (*tld, info.into())).collect());
+
+/// TLD registry data: entries loaded at runtime over the compiled-in [`TLD_SERVERS`].
+///
+/// Cloning is cheap; clones share the loaded entries, which are freed with the last clone.
+///
+/// The default registry has no loaded entries and behaves like [`crate::get_tld_info`].
+#[derive(Debug, Clone, Default)]
+pub struct Registry {
+    loaded: Arc<HashMap<String, TldEntry>>,
+}
+
+impl Registry {
+    /// Load a registry file: JSON for a `.json` extension, TOML for `.toml`, and a root zone
+    /// file otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can't be read or parsed.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, RegistryError> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path)?;
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("json") => Self::from_json(&text),
+            Some(ext) if ext.eq_ignore_ascii_case("toml") => Self::from_toml(&text),
+            _ => Self::from_root_zone(&text),
+        }
+    }
+
+    /// Where `dotchk registry update` writes its dump: `registry.json` in [`crate::cache_dir`].
+    pub fn cache_path() -> Option<PathBuf> {
+        crate::cache_dir().map(|dir| dir.join("registry.json"))
+    }
+
+    /// The registry at [`Registry::cache_path`], or `None` if there is none.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file exists but can't be read or parsed.
+    pub fn load_cached() -> Result<Option<Self>, RegistryError> {
+        match Self::cache_path() {
+            Some(path) if path.exists() => Self::load(path).map(Some),
+            _ => Ok(None),
+        }
+    }
+
+    /// Registry with every TLD delegated in a root zone file (see [`RootZone::parse`]).
+    ///
+    /// Each TLD gets up to 3 IPv4 glue addresses of its nameservers; everything else is
+    /// inherited from the compiled-in entry, if any. TLDs with neither glue nor a compiled-in
+    /// entry are left out, as there would be no server to ask.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the zone can't be parsed or delegates no TLDs.
+    pub fn from_root_zone(text: &str) -> Result<Self, RegistryError> {
+        Ok(Self::from_zone(&RootZone::parse(text)?))
+    }
+
+    /// Registry with every TLD delegated in a parsed root zone that has servers to ask.
+    pub fn from_zone(zone: &RootZone) -> Self {
+        let loaded = zone
+            .delegations
+            .keys()
+            .filter_map(|tld| {
+                let entry = EntryFile {
+                    servers: zone.ipv4_servers(tld),
+                    ..EntryFile::default()
+                };
+                let info = merge(tld, entry);
+                if info.servers.is_empty() {
+                    debug!("Leaving out .{}: no IPv4 glue and no compiled-in servers", tld);
+                    return None;
+                }
+                Some((tld.clone(), info))
+            })
+            .collect();
+        Self {
+            loaded: Arc::new(loaded),
+        }
+    }
+
+    /// Registry from a dotchk JSON dump: `{"tlds": {"example": {"servers": [...]}}}`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for invalid JSON, unknown fields, an invalid TLD or server address,
+    /// an entry without servers that has no compiled-in ones either, or a dump without TLDs.
+    pub fn from_json(text: &str) -> Result<Self, RegistryError> {
+        Self::from_file(serde_json::from_str(text)?)
+    }
+
+    /// Registry from a dotchk TOML dump, with one `[tlds.<name>]` table per TLD.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for invalid TOML, unknown fields, an invalid TLD or server address,
+    /// an entry without servers that has no compiled-in ones either, or a dump without TLDs.
+    pub fn from_toml(text: &str) -> Result<Self, RegistryError> {
+        Self::from_file(toml::from_str(text)?)
+    }
+
+    fn from_file(file: RegistryFile) -> Result<Self, RegistryError> {
+        if file.tlds.is_empty() {
+            return Err(RegistryError::Empty);
+        }
+
+        let mut loaded = HashMap::with_capacity(file.tlds.len());
+        for (name, entry) in file.tlds {
+            let invalid = |message: String| RegistryError::Entry {
+                tld: name.clone(),
+                message,
+            };
+            let tld = to_ascii(name.trim_end_matches('.')).ok_or_else(|| invalid("not a valid name".to_string()))?;
+            if let Some(server) = entry.servers.iter().find(|server| server.parse::<IpAddr>().is_err()) {
+                return Err(invalid(format!("'{server}' is not an IP address")));
+            }
+            if entry.timeout_ms == Some(0) {
+                return Err(invalid("timeout_ms must be at least 1".to_string()));
+            }
+            let info = merge(&tld, entry);
+            if info.servers.is_empty() {
+                return Err(invalid(
+                    "no servers, and no compiled-in entry to take them from".to_string(),
+                ));
+            }
+            loaded.insert(tld, info);
+        }
+        Ok(Self {
+            loaded: Arc::new(loaded),
+        })
+    }
+
+    /// Registry data for the TLD (or second-level suffix, like `co.uk`) of `domain`.
+    ///
+    /// Loaded entries win over compiled-in ones; a suffix without its own entry falls back
+    /// to its TLD's.
+    pub fn tld_info(&self, domain: &str) -> Option<&TldEntry> {
+        let domain = domain.to_lowercase();
+        if !domain.contains('.') {
+            return None;
+        }
+
+        let suffix = psl::public_suffix(&domain)?;
+        let tld = suffix.rsplit('.').next()?;
+        self.entry(suffix).or_else(|| self.entry(tld))
+    }
+
+    /// The entry for `name` exactly (`com`, `co.uk`), without falling back to its TLD.
+    pub fn entry(&self, name: &str) -> Option<&TldEntry> {
+        self.loaded.get(name).or_else(|| BUILTIN.get(name))
+    }
+
+    /// Label policy of the public suffix `suffix` (`com`, `com.au`): a loaded entry's, else
+    /// [`get_suffix_policy`]'s. Second-level suffixes never inherit their TLD's.
+    pub fn label_policy(&self, suffix: &str) -> Option<LabelPolicy> {
+        match self.loaded.get(suffix) {
+            Some(info) => Some(info.policy),
+            None => get_suffix_policy(suffix),
+        }
+    }
+
+    /// TLDs for `--all`: compiled-in and loaded ones not marked `excluded_from_all`.
+    pub fn public_tlds(&self) -> Vec<&str> {
+        let loaded = self.loaded.iter().map(|(tld, info)| (tld.as_str(), info.excluded_from_all));
+        let builtin = TLD_SERVERS
+            .entries()
+            .filter(|(tld, _)| !self.loaded.contains_key(**tld))
+            .map(|(tld, info)| (*tld, info.excluded_from_all));
+        let mut tlds: Vec<&str> = loaded
+            .chain(builtin)
+            .filter(|(tld, excluded_from_all)| !excluded_from_all && !tld.contains('.'))
+            .map(|(tld, _)| tld)
+            .collect();
+        tlds.sort_unstable();
+        tlds
+    }
+
+    /// Loaded TLDs the compiled-in table doesn't have, sorted.
+    pub fn added(&self) -> Vec<&str> {
+        let mut added: Vec<&str> = self
+            .loaded
+            .keys()
+            .filter(|tld| !TLD_SERVERS.contains_key(tld.as_str()))
+            .map(String::as_str)
+            .collect();
+        added.sort_unstable();
+        added
+    }
+
+    /// Loaded TLDs whose nameservers differ from the compiled-in table's, sorted.
+    pub fn changed(&self) -> Vec<&str> {
+        let mut changed: Vec<&str> = self
+            .loaded
+            .iter()
+            .filter(|(tld, info)| {
+                TLD_SERVERS.get(tld.as_str()).is_some_and(|builtin| {
+                    let mut old = builtin.servers.to_vec();
+                    let mut new: Vec<&str> = info.servers.iter().map(String::as_str).collect();
+                    old.sort_unstable();
+                    new.sort_unstable();
+                    old != new
+                })
+            })
+            .map(|(tld, _)| tld.as_str())
+            .collect();
+        changed.sort_unstable();
+        changed
+    }
+
+    /// Number of loaded entries.
+    pub fn len(&self) -> usize {
+        self.loaded.len()
+    }
+
+    /// Whether no entries were loaded, so only the compiled-in table is used.
+    pub fn is_empty(&self) -> bool {
+        self.loaded.is_empty()
+    }
+}
+
+/// `entry` over the compiled-in entry for `tld`.
+fn merge(tld: &str, entry: EntryFile) -> TldEntry {
+    let builtin = TLD_SERVERS.get(tld);
+    let servers = if entry.servers.is_empty() {
+        builtin.map(|info| owned(info.servers)).unwrap_or_default()
+    } else {
+        entry.servers
+    };
+    let categories = match entry.categories {
+        Some(categories) => categories,
+        None => builtin.map(|info| owned(info.categories)).unwrap_or_default(),
+    };
+    let mut policy = get_suffix_policy(tld).unwrap_or(LabelPolicy::DEFAULT);
+    if let Some(overrides) = entry.policy {
+        policy.min_length = overrides.min_length.unwrap_or(policy.min_length);
+        policy.max_length = overrides.max_length.unwrap_or(policy.max_length);
+        policy.numeric = overrides.numeric.unwrap_or(policy.numeric);
+        policy.idn = overrides.idn.unwrap_or(policy.idn);
+    }
+
+    TldEntry {
+        servers,
+        timeout_ms: entry
+            .timeout_ms
+            .or(builtin.map(|info| info.timeout_ms))
+            .unwrap_or(DEFAULT_TIMEOUT_MS),
+        excluded_from_all: entry
+            .excluded_from_all
+            .or(builtin.map(|info| info.excluded_from_all))
+            .unwrap_or(false),
+        categories,
+        whois: builtin.and_then(|info| info.whois),
+        rdap: builtin.and_then(|info| info.rdap),
+        policy,
+    }
+}
+
+fn owned(strings: &[&str]) -> Vec<String> {
+    strings.iter().map(|s| s.to_string()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ROOT_ZONE: &str = include_str!("../tests/fixtures/root.zone");
+
+    #[test]
+    fn parses_delegations_and_glue() {
+        let zone = RootZone::parse(ROOT_ZONE).unwrap();
+        assert_eq!(zone.delegations.keys().collect::<Vec<_>>(), ["com", "newtld", "noglue"]);
+        assert_eq!(zone.delegations["com"], ["a.gtld-servers.net", "b.gtld-servers.net"]);
+        assert_eq!(zone.glue["ns1.nic.newtld"].len(), 3);
+
+        // One address per host first, IPv4 only
+        assert_eq!(zone.ipv4_servers("newtld"), ["192.0.2.1", "198.51.100.1", "192.0.2.2"]);
+        assert!(zone.ipv4_servers("noglue").is_empty());
+    }
+
+    #[test]
+    fn rejects_broken_zones() {
+        assert!(matches!(
+            RootZone::parse("example.\t172800\tIN\tNS\n"),
+            Err(RegistryError::RootZone { line: 1, .. })
+        ));
+        assert!(matches!(
+            RootZone::parse("com.\tNS\tns.example.\nns.example.\tA\tnot-an-ip\n"),
+            Err(RegistryError::RootZone { line: 2, .. })
+        ));
+        assert!(matches!(RootZone::parse("; nothing\n"), Err(RegistryError::Empty)));
+    }
+
+    #[test]
+    fn root_zone_overrides_and_extends_builtin() {
+        let registry = Registry::from_root_zone(ROOT_ZONE).unwrap();
+        // noglue has neither glue nor a compiled-in entry, so nothing to ask
+        assert_eq!(registry.len(), 2);
+        assert!(registry.tld_info("brand.noglue").is_none());
+
+        let newtld = registry.tld_info("brand.newtld").unwrap();
+        assert_eq!(newtld.servers, ["192.0.2.1", "198.51.100.1", "192.0.2.2"]);
+        assert_eq!(newtld.timeout_ms, DEFAULT_TIMEOUT_MS);
+        assert!(crate::get_tld_info("brand.newtld").is_none());
+
+        // New glue replaces the servers; the rest is inherited
+        let com = registry.tld_info("example.com").unwrap();
+        let builtin = crate::get_tld_info("example.com").unwrap();
+        assert_eq!(com.servers, ["192.0.2.53"]);
+        assert_eq!(com.whois, builtin.whois);
+        assert_eq!(com.rdap, builtin.rdap);
+
+        // Untouched TLDs come from the compiled-in table
+        assert_eq!(
+            registry.tld_info("example.org").unwrap().servers,
+            crate::get_tld_info("example.org").unwrap().servers
+        );
+        assert!(registry.public_tlds().contains(&"newtld"));
+    }
+
+    #[test]
+    fn finds_hosts_missing_glue() {
+        let zone = RootZone::parse(ROOT_ZONE).unwrap();
+        // newtld and com have IPv4 glue; noglue's hosts have none, or only IPv6
+        assert_eq!(zone.missing_glue(), ["ns.elsewhere.com", "ns.v6only.newtld"]);
+    }
+
+    /// Answer every query with the A record 203.0.113.7 from a local UDP stand-in resolver;
+    /// returns its address.
+    async fn address_stand_in() -> String {
+        let socket = tokio::net::UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let address = socket.local_addr().unwrap().to_string();
+        tokio::spawn(async move {
+            let mut buffer = vec![0; 512];
+            while let Ok((_, from)) = socket.recv_from(&mut buffer).await {
+                // Header and question only, dropping the query's OPT record
+                let mut end = 12;
+                while buffer[end] != 0 {
+                    end += usize::from(buffer[end]) + 1;
+                }
+                let mut response = buffer[..end + 5].to_vec();
+                response[2] |= 0x80; // QR=1
+                response[7] = 1; // One answer
+                response[11] = 0; // No additional records
+                response.extend_from_slice(&[0xC0, 0x0C, 0, 1, 0, 1, 0, 0, 0x0E, 0x10, 0, 4, 203, 0, 113, 7]);
+                socket.send_to(&response, from).await.unwrap();
+            }
+        });
+        address
+    }
+
+    #[tokio::test]
+    async fn resolves_missing_glue_through_the_checkers_resolvers() {
+        let resolver = address_stand_in().await;
+        let checker = Checker::builder().resolvers(&[&resolver]).unwrap().build().await.unwrap();
+
+        let mut zone = RootZone::parse(ROOT_ZONE).unwrap();
+        assert_eq!(zone.resolve_missing_glue(&checker).await, 2);
+        assert_eq!(
+            zone.glue["ns.elsewhere.com"],
+            ["203.0.113.7".parse::<IpAddr>().unwrap()]
+        );
+        assert_eq!(zone.ipv4_servers("noglue"), ["203.0.113.7"]);
+        assert!(zone.missing_glue().is_empty());
+    }
+
+    #[test]
+    fn zone_dumps_round_trip() {
+        let mut zone = RootZone::parse(ROOT_ZONE).unwrap();
+        // Without servers for noglue the dump leaves it out, and still loads
+        let json = zone.to_json().unwrap();
+        assert!(!json.contains("noglue"));
+        assert_eq!(Registry::from_json(&json).unwrap().len(), 2);
+
+        zone.glue
+            .insert("ns.elsewhere.com".to_string(), vec!["203.0.113.7".parse().unwrap()]);
+
+        // Only servers are written, so the rest keeps following the compiled-in table
+        let json = zone.to_json().unwrap();
+        assert!(!json.contains("timeout_ms"));
+        for registry in [
+            Registry::from_json(&json).unwrap(),
+            Registry::from_toml(&zone.to_toml().unwrap()).unwrap(),
+        ] {
+            assert_eq!(registry.len(), 3);
+            assert_eq!(registry.tld_info("brand.noglue").unwrap().servers, ["203.0.113.7"]);
+            assert_eq!(
+                registry.tld_info("brand.newtld").unwrap().servers,
+                Registry::from_zone(&zone).tld_info("brand.newtld").unwrap().servers
+            );
+            assert_eq!(registry.added(), ["newtld", "noglue"]);
+            assert_eq!(registry.changed(), ["com"]);
+        }
+    }
+
+    #[test]
+    fn dumps_merge_fields_over_builtin() {
+        let toml = r#"
+            [tlds.eu]
+            servers = ["192.0.2.10"]
+            policy = { min_length = 3 }
+
+            [tlds."co.uk"]
+            servers = ["192.0.2.20"]
+
+            [tlds.uk]
+            policy = { min_length = 5 }
+        "#;
+        let registry = Registry::from_toml(toml).unwrap();
+        let eu = registry.tld_info("brand.eu").unwrap();
+        assert_eq!(eu.servers, ["192.0.2.10"]);
+        assert_eq!(eu.policy.min_length, 3);
+        assert_eq!(
+            eu.policy.max_length,
+            crate::get_tld_info("brand.eu").unwrap().policy.max_length
+        );
+        assert_eq!(registry.tld_info("brand.co.uk").unwrap().servers, ["192.0.2.20"]);
+        assert_ne!(registry.tld_info("brand.uk").unwrap().servers, ["192.0.2.20"]);
+
+        // Each suffix keeps its own policy
+        assert_eq!(registry.label_policy("uk").unwrap().min_length, 5);
+        assert_eq!(registry.label_policy("co.uk"), Some(LabelPolicy::DEFAULT));
+        assert_eq!(registry.label_policy("com.au"), crate::get_suffix_policy("com.au"));
+
+        let json = r#"{"tlds": {"newtld": {"servers": ["192.0.2.30"], "excluded_from_all": true}}}"#;
+        let registry = Registry::from_json(json).unwrap();
+        assert!(registry.tld_info("brand.newtld").unwrap().excluded_from_all);
+        assert!(!registry.public_tlds().contains(&"newtld"));
+    }
+
+    #[test]
+    fn dumps_reject_invalid_entries() {
+        assert!(matches!(
+            Registry::from_json(r#"{"tlds": {"x": {"servers": ["ns.example"]}}}"#),
+            Err(RegistryError::Entry { .. })
+        ));
+        assert!(matches!(
+            Registry::from_json(r#"{"tlds": {"x": {"whois": "whois.example"}}}"#),
+            Err(RegistryError::Json(_))
+        ));
+        assert!(matches!(Registry::from_toml("[tlds]\n"), Err(RegistryError::Empty)));
+        assert!(matches!(
+            Registry::from_json(r#"{"tlds": {"newtld": {"timeout_ms": 500}}}"#),
+            Err(RegistryError::Entry { .. })
+        ));
+    }
+}
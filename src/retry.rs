@@ -0,0 +1,197 @@
+//! Retry policy for queries to authoritative servers.
+//!
+//! Registry servers (especially some ccTLDs) drop the odd packet or answer SERVFAIL under
+//! load. Instead of moving on to the next server after one failure, the `Checker` retries
+//! the same server a few times with exponential backoff and jitter, for the error kinds
+//! the policy marks as retryable.
+
+use crate::DomainCheckerError;
+use crate::dns_pipelined::DnsError;
+use serde::{Deserialize, Serialize};
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+/// Kinds of query failure a [`RetryPolicy`] can retry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum RetryOn {
+    /// The server didn't answer in time.
+    Timeout,
+    /// The server answered SERVFAIL.
+    ServerFailure,
+    /// Sending or receiving failed locally (e.g. a transient network error).
+    Socket,
+    /// The answer was malformed.
+    InvalidResponse,
+    /// The query waited too long for a rate limit token.
+    RateLimited,
+}
+
+impl RetryOn {
+    fn matches(self, error: &DnsError) -> bool {
+        matches!(
+            (self, error),
+            (RetryOn::Timeout, DnsError::Timeout)
+                | (RetryOn::ServerFailure, DnsError::ServerFailure(_))
+                | (RetryOn::Socket, DnsError::Socket(_))
+                | (RetryOn::InvalidResponse, DnsError::InvalidResponse)
+                | (RetryOn::RateLimited, DnsError::RateLimited)
+        )
+    }
+}
+
+/// How often, and how patiently, each authoritative server is retried before moving on.
+///
+/// The delay before retry `n` (1-based) is `initial_backoff * 2^(n-1)`, capped at
+/// `max_backoff`, of which a random half is dropped (jitter) so retries from many
+/// concurrent checks don't arrive at the server in lockstep.
+///
+/// # Examples
+///
+/// ```rust
+/// use dotchk::{Checker, RetryOn, RetryPolicy};
+/// use std::time::Duration;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let policy = RetryPolicy::default()
+///     .attempts_per_server(3)
+///     .initial_backoff(Duration::from_millis(100))
+///     .retry_on(&[RetryOn::Timeout, RetryOn::ServerFailure, RetryOn::Socket]);
+///
+/// let checker = Checker::builder().retry_policy(policy)?.build().await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RetryPolicy {
+    attempts_per_server: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    retry_on: Vec<RetryOn>,
+}
+
+impl Default for RetryPolicy {
+    /// Two attempts per server, 50ms initial backoff up to 1s, retrying timeouts and SERVFAIL.
+    fn default() -> Self {
+        Self {
+            attempts_per_server: 2,
+            initial_backoff: Duration::from_millis(50),
+            max_backoff: Duration::from_secs(1),
+            retry_on: vec![RetryOn::Timeout, RetryOn::ServerFailure],
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy that sends exactly one query per server.
+    pub fn none() -> Self {
+        Self {
+            attempts_per_server: 1,
+            ..Self::default()
+        }
+    }
+
+    /// Total attempts per server, including the first query.
+    pub fn attempts_per_server(mut self, attempts: u32) -> Self {
+        self.attempts_per_server = attempts;
+        self
+    }
+
+    /// Delay before the first retry; doubles with every further retry.
+    pub fn initial_backoff(mut self, backoff: Duration) -> Self {
+        self.initial_backoff = backoff;
+        self
+    }
+
+    /// Upper bound for the delay between two attempts.
+    pub fn max_backoff(mut self, backoff: Duration) -> Self {
+        self.max_backoff = backoff;
+        self
+    }
+
+    /// Error kinds that are retried; anything else moves on to the next server right away.
+    pub fn retry_on(mut self, kinds: &[RetryOn]) -> Self {
+        self.retry_on = kinds.to_vec();
+        self
+    }
+
+    pub(crate) fn validate(&self) -> Result<(), DomainCheckerError> {
+        if self.attempts_per_server == 0 {
+            return Err(DomainCheckerError::Internal(
+                "retry attempts_per_server must be at least 1".to_string(),
+            ));
+        }
+        if self.attempts_per_server > 10 {
+            return Err(DomainCheckerError::Internal(
+                "retry attempts_per_server cannot exceed 10".to_string(),
+            ));
+        }
+        if self.max_backoff > Duration::from_secs(10) {
+            return Err(DomainCheckerError::Internal(
+                "retry max_backoff cannot exceed 10 seconds".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Whether `error` after attempt number `attempt` (1-based) should be retried on the same server.
+    pub(crate) fn should_retry(&self, error: &DnsError, attempt: u32) -> bool {
+        attempt < self.attempts_per_server && self.retry_on.iter().any(|kind| kind.matches(error))
+    }
+
+    /// Jittered delay before retry number `retry` (1-based).
+    pub(crate) fn backoff(&self, retry: u32) -> Duration {
+        let exponential = self
+            .initial_backoff
+            .saturating_mul(1 << retry.saturating_sub(1).min(16))
+            .min(self.max_backoff);
+        let half = exponential / 2;
+        half + half.mul_f64(random_fraction())
+    }
+}
+
+/// A random number in `[0, 1)`; good enough for jitter without pulling in an RNG crate.
+fn random_fraction() -> f64 {
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u64(0);
+    (hasher.finish() >> 11) as f64 / (1u64 << 53) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn retries_only_configured_errors_up_to_attempt_limit() {
+        let policy = RetryPolicy::default().attempts_per_server(3);
+        assert!(policy.should_retry(&DnsError::Timeout, 1));
+        assert!(policy.should_retry(&DnsError::ServerFailure(None), 2));
+        assert!(!policy.should_retry(&DnsError::Timeout, 3));
+        assert!(!policy.should_retry(&DnsError::FormatError, 1));
+        assert!(!RetryPolicy::none().should_retry(&DnsError::Timeout, 1));
+    }
+
+    #[test]
+    fn backoff_grows_exponentially_with_jitter_and_cap() {
+        let policy = RetryPolicy::default()
+            .initial_backoff(Duration::from_millis(100))
+            .max_backoff(Duration::from_millis(300));
+
+        for _ in 0..20 {
+            let first = policy.backoff(1);
+            assert!(first >= Duration::from_millis(50) && first <= Duration::from_millis(100));
+            let second = policy.backoff(2);
+            assert!(second >= Duration::from_millis(100) && second <= Duration::from_millis(200));
+            let capped = policy.backoff(10);
+            assert!(capped >= Duration::from_millis(150) && capped <= Duration::from_millis(300));
+        }
+    }
+
+    #[test]
+    fn validate_rejects_out_of_range_policies() {
+        assert!(RetryPolicy::default().validate().is_ok());
+        assert!(RetryPolicy::default().attempts_per_server(0).validate().is_err());
+        assert!(RetryPolicy::default().attempts_per_server(11).validate().is_err());
+        assert!(RetryPolicy::default().max_backoff(Duration::from_secs(11)).validate().is_err());
+    }
+}
@@ -1,11 +1,13 @@
 //! Top-level domain (TLD) type and utilities.
 
+use crate::psl;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::ops::Deref;
 
 /// A top-level domain (TLD) string.
 ///
+/// For second-level registries this is the whole public suffix, e.g. `co.uk`.
 /// TLDs are normalized to lowercase.
 ///
 /// # Examples
@@ -33,8 +35,9 @@ impl Tld {
 
     /// Extract the TLD from a domain string.
     ///
-    /// Returns the last component after splitting by '.'.
-    /// If the domain has no dots, returns the entire string.
+    /// Returns the domain's public suffix, which is more than the last label for
+    /// second-level registries like `co.uk`. If the domain has no dots, returns the
+    /// entire string.
     ///
     /// # Examples
     ///
@@ -45,10 +48,13 @@ impl Tld {
     /// assert_eq!(tld.as_str(), "com");
     ///
     /// let tld = Tld::extract_from("test.co.uk");
-    /// assert_eq!(tld.as_str(), "uk");
+    /// assert_eq!(tld.as_str(), "co.uk");
     /// ```
     pub fn extract_from(domain: &str) -> Self {
-        let tld = domain.split('.').next_back().unwrap_or(domain);
+        let domain = domain.to_lowercase();
+        let tld = psl::public_suffix(&domain)
+            .or_else(|| domain.split('.').next_back())
+            .unwrap_or(&domain);
         Self(tld.to_string())
     }
 
@@ -90,7 +96,8 @@ mod tests {
     #[test]
     fn tld_extract_from_works() {
         assert_eq!(Tld::extract_from("example.com").as_str(), "com");
-        assert_eq!(Tld::extract_from("test.co.uk").as_str(), "uk");
+        assert_eq!(Tld::extract_from("test.co.uk").as_str(), "co.uk");
+        assert_eq!(Tld::extract_from("www.brand.com.au").as_str(), "com.au");
         assert_eq!(Tld::extract_from("domain.xyz").as_str(), "xyz");
     }
 
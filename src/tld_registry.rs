@@ -11,6 +11,35 @@ pub struct TldInfo {
     pub timeout_ms: u64,
     pub excluded_from_all: bool,
     pub categories: &'static [&'static str],
+    /// Registry WHOIS server (port 43), if known; otherwise ask whois.iana.org for a referral.
+    pub whois: Option<&'static str>,
+    /// RDAP base URL from the IANA bootstrap file (ends with `/`), if the registry runs RDAP.
+    pub rdap: Option<&'static str>,
+    /// Which labels the registry accepts directly under this TLD.
+    pub policy: LabelPolicy,
+}
+
+/// Which second-level labels a registry accepts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LabelPolicy {
+    /// Shortest registrable label, in characters of its ASCII form.
+    pub min_length: u8,
+    /// Longest registrable label, in characters of its ASCII form.
+    pub max_length: u8,
+    /// Whether labels made only of digits are accepted.
+    pub numeric: bool,
+    /// Whether internationalized (`xn--`) labels are accepted.
+    pub idn: bool,
+}
+
+impl LabelPolicy {
+    /// What DNS itself allows: 1-63 characters, digits-only and IDN labels accepted.
+    pub const DEFAULT: LabelPolicy = LabelPolicy {
+        min_length: 1,
+        max_length: 63,
+        numeric: true,
+        idn: true,
+    };
 }
 
 pub static TLD_SERVERS: phf::Map<&'static str, TldInfo> = phf_map! {
@@ -19,8652 +48,13005 @@ pub static TLD_SERVERS: phf::Map<&'static str, TldInfo> = phf_map! {
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "aarp" => TldInfo {
         servers: &["37.209.194.9", "156.154.173.82", "156.154.174.82"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "abb" => TldInfo {
         servers: &["65.22.115.41", "65.22.114.41", "65.22.113.41"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "abbott" => TldInfo {
         servers: &["65.22.159.41", "65.22.156.41", "65.22.157.41"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "abbvie" => TldInfo {
         servers: &["156.154.100.3", "213.248.219.41", "103.49.83.41"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "abc" => TldInfo {
         servers: &["65.22.107.28", "161.232.30.28", "65.22.104.28"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "able" => TldInfo {
         servers: &["37.209.194.10", "37.209.196.10", "156.154.145.3"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "abogado" => TldInfo {
         servers: &["37.209.192.10", "156.154.173.82", "37.209.194.10"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "abudhabi" => TldInfo {
         servers: &["37.209.192.10", "37.209.196.10", "37.209.198.10"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ac" => TldInfo {
         servers: &["65.22.163.1", "65.22.161.1", "65.22.160.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "academy" => TldInfo {
         servers: &["65.22.24.37", "161.232.13.37", "161.232.12.37"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "accenture" => TldInfo {
         servers: &["161.232.33.8", "65.22.110.8", "65.22.108.8"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "accountant" => TldInfo {
         servers: &["156.154.144.195", "156.154.159.195", "37.209.192.10"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "accountants" => TldInfo {
         servers: &["65.22.32.31", "65.22.34.31", "161.232.17.31"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "aco" => TldInfo {
         servers: &["194.0.24.12", "194.0.25.32", "194.0.26.12"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "actor" => TldInfo {
         servers: &["65.22.33.60", "161.232.16.60", "65.22.35.60"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ad" => TldInfo {
         servers: &["192.134.0.49", "195.253.64.11", "195.253.65.11"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ads" => TldInfo {
         servers: &["216.239.36.105", "216.239.34.105", "216.239.60.105"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "adult" => TldInfo {
         servers: &["156.154.173.82", "156.154.174.82", "37.209.196.10"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["adult"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ae" => TldInfo {
         servers: &["202.12.31.53", "199.4.137.1", "79.98.120.73"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "aeg" => TldInfo {
         servers: &["161.232.33.2", "161.232.32.2", "65.22.108.2"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "aero" => TldInfo {
         servers: &["199.254.54.1", "199.249.115.1", "199.254.51.1"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "aetna" => TldInfo {
         servers: &["37.209.196.9", "37.209.192.9", "156.154.144.5"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "af" => TldInfo {
         servers: &["185.28.194.194", "204.61.216.13", "185.38.108.108"],
         timeout_ms: 750,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "afl" => TldInfo {
         servers: &["156.154.172.82", "156.154.174.82", "37.209.192.9"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "africa" => TldInfo {
         servers: &["194.58.198.33", "194.146.106.74"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ag" => TldInfo {
         servers: &["199.254.61.1", "199.249.116.1", "199.249.124.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "agakhan" => TldInfo {
         servers: &["65.22.46.1", "65.22.45.1", "65.22.47.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "agency" => TldInfo {
         servers: &["65.22.27.2", "65.22.24.2", "65.22.26.2"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ai" => TldInfo {
         servers: &["199.115.157.1", "199.115.156.1", "199.115.153.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: Some("whois.nic.ai"),
+        rdap: Some("https://rdap.identitydigital.services/rdap/"),
+        policy: LabelPolicy::DEFAULT,
     },
     "aig" => TldInfo {
         servers: &["156.154.145.6", "156.154.159.6", "37.209.192.9"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "airbus" => TldInfo {
         servers: &["65.22.110.10", "161.232.33.10", "65.22.111.10"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "airforce" => TldInfo {
         servers: &["65.22.35.34", "65.22.34.34", "65.22.32.34"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "airtel" => TldInfo {
         servers: &["192.42.174.30", "192.42.173.30", "192.42.176.30"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "akdn" => TldInfo {
         servers: &["65.22.45.17", "65.22.46.17", "65.22.44.17"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "al" => TldInfo {
         servers: &["194.0.11.108", "202.29.151.3", "147.28.0.39"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "alibaba" => TldInfo {
         servers: &["65.22.134.9", "65.22.132.9", "65.22.133.9"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "alipay" => TldInfo {
         servers: &["65.22.128.25", "65.22.130.25", "65.22.131.25"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "allfinanz" => TldInfo {
         servers: &["194.169.218.75", "212.18.248.75", "212.18.249.75"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "allstate" => TldInfo {
         servers: &["65.22.48.9", "65.22.49.9", "65.22.51.9"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ally" => TldInfo {
         servers: &["156.154.172.82", "37.209.192.9", "156.154.173.82"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "alsace" => TldInfo {
         servers: &["194.0.36.1", "194.0.9.1", "194.146.106.46"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "alstom" => TldInfo {
         servers: &["195.253.65.12", "195.253.64.11", "195.253.64.12"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "am" => TldInfo {
         servers: &["204.61.216.96", "195.43.75.53", "194.0.1.26"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "amazon" => TldInfo {
         servers: &["213.248.222.90", "156.154.103.3", "43.230.50.90"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "americanexpress" => TldInfo {
         servers: &["37.209.192.9", "37.209.194.9", "37.209.196.9"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "americanfamily" => TldInfo {
         servers: &["156.154.173.82", "37.209.196.9", "156.154.174.82"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "amex" => TldInfo {
         servers: &["37.209.196.9", "156.154.159.10", "156.154.145.10"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "amfam" => TldInfo {
         servers: &["37.209.192.9", "156.154.174.82", "156.154.173.82"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "amica" => TldInfo {
         servers: &["37.209.192.9", "156.154.159.11", "37.209.194.9"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "amsterdam" => TldInfo {
         servers: &["185.159.199.202", "194.0.28.4", "194.0.25.26"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "analytics" => TldInfo {
         servers: &["37.209.192.10", "156.154.159.12", "37.209.196.10"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "android" => TldInfo {
         servers: &["216.239.36.105", "216.239.60.105", "216.239.34.105"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "anquan" => TldInfo {
         servers: &["103.61.62.1", "103.61.63.1", "103.61.61.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "anz" => TldInfo {
         servers: &["37.209.194.9", "156.154.173.82", "37.209.192.9"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ao" => TldInfo {
         servers: &["206.51.254.4", "185.39.208.29", "185.43.134.13"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "aol" => TldInfo {
         servers: &["65.22.113.42", "65.22.112.42", "65.22.115.42"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "apartments" => TldInfo {
         servers: &["65.22.28.56", "65.22.29.56", "65.22.30.56"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "app" => TldInfo {
         servers: &["216.239.32.105", "216.239.38.105", "216.239.36.105"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: Some("whois.nic.google"),
+        rdap: Some("https://pubapi.registry.google/rdap/"),
+        policy: LabelPolicy::DEFAULT,
     },
     "apple" => TldInfo {
         servers: &["65.22.61.1", "65.22.62.1", "65.22.63.1"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "aq" => TldInfo {
         servers: &["204.61.216.132", "202.46.190.131", "77.72.229.254"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "aquarelle" => TldInfo {
         servers: &["212.18.249.160", "194.169.218.160", "185.24.64.160"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ar" => TldInfo {
         servers: &["200.108.148.50", "192.140.126.50", "170.238.66.50"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "arab" => TldInfo {
         servers: &["37.209.192.10", "37.209.194.10", "37.209.196.10"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "aramco" => TldInfo {
         servers: &["156.154.156.14", "156.154.157.14", "37.209.196.9"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "archi" => TldInfo {
         servers: &["65.22.84.1", "65.22.85.1", "65.22.87.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "army" => TldInfo {
         servers: &["161.232.15.59", "65.22.31.59", "65.22.30.59"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "arpa" => TldInfo {
         servers: &["170.247.170.2", "193.0.14.129", "192.203.230.10"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "art" => TldInfo {
         servers: &["212.18.249.49", "185.24.64.49", "194.169.218.49"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "arte" => TldInfo {
         servers: &["65.22.108.11", "65.22.109.11", "161.232.33.11"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "as" => TldInfo {
         servers: &["213.248.223.254", "194.146.106.86", "204.61.216.111"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "asda" => TldInfo {
         servers: &["65.22.115.43", "65.22.114.43", "65.22.113.43"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "asia" => TldInfo {
         servers: &["199.254.29.1", "199.254.30.1", "199.249.114.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "associates" => TldInfo {
         servers: &["65.22.31.27", "65.22.28.27", "161.232.14.27"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "at" => TldInfo {
         servers: &["81.91.173.130", "81.91.161.98", "78.104.144.2"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: Some("whois.nic.at"),
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "athleta" => TldInfo {
         servers: &["37.209.194.9", "37.209.192.9", "156.154.145.16"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "attorney" => TldInfo {
         servers: &["65.22.22.20", "65.22.20.20", "65.22.21.20"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "au" => TldInfo {
         servers: &["65.22.197.1", "65.22.199.1", "58.65.254.1"],
         timeout_ms: 750,
         excluded_from_all: false,
         categories: &[],
+        whois: Some("whois.auda.org.au"),
+        rdap: None,
+        policy: LabelPolicy { idn: false, min_length: 2, ..LabelPolicy::DEFAULT },
     },
     "auction" => TldInfo {
         servers: &["65.22.35.55", "161.232.16.55", "65.22.34.55"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "audi" => TldInfo {
         servers: &["65.22.210.17", "65.22.211.17", "65.22.208.17"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "audible" => TldInfo {
         servers: &["156.154.101.3", "43.230.50.56", "103.49.82.56"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "audio" => TldInfo {
         servers: &["212.18.248.150", "212.18.249.150", "194.169.218.150"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "auspost" => TldInfo {
         servers: &["156.154.172.82", "156.154.173.82", "37.209.196.9"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "author" => TldInfo {
         servers: &["156.154.102.3", "43.230.50.60", "103.49.82.60"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "auto" => TldInfo {
         servers: &["212.18.249.131", "185.24.64.131", "194.169.218.131"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "autos" => TldInfo {
         servers: &["212.18.249.136", "212.18.248.136", "194.169.218.136"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "aw" => TldInfo {
         servers: &["185.159.199.201", "201.229.0.26", "194.0.28.2"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "aws" => TldInfo {
         servers: &["156.154.100.3", "156.154.103.3", "103.49.82.53"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ax" => TldInfo {
         servers: &["82.199.186.130", "82.199.184.194", "194.112.0.5"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "axa" => TldInfo {
         servers: &["156.154.144.20", "37.209.194.9", "37.209.196.9"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "az" => TldInfo {
         servers: &["147.28.0.39", "148.251.2.246", "195.47.253.13"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "azure" => TldInfo {
         servers: &["156.154.101.3", "213.248.219.129", "156.154.100.3"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ba" => TldInfo {
         servers: &["195.130.35.3", "204.61.216.117"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "baby" => TldInfo {
         servers: &["212.18.248.101", "194.169.218.101", "185.24.64.101"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "baidu" => TldInfo {
         servers: &["116.169.54.111", "223.72.199.37", "203.99.27.1"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "banamex" => TldInfo {
         servers: &["156.154.145.21", "37.209.192.9", "37.209.196.9"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "band" => TldInfo {
         servers: &["161.232.14.45", "65.22.28.45", "65.22.30.45"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "bank" => TldInfo {
         servers: &["156.154.104.27", "156.154.103.27", "37.209.192.10"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "bar" => TldInfo {
         servers: &["64.96.2.1", "185.159.197.3", "64.96.1.1"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["controlled"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "barcelona" => TldInfo {
         servers: &["195.253.65.11", "195.253.65.12", "195.253.64.11"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "barclaycard" => TldInfo {
         servers: &["65.22.123.9", "65.22.120.9", "65.22.122.9"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "barclays" => TldInfo {
         servers: &["65.22.120.1", "65.22.121.1", "65.22.123.1"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "barefoot" => TldInfo {
         servers: &["65.22.58.17", "65.22.59.17", "65.22.56.17"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "bargains" => TldInfo {
         servers: &["65.22.22.62", "65.22.23.62", "65.22.21.62"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "baseball" => TldInfo {
         servers: &["37.209.196.10", "37.209.192.10", "37.209.194.10"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "basketball" => TldInfo {
         servers: &["37.209.192.10", "156.154.173.82", "156.154.174.82"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "bauhaus" => TldInfo {
         servers: &["195.253.64.11", "195.253.65.11", "195.253.64.12"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "bayern" => TldInfo {
         servers: &["37.209.196.2", "195.253.64.11", "37.209.192.2"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "bb" => TldInfo {
         servers: &["64.68.193.10", "64.68.192.10", "64.119.198.193"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "bbc" => TldInfo {
         servers: &["213.248.223.4", "156.154.100.3", "156.154.103.3"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "bbt" => TldInfo {
         servers: &["65.22.112.44", "65.22.114.44", "65.22.115.44"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "bbva" => TldInfo {
         servers: &["156.154.102.3", "156.154.103.3", "213.248.219.44"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "bcg" => TldInfo {
         servers: &["65.22.217.25", "65.22.218.25", "65.22.216.25"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "bcn" => TldInfo {
         servers: &["195.253.65.11", "195.253.64.12", "195.253.64.11"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "bd" => TldInfo {
         servers: &["203.112.194.232", "204.61.216.108", "123.49.12.112"],
         timeout_ms: 750,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "be" => TldInfo {
         servers: &["194.0.25.36", "120.29.253.8", "194.0.37.1"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: Some("whois.dns.be"),
+        rdap: None,
+        policy: LabelPolicy { min_length: 2, ..LabelPolicy::DEFAULT },
     },
     "beats" => TldInfo {
         servers: &["65.22.62.9", "65.22.61.9", "65.22.60.9"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "beauty" => TldInfo {
         servers: &["185.24.64.119", "194.169.218.119", "212.18.248.119"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "beer" => TldInfo {
         servers: &["37.209.196.10", "156.154.172.82", "37.209.192.10"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["controlled"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "berlin" => TldInfo {
         servers: &["194.0.25.16", "194.0.24.5", "194.0.26.5"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "best" => TldInfo {
         servers: &["212.18.249.109", "185.24.64.109", "194.169.218.109"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "bestbuy" => TldInfo {
         servers: &["65.22.219.9", "65.22.216.9", "65.22.217.9"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "bet" => TldInfo {
         servers: &["65.22.37.17", "65.22.39.17", "65.22.36.17"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["gambling"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "bf" => TldInfo {
         servers: &["212.52.146.50", "194.0.9.1", "13.39.116.127"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "bg" => TldInfo {
         servers: &["193.68.3.232", "204.61.216.110", "185.143.80.1"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "bh" => TldInfo {
         servers: &["194.169.218.115", "212.18.248.115", "212.18.249.115"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "bharti" => TldInfo {
         servers: &["192.42.173.30", "192.42.175.30", "192.42.174.30"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "bi" => TldInfo {
         servers: &["196.216.168.23", "196.2.12.205", "196.2.8.205"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "bible" => TldInfo {
         servers: &["37.209.194.10", "156.154.171.100", "37.209.196.10"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["religious"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "bid" => TldInfo {
         servers: &["37.209.196.10", "37.209.194.10", "156.154.145.25"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "bike" => TldInfo {
         servers: &["65.22.25.44", "65.22.26.44", "65.22.24.44"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "bing" => TldInfo {
         servers: &["213.248.223.130", "156.154.103.3", "156.154.100.3"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "bingo" => TldInfo {
         servers: &["161.232.16.62", "65.22.34.62", "65.22.33.62"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["gambling"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "bio" => TldInfo {
         servers: &["65.22.84.9", "65.22.86.9", "65.22.85.9"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "biz" => TldInfo {
         servers: &["37.209.192.13", "204.74.107.1", "156.154.125.65"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: Some("whois.nic.biz"),
+        rdap: Some("https://rdap.nic.biz/"),
+        policy: LabelPolicy::DEFAULT,
     },
     "bj" => TldInfo {
         servers: &["196.216.168.33", "204.61.216.125", "194.0.9.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "black" => TldInfo {
         servers: &["65.22.70.33", "65.22.71.33", "65.22.69.33"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "blackfriday" => TldInfo {
         servers: &["156.154.173.82", "156.154.172.82", "37.209.192.10"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "blockbuster" => TldInfo {
         servers: &["185.159.197.3", "64.96.2.1", "185.159.198.3"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "blog" => TldInfo {
         servers: &["185.159.197.9", "185.159.198.9", "185.159.199.9"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "bloomberg" => TldInfo {
         servers: &["65.22.111.3", "65.22.110.3", "65.22.108.3"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "blue" => TldInfo {
         servers: &["65.22.29.9", "65.22.31.13", "65.22.30.9"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "bm" => TldInfo {
         servers: &["199.254.60.9", "199.254.61.9", "199.249.116.9"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "bms" => TldInfo {
         servers: &["65.22.112.45", "65.22.115.45", "65.22.113.45"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "bmw" => TldInfo {
         servers: &["212.18.248.76", "194.169.218.76", "185.24.64.76"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "bn" => TldInfo {
         servers: &["202.93.214.163", "202.12.31.53", "194.0.1.33"],
         timeout_ms: 750,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "bnpparibas" => TldInfo {
         servers: &["65.22.67.9", "65.22.64.9", "65.22.65.9"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "bo" => TldInfo {
         servers: &["192.93.0.4", "204.61.216.48", "166.114.1.40"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "boats" => TldInfo {
         servers: &["212.18.249.134", "185.24.64.134", "212.18.248.134"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "boehringer" => TldInfo {
         servers: &["65.22.190.17", "65.22.191.17", "65.22.189.17"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "bofa" => TldInfo {
         servers: &["65.22.110.4", "65.22.109.4", "65.22.111.4"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "bom" => TldInfo {
         servers: &["200.229.248.10", "200.192.233.10", "200.219.148.10"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "bond" => TldInfo {
         servers: &["185.24.64.85", "212.18.249.85", "212.18.248.85"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "boo" => TldInfo {
         servers: &["216.239.38.105", "216.239.32.105", "216.239.60.105"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "book" => TldInfo {
         servers: &["103.49.82.61", "156.154.101.3", "213.248.222.61"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "booking" => TldInfo {
         servers: &["37.209.196.9", "156.154.144.27", "156.154.159.27"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "bosch" => TldInfo {
         servers: &["65.22.112.46", "65.22.113.46", "65.22.115.46"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "bostik" => TldInfo {
         servers: &["194.146.106.46", "194.0.9.1", "194.0.36.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "boston" => TldInfo {
         servers: &["156.154.174.82", "156.154.172.82", "37.209.196.10"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "bot" => TldInfo {
         servers: &["213.248.222.55", "156.154.101.3", "103.49.82.55"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "boutique" => TldInfo {
         servers: &["65.22.27.12", "161.232.13.12", "65.22.26.12"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "box" => TldInfo {
         servers: &["212.18.249.139", "185.24.64.139", "212.18.248.139"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "br" => TldInfo {
         servers: &["200.189.41.10", "200.219.148.10", "200.219.159.10"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: Some("whois.registro.br"),
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "bradesco" => TldInfo {
         servers: &["65.22.126.2", "65.22.125.2", "65.22.127.2"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "bridgestone" => TldInfo {
         servers: &["37.209.198.4", "37.209.192.4", "37.209.196.4"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "broadway" => TldInfo {
         servers: &["156.154.103.3", "156.154.100.3", "156.154.101.3"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "broker" => TldInfo {
         servers: &["65.22.21.67", "65.22.23.67", "161.232.10.67"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "brother" => TldInfo {
         servers: &["37.209.198.4", "37.209.194.4", "37.209.192.4"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "brussels" => TldInfo {
         servers: &["120.29.253.8", "194.0.6.1", "194.0.37.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "bs" => TldInfo {
         servers: &["194.0.1.36", "204.61.216.68", "204.61.216.4"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "bt" => TldInfo {
         servers: &["204.61.216.17", "198.6.1.65", "202.144.128.200"],
         timeout_ms: 750,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "build" => TldInfo {
         servers: &["194.169.218.20", "212.18.249.20", "185.24.64.20"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "builders" => TldInfo {
         servers: &["65.22.30.24", "65.22.28.24", "161.232.14.24"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "business" => TldInfo {
         servers: &["65.22.33.38", "65.22.34.38", "65.22.32.38"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "buy" => TldInfo {
         servers: &["156.154.101.3", "156.154.102.3", "43.230.50.62"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "buzz" => TldInfo {
         servers: &["156.154.145.29", "37.209.192.10", "156.154.159.29"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "bv" => TldInfo {
         servers: &["128.39.8.40", "193.10.252.19", "158.38.8.133"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "bw" => TldInfo {
         servers: &["168.167.168.37", "204.61.216.70", "168.167.98.226"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "by" => TldInfo {
         servers: &["31.44.5.245", "93.125.25.72", "93.125.25.73"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "bz" => TldInfo {
         servers: &["199.249.116.1", "199.254.60.1", "199.254.59.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "bzh" => TldInfo {
         servers: &["194.0.9.1", "194.146.106.46", "194.0.36.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ca" => TldInfo {
         servers: &["45.142.220.101", "185.159.196.2", "198.182.167.1"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: Some("whois.cira.ca"),
+        rdap: None,
+        policy: LabelPolicy { min_length: 2, ..LabelPolicy::DEFAULT },
     },
     "cab" => TldInfo {
         servers: &["161.232.15.29", "65.22.28.29", "65.22.31.29"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "cafe" => TldInfo {
         servers: &["65.22.23.44", "65.22.20.44", "161.232.11.44"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "cal" => TldInfo {
         servers: &["216.239.34.105", "216.239.36.105", "216.239.60.105"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "call" => TldInfo {
         servers: &["43.230.50.63", "103.49.82.63", "156.154.100.3"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "calvinklein" => TldInfo {
         servers: &["156.154.145.31", "156.154.144.31", "37.209.192.9"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "cam" => TldInfo {
         servers: &["212.18.248.77", "212.18.249.77", "185.24.64.77"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["adult"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "camera" => TldInfo {
         servers: &["161.232.12.26", "65.22.26.26", "65.22.27.26"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "camp" => TldInfo {
         servers: &["161.232.14.51", "65.22.30.51", "65.22.31.51"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "canon" => TldInfo {
         servers: &["37.209.192.4", "37.209.194.4", "37.209.196.4"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "capetown" => TldInfo {
         servers: &["194.58.198.33", "194.146.106.74"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "capital" => TldInfo {
         servers: &["65.22.30.10", "65.22.29.10", "161.232.14.10"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "capitalone" => TldInfo {
         servers: &["192.42.175.30", "192.42.173.30", "192.42.174.30"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "car" => TldInfo {
         servers: &["212.18.249.130", "194.169.218.130", "185.24.64.130"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "caravan" => TldInfo {
         servers: &["156.154.145.32", "37.209.192.9", "156.154.159.32"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "cards" => TldInfo {
         servers: &["65.22.35.50", "65.22.34.50", "161.232.17.50"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "care" => TldInfo {
         servers: &["65.22.33.5", "65.22.32.5", "65.22.34.5"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "career" => TldInfo {
         servers: &["103.49.83.121", "156.154.102.3", "156.154.100.3"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "careers" => TldInfo {
         servers: &["65.22.24.48", "161.232.12.48", "65.22.26.48"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "cars" => TldInfo {
         servers: &["212.18.249.129", "212.18.248.129", "185.24.64.129"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "casa" => TldInfo {
         servers: &["37.209.194.10", "37.209.196.10", "37.209.192.10"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "case" => TldInfo {
         servers: &["212.18.249.140", "212.18.248.140", "185.24.64.140"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "cash" => TldInfo {
         servers: &["65.22.35.44", "65.22.32.44", "161.232.16.44"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "casino" => TldInfo {
         servers: &["65.22.22.18", "65.22.20.18", "65.22.23.18"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["gambling"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "cat" => TldInfo {
         servers: &["194.0.11.110", "194.69.254.15", "195.253.64.4"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "catering" => TldInfo {
         servers: &["65.22.33.24", "161.232.17.24", "65.22.34.24"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "catholic" => TldInfo {
         servers: &["37.209.194.9", "37.209.192.9", "156.154.174.82"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["religious"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "cba" => TldInfo {
         servers: &["156.154.174.82", "37.209.192.9", "37.209.196.9"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "cbn" => TldInfo {
         servers: &["37.209.192.9", "156.154.144.35", "37.209.194.9"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "cbre" => TldInfo {
         servers: &["156.154.159.36", "37.209.192.9", "37.209.194.9"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "cc" => TldInfo {
         servers: &["192.42.173.30", "192.42.176.30", "192.42.174.30"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: Some("ccwhois.verisign-grs.com"),
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "cd" => TldInfo {
         servers: &["102.68.62.15", "102.68.60.15", "161.97.87.130"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "center" => TldInfo {
         servers: &["161.232.14.4", "65.22.29.4", "161.232.15.4"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ceo" => TldInfo {
         servers: &["194.169.218.78", "212.18.249.78", "212.18.248.78"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "cern" => TldInfo {
         servers: &["65.22.225.1", "65.22.226.1", "65.22.224.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "cf" => TldInfo {
         servers: &["185.21.171.17", "185.21.168.17", "185.21.170.17"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "cfa" => TldInfo {
         servers: &["65.22.105.27", "161.232.30.27", "65.22.104.27"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "cfd" => TldInfo {
         servers: &["185.24.64.122", "212.18.248.122", "212.18.249.122"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "cg" => TldInfo {
         servers: &["54.39.22.36", "151.80.35.161", "192.36.125.2"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ch" => TldInfo {
         servers: &["194.146.106.10", "194.0.25.39", "130.59.31.41"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: Some("whois.nic.ch"),
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "chanel" => TldInfo {
         servers: &["65.22.114.47", "65.22.112.47", "65.22.113.47"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "channel" => TldInfo {
         servers: &["216.239.32.105", "216.239.38.105", "216.239.34.105"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "charity" => TldInfo {
         servers: &["65.22.22.61", "65.22.20.61", "161.232.11.61"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "chase" => TldInfo {
         servers: &["37.209.192.9", "156.154.158.39", "37.209.194.9"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "chat" => TldInfo {
         servers: &["65.22.34.42", "65.22.32.42", "161.232.17.42"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "cheap" => TldInfo {
         servers: &["65.22.23.26", "161.232.11.26", "65.22.22.26"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "chintai" => TldInfo {
         servers: &["37.209.192.9", "156.154.145.40", "156.154.159.40"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "christmas" => TldInfo {
         servers: &["194.169.218.153", "185.24.64.153", "212.18.248.153"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "chrome" => TldInfo {
         servers: &["216.239.38.105", "216.239.36.105", "216.239.32.105"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "church" => TldInfo {
         servers: &["161.232.12.39", "65.22.26.39", "65.22.24.39"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["religious"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ci" => TldInfo {
         servers: &["196.49.0.84", "192.134.0.49", "196.216.168.30"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "cipriani" => TldInfo {
         servers: &["65.22.236.41", "65.22.237.41", "65.22.239.41"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "circle" => TldInfo {
         servers: &["156.154.103.3", "156.154.100.3", "103.49.82.64"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "cisco" => TldInfo {
         servers: &["37.209.194.9", "156.154.159.44", "37.209.192.9"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "citadel" => TldInfo {
         servers: &["65.22.114.33", "65.22.113.33", "65.22.112.33"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "citi" => TldInfo {
         servers: &["156.154.145.213", "156.154.144.213", "156.154.159.213"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "citic" => TldInfo {
         servers: &["203.99.25.1", "203.99.26.1", "116.169.54.111"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "city" => TldInfo {
         servers: &["65.22.26.4", "161.232.12.4", "161.232.13.4"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ck" => TldInfo {
         servers: &["130.195.5.12", "202.65.32.127", "202.65.32.128"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "cl" => TldInfo {
         servers: &["185.159.198.56", "204.61.216.30", "190.124.27.10"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "claims" => TldInfo {
         servers: &["65.22.20.60", "65.22.23.60", "65.22.22.60"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "cleaning" => TldInfo {
         servers: &["65.22.20.27", "65.22.21.27", "65.22.22.27"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "click" => TldInfo {
         servers: &["185.159.197.3", "64.96.2.1", "185.159.198.3"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "clinic" => TldInfo {
         servers: &["161.232.16.47", "65.22.32.47", "65.22.35.47"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "clinique" => TldInfo {
         servers: &["65.22.53.41", "65.22.55.41", "65.22.52.41"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "clothing" => TldInfo {
         servers: &["65.22.24.13", "65.22.25.13", "161.232.13.13"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "cloud" => TldInfo {
         servers: &["64.96.1.1", "185.159.198.3", "64.96.2.1"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "club" => TldInfo {
         servers: &["156.154.145.215", "37.209.196.10", "37.209.192.10"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "clubmed" => TldInfo {
         servers: &["161.232.33.12", "65.22.111.12", "65.22.108.12"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "cm" => TldInfo {
         servers: &["195.24.205.60", "128.223.32.35", "156.106.192.121"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "cn" => TldInfo {
         servers: &["202.112.0.44", "203.119.25.1", "203.119.26.1"],
         timeout_ms: 750,
         excluded_from_all: false,
         categories: &[],
+        whois: Some("whois.cnnic.cn"),
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "co" => TldInfo {
         servers: &["204.74.105.1", "156.154.104.25", "37.209.196.14"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: Some("whois.registry.co"),
+        rdap: Some("https://rdap.registry.co/co/"),
+        policy: LabelPolicy::DEFAULT,
     },
     "coach" => TldInfo {
         servers: &["65.22.34.41", "65.22.35.41", "65.22.33.41"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "codes" => TldInfo {
         servers: &["161.232.16.43", "65.22.34.43", "65.22.33.43"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "coffee" => TldInfo {
         servers: &["161.232.14.41", "65.22.31.41", "65.22.29.41"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "college" => TldInfo {
         servers: &["212.18.248.44", "194.169.218.44", "212.18.249.44"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "cologne" => TldInfo {
         servers: &["194.0.11.114", "157.90.205.138", "193.56.204.111"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "com" => TldInfo {
         servers: &["192.26.92.30", "192.54.112.30", "192.55.83.30"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: Some("whois.verisign-grs.com"),
+        rdap: Some("https://rdap.verisign.com/com/v1/"),
+        policy: LabelPolicy::DEFAULT,
     },
     "commbank" => TldInfo {
         servers: &["37.209.196.9", "156.154.172.82", "156.154.174.82"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "community" => TldInfo {
         servers: &["65.22.25.10", "161.232.12.10", "65.22.27.10"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "company" => TldInfo {
         servers: &["65.22.27.36", "65.22.25.36", "161.232.12.36"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "compare" => TldInfo {
         servers: &["156.154.173.82", "37.209.192.9", "156.154.172.82"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "computer" => TldInfo {
         servers: &["65.22.30.19", "65.22.28.19", "65.22.29.19"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "comsec" => TldInfo {
         servers: &["192.42.174.30", "192.42.173.30", "192.42.175.30"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "condos" => TldInfo {
         servers: &["65.22.23.63", "65.22.22.63", "65.22.20.63"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "construction" => TldInfo {
         servers: &["65.22.23.50", "65.22.21.50", "65.22.20.50"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "consulting" => TldInfo {
         servers: &["65.22.25.65", "161.232.12.65", "65.22.24.65"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "contact" => TldInfo {
         servers: &["65.22.27.34", "65.22.25.34", "161.232.13.34"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "contractors" => TldInfo {
         servers: &["161.232.13.56", "161.232.12.56", "65.22.25.56"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "cooking" => TldInfo {
         servers: &["37.209.194.10", "156.154.172.82", "156.154.174.82"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "cool" => TldInfo {
         servers: &["161.232.12.38", "65.22.26.38", "65.22.27.38"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "coop" => TldInfo {
         servers: &["64.96.1.1", "64.96.2.1", "185.159.197.3"],
         timeout_ms: 500,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "corsica" => TldInfo {
         servers: &["194.0.36.1", "194.146.106.46", "194.0.9.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "country" => TldInfo {
         servers: &["185.159.197.3", "64.96.1.1", "185.159.198.3"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "coupon" => TldInfo {
         servers: &["161.232.38.1", "65.22.78.1", "161.232.39.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "coupons" => TldInfo {
         servers: &["65.22.24.29", "65.22.27.29", "161.232.12.29"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "courses" => TldInfo {
         servers: &["37.209.196.9", "37.209.192.9", "37.209.194.9"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "cpa" => TldInfo {
         servers: &["156.154.172.82", "37.209.192.9", "156.154.174.82"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "cr" => TldInfo {
         servers: &["185.159.198.100", "185.159.197.100", "200.107.82.100"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "credit" => TldInfo {
         servers: &["65.22.35.54", "65.22.32.54", "161.232.16.54"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "creditcard" => TldInfo {
         servers: &["161.232.11.65", "65.22.20.65", "65.22.22.65"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "creditunion" => TldInfo {
         servers: &["185.159.198.3", "64.96.2.1", "64.96.1.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "cricket" => TldInfo {
         servers: &["156.154.145.220", "37.209.196.10", "156.154.159.220"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "crown" => TldInfo {
         servers: &["185.159.198.5", "45.142.220.101", "185.159.197.5"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "crs" => TldInfo {
         servers: &["65.22.113.25", "65.22.112.25", "65.22.114.25"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "cruise" => TldInfo {
         servers: &["65.22.195.25", "65.22.194.25", "65.22.192.25"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "cruises" => TldInfo {
         servers: &["65.22.26.30", "161.232.12.30", "65.22.24.30"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "cu" => TldInfo {
         servers: &["200.160.0.5", "193.0.9.70", "169.158.128.88"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "cuisinella" => TldInfo {
         servers: &["37.209.196.9", "156.154.172.82", "156.154.173.82"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "cv" => TldInfo {
         servers: &["185.39.208.18", "204.61.216.105", "194.58.198.135"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "cw" => TldInfo {
         servers: &["193.0.9.86", "65.208.122.63", "128.86.1.20"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "cx" => TldInfo {
         servers: &["185.38.108.108", "185.28.194.194", "204.61.216.16"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "cy" => TldInfo {
         servers: &["204.61.216.44", "194.42.29.16", "139.91.191.3"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "cymru" => TldInfo {
         servers: &["103.49.83.3", "156.154.103.3", "43.230.51.3"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "cyou" => TldInfo {
         servers: &["194.169.218.120", "212.18.249.120", "185.24.64.120"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "cz" => TldInfo {
         servers: &["194.0.13.1", "194.0.12.1", "194.0.14.1"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "dad" => TldInfo {
         servers: &["216.239.60.105", "216.239.36.105", "216.239.38.105"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "dance" => TldInfo {
         servers: &["161.232.13.50", "65.22.24.50", "65.22.27.50"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "data" => TldInfo {
         servers: &["185.159.197.3", "185.159.198.3", "64.96.2.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "date" => TldInfo {
         servers: &["37.209.196.10", "156.154.144.46", "156.154.145.46"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "dating" => TldInfo {
         servers: &["65.22.31.28", "65.22.30.28", "161.232.15.28"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["adult"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "datsun" => TldInfo {
         servers: &["37.209.192.4", "37.209.196.4", "37.209.194.4"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "day" => TldInfo {
         servers: &["216.239.60.105", "216.239.36.105", "216.239.32.105"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "dclk" => TldInfo {
         servers: &["216.239.36.105", "216.239.38.105", "216.239.34.105"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "dds" => TldInfo {
         servers: &["37.209.194.10", "37.209.196.10", "37.209.192.10"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "de" => TldInfo {
         servers: &["195.243.137.26", "77.67.63.105", "81.91.164.5"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: Some("whois.denic.de"),
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "deal" => TldInfo {
         servers: &["103.49.82.65", "213.248.222.65", "156.154.103.3"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "dealer" => TldInfo {
         servers: &["212.18.249.138", "185.24.64.138", "212.18.248.138"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "deals" => TldInfo {
         servers: &["161.232.11.15", "161.232.10.15", "65.22.23.15"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "degree" => TldInfo {
         servers: &["65.22.25.61", "161.232.12.61", "65.22.27.61"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "delivery" => TldInfo {
         servers: &["65.22.28.48", "65.22.31.48", "65.22.30.48"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "dell" => TldInfo {
         servers: &["156.154.144.47", "37.209.194.9", "37.209.196.9"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "deloitte" => TldInfo {
         servers: &["194.169.218.80", "185.24.64.80", "212.18.249.80"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "delta" => TldInfo {
         servers: &["65.22.224.25", "65.22.225.25", "65.22.226.25"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "democrat" => TldInfo {
         servers: &["65.22.33.32", "161.232.16.32", "161.232.17.32"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "dental" => TldInfo {
         servers: &["65.22.24.49", "161.232.12.49", "65.22.26.49"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "dentist" => TldInfo {
         servers: &["161.232.13.27", "161.232.12.27", "65.22.26.27"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "desi" => TldInfo {
         servers: &["213.248.221.153", "156.154.102.3", "43.230.49.153"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "design" => TldInfo {
         servers: &["37.209.192.10", "37.209.196.10", "156.154.173.82"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "dev" => TldInfo {
         servers: &["216.239.60.105", "216.239.32.105", "216.239.38.105"],
         timeout_ms: 500,
         excluded_from_all: true,
         categories: &["private"],
+        whois: Some("whois.nic.google"),
+        rdap: Some("https://pubapi.registry.google/rdap/"),
+        policy: LabelPolicy::DEFAULT,
     },
     "dhl" => TldInfo {
         servers: &["212.18.248.82", "194.169.218.82", "185.24.64.82"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "diamonds" => TldInfo {
         servers: &["161.232.13.60", "65.22.26.60", "65.22.24.60"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "diet" => TldInfo {
         servers: &["194.169.218.145", "212.18.249.145", "212.18.248.145"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "digital" => TldInfo {
         servers: &["65.22.23.36", "161.232.10.36", "65.22.21.36"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "direct" => TldInfo {
         servers: &["65.22.26.46", "65.22.27.46", "65.22.24.46"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "directory" => TldInfo {
         servers: &["65.22.30.11", "65.22.28.11", "161.232.14.11"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "discount" => TldInfo {
         servers: &["65.22.31.57", "65.22.28.57", "161.232.14.57"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "discover" => TldInfo {
         servers: &["65.22.110.30", "65.22.109.30", "65.22.111.30"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "dish" => TldInfo {
         servers: &["185.159.198.3", "64.96.2.1", "64.96.1.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "diy" => TldInfo {
         servers: &["64.96.2.1", "185.159.197.3", "64.96.1.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "dj" => TldInfo {
         servers: &["196.201.196.54", "52.213.131.190", "197.241.17.175"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "dk" => TldInfo {
         servers: &["194.0.28.7", "130.226.213.138", "185.159.199.45"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: Some("whois.punktum.dk"),
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "dm" => TldInfo {
         servers: &["64.96.2.1", "64.96.1.1", "194.0.1.34"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "dnp" => TldInfo {
         servers: &["37.209.198.4", "37.209.192.4", "37.209.194.4"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "do" => TldInfo {
         servers: &["190.113.65.13", "190.113.72.178", "190.113.72.177"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "docs" => TldInfo {
         servers: &["216.239.38.105", "216.239.60.105", "216.239.32.105"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "doctor" => TldInfo {
         servers: &["65.22.27.21", "65.22.24.21", "65.22.25.21"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "dog" => TldInfo {
         servers: &["161.232.17.45", "65.22.32.45", "65.22.33.45"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "domains" => TldInfo {
         servers: &["65.22.33.51", "65.22.35.51", "65.22.34.51"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "dot" => TldInfo {
         servers: &["185.159.197.3", "185.159.198.3", "64.96.2.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "download" => TldInfo {
         servers: &["37.209.194.10", "156.154.145.50", "156.154.159.50"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "drive" => TldInfo {
         servers: &["216.239.32.105", "216.239.36.105", "216.239.34.105"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "dtv" => TldInfo {
         servers: &["185.159.198.3", "185.159.197.3", "64.96.2.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "dubai" => TldInfo {
         servers: &["37.209.194.10", "37.209.192.10", "37.209.198.10"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "dunlop" => TldInfo {
         servers: &["65.22.122.33", "65.22.121.33", "65.22.123.33"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "dupont" => TldInfo {
         servers: &["37.209.192.9", "37.209.194.9", "156.154.156.52"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "durban" => TldInfo {
         servers: &["194.58.198.33", "194.146.106.74"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "dvag" => TldInfo {
         servers: &["185.24.64.84", "212.18.248.84", "212.18.249.84"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "dvr" => TldInfo {
         servers: &["64.96.1.1", "64.96.2.1", "185.159.198.3"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "dz" => TldInfo {
         servers: &["193.194.64.242", "213.179.160.66", "196.216.168.36"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "earth" => TldInfo {
         servers: &["156.154.159.230", "156.154.144.230", "156.154.145.230"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "eat" => TldInfo {
         servers: &["216.239.34.105", "216.239.38.105", "216.239.36.105"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ec" => TldInfo {
         servers: &["200.0.68.10", "204.61.216.39", "200.12.199.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "eco" => TldInfo {
         servers: &["185.159.199.4", "45.142.220.101", "185.159.197.4"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "edeka" => TldInfo {
         servers: &["65.22.17.25", "65.22.18.25", "65.22.16.25"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "edu" => TldInfo {
         servers: &["192.52.178.30", "192.31.80.30", "192.55.83.30"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: Some("whois.educause.edu"),
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "education" => TldInfo {
         servers: &["161.232.14.38", "65.22.30.38", "65.22.28.38"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ee" => TldInfo {
         servers: &["46.226.142.147", "195.43.87.10", "193.40.132.5"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "eg" => TldInfo {
         servers: &["193.227.1.1", "78.104.145.8", "147.28.0.39"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "email" => TldInfo {
         servers: &["161.232.13.35", "65.22.24.35", "65.22.27.35"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "emerck" => TldInfo {
         servers: &["65.22.156.1", "65.22.157.1", "65.22.158.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "energy" => TldInfo {
         servers: &["65.22.27.45", "161.232.13.45", "161.232.12.45"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "engineer" => TldInfo {
         servers: &["65.22.27.22", "161.232.13.22", "65.22.25.22"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "engineering" => TldInfo {
         servers: &["65.22.25.18", "65.22.26.18", "161.232.12.18"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "enterprises" => TldInfo {
         servers: &["161.232.11.52", "65.22.21.52", "65.22.20.52"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "epson" => TldInfo {
         servers: &["37.209.196.4", "37.209.192.4", "37.209.198.4"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "equipment" => TldInfo {
         servers: &["65.22.33.21", "65.22.35.21", "65.22.32.21"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "er" => TldInfo {
         servers: &["196.200.96.2", "196.200.96.1", "193.0.9.72"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ericsson" => TldInfo {
         servers: &["65.22.57.33", "65.22.59.33", "65.22.56.33"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "erni" => TldInfo {
         servers: &["195.253.65.11", "195.253.64.11", "195.253.64.12"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "es" => TldInfo {
         servers: &["194.0.34.53", "194.0.33.53", "204.61.217.1"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "esq" => TldInfo {
         servers: &["216.239.60.105", "216.239.34.105", "216.239.36.105"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "estate" => TldInfo {
         servers: &["65.22.31.47", "65.22.29.47", "65.22.28.47"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "et" => TldInfo {
         servers: &["197.156.74.193", "196.188.116.180", "197.156.74.192"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "eu" => TldInfo {
         servers: &["194.146.106.90", "149.38.1.26", "194.0.25.28"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: Some("whois.eu"),
+        rdap: None,
+        policy: LabelPolicy { min_length: 2, ..LabelPolicy::DEFAULT },
     },
     "eurovision" => TldInfo {
         servers: &["195.253.64.12", "195.253.65.12", "195.253.64.11"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "eus" => TldInfo {
         servers: &["195.253.65.11", "195.253.65.12", "195.253.64.12"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "events" => TldInfo {
         servers: &["65.22.32.6", "65.22.33.6", "65.22.34.6"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "exchange" => TldInfo {
         servers: &["65.22.31.14", "161.232.15.14", "65.22.28.14"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "expert" => TldInfo {
         servers: &["65.22.35.4", "65.22.32.4", "161.232.16.4"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "exposed" => TldInfo {
         servers: &["65.22.26.58", "161.232.12.58", "161.232.13.58"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "express" => TldInfo {
         servers: &["65.22.32.16", "161.232.17.16", "65.22.35.16"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "extraspace" => TldInfo {
         servers: &["65.22.117.1", "65.22.118.1", "65.22.119.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "fage" => TldInfo {
         servers: &["65.22.157.33", "65.22.159.33", "65.22.158.33"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "fail" => TldInfo {
         servers: &["161.232.11.56", "65.22.20.56", "65.22.23.56"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "fairwinds" => TldInfo {
         servers: &["156.154.102.3", "213.248.223.135", "156.154.101.3"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "faith" => TldInfo {
         servers: &["37.209.192.10", "37.209.196.10", "156.154.144.54"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["religious"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "family" => TldInfo {
         servers: &["65.22.31.39", "161.232.14.39", "65.22.29.39"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "fan" => TldInfo {
         servers: &["161.232.11.57", "161.232.10.57", "65.22.22.57"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "fans" => TldInfo {
         servers: &["212.18.249.39", "185.24.64.39", "212.18.248.39"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "farm" => TldInfo {
         servers: &["65.22.33.8", "161.232.17.8", "65.22.35.8"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "farmers" => TldInfo {
         servers: &["37.209.196.9", "156.154.159.55", "156.154.145.55"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "fashion" => TldInfo {
         servers: &["37.209.192.10", "156.154.173.82", "156.154.174.82"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "fast" => TldInfo {
         servers: &["156.154.103.3", "156.154.101.3", "103.49.82.66"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "fedex" => TldInfo {
         servers: &["65.22.231.33", "65.22.228.33", "65.22.229.33"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "feedback" => TldInfo {
         servers: &["64.96.1.1", "185.159.197.3", "64.96.2.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ferrari" => TldInfo {
         servers: &["65.22.125.25", "65.22.124.25", "65.22.126.25"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ferrero" => TldInfo {
         servers: &["37.209.192.9", "156.154.145.57", "37.209.196.9"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "fi" => TldInfo {
         servers: &["194.146.106.26", "204.61.216.98", "185.159.199.190"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: Some("whois.fi"),
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "fidelity" => TldInfo {
         servers: &["65.22.114.48", "65.22.115.48", "65.22.113.48"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "fido" => TldInfo {
         servers: &["65.22.108.41", "65.22.109.41", "65.22.110.41"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "film" => TldInfo {
         servers: &["156.154.173.82", "37.209.196.10", "37.209.192.10"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "final" => TldInfo {
         servers: &["200.219.159.10", "200.189.41.10", "200.229.248.10"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "finance" => TldInfo {
         servers: &["65.22.22.10", "65.22.21.10", "161.232.10.10"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "financial" => TldInfo {
         servers: &["161.232.10.54", "65.22.21.54", "65.22.22.54"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "fire" => TldInfo {
         servers: &["156.154.102.3", "213.248.218.57", "43.230.50.57"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "firestone" => TldInfo {
         servers: &["37.209.194.4", "37.209.196.4", "37.209.192.4"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "firmdale" => TldInfo {
         servers: &["72.0.51.1", "72.42.115.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "fish" => TldInfo {
         servers: &["65.22.31.23", "161.232.14.23", "65.22.28.23"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "fishing" => TldInfo {
         servers: &["156.154.173.82", "37.209.196.10", "37.209.194.10"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "fit" => TldInfo {
         servers: &["37.209.192.10", "37.209.196.10", "156.154.174.82"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "fitness" => TldInfo {
         servers: &["65.22.21.47", "161.232.11.47", "65.22.20.47"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "fj" => TldInfo {
         servers: &["185.28.194.194", "204.61.216.138", "144.120.146.65"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "fk" => TldInfo {
         servers: &["93.187.151.42", "80.73.216.251", "80.73.216.250"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "flickr" => TldInfo {
         servers: &["37.209.192.9", "37.209.194.9", "156.154.159.60"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "flights" => TldInfo {
         servers: &["161.232.14.31", "65.22.28.31", "161.232.15.31"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "flir" => TldInfo {
         servers: &["156.154.159.61", "156.154.144.61", "37.209.192.9"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "florist" => TldInfo {
         servers: &["65.22.35.30", "65.22.32.30", "161.232.16.30"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "flowers" => TldInfo {
         servers: &["185.24.64.154", "212.18.249.154", "212.18.248.154"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "fly" => TldInfo {
         servers: &["216.239.38.105", "216.239.32.105", "216.239.36.105"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "fm" => TldInfo {
         servers: &["206.51.254.3", "204.61.216.137", "185.24.64.26"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "fo" => TldInfo {
         servers: &["194.169.218.27", "185.24.64.27", "212.18.249.27"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "foo" => TldInfo {
         servers: &["216.239.34.105", "216.239.32.105", "216.239.36.105"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "food" => TldInfo {
         servers: &["64.96.2.1", "64.96.1.1", "185.159.198.3"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "football" => TldInfo {
         servers: &["65.22.23.22", "161.232.11.22", "161.232.10.22"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ford" => TldInfo {
         servers: &["156.154.145.62", "37.209.192.9", "156.154.159.62"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "forex" => TldInfo {
         servers: &["65.22.28.65", "161.232.14.65", "161.232.15.65"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "forsale" => TldInfo {
         servers: &["65.22.35.53", "65.22.34.53", "161.232.16.53"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "forum" => TldInfo {
         servers: &["185.159.197.3", "185.159.198.3", "64.96.2.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "foundation" => TldInfo {
         servers: &["65.22.27.43", "65.22.24.43", "65.22.26.43"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "fox" => TldInfo {
         servers: &["37.209.192.9", "37.209.194.9", "156.154.144.63"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "fr" => TldInfo {
         servers: &["194.0.36.1", "194.146.106.46", "194.0.9.1"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: Some("whois.nic.fr"),
+        rdap: Some("https://rdap.nic.fr/"),
+        policy: LabelPolicy::DEFAULT,
     },
     "free" => TldInfo {
         servers: &["156.154.100.3", "213.248.222.67", "156.154.101.3"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "fresenius" => TldInfo {
         servers: &["185.24.64.86", "212.18.248.86", "212.18.249.86"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "frl" => TldInfo {
         servers: &["185.24.64.87", "212.18.248.87", "194.169.218.87"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "frogans" => TldInfo {
         servers: &["65.22.189.1", "65.22.188.1", "65.22.190.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "frontier" => TldInfo {
         servers: &["156.154.144.64", "156.154.159.64", "156.154.145.64"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ftr" => TldInfo {
         servers: &["37.209.192.9", "37.209.196.9", "37.209.194.9"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "fujitsu" => TldInfo {
         servers: &["37.209.196.4", "37.209.192.4", "37.209.194.4"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "fun" => TldInfo {
         servers: &["212.18.249.72", "185.24.64.72", "212.18.248.72"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "fund" => TldInfo {
         servers: &["65.22.23.45", "161.232.11.45", "65.22.22.45"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "furniture" => TldInfo {
         servers: &["65.22.32.59", "65.22.33.59", "161.232.17.59"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "futbol" => TldInfo {
         servers: &["65.22.30.60", "65.22.31.60", "65.22.28.60"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "fyi" => TldInfo {
         servers: &["161.232.10.42", "161.232.11.42", "65.22.21.42"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ga" => TldInfo {
         servers: &["194.146.106.46", "194.0.36.1", "194.0.9.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "gal" => TldInfo {
         servers: &["195.253.64.12", "195.253.64.11", "195.253.65.11"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "gallery" => TldInfo {
         servers: &["65.22.24.8", "65.22.25.8", "161.232.12.8"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "gallo" => TldInfo {
         servers: &["65.22.59.1", "65.22.58.1", "65.22.56.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "gallup" => TldInfo {
         servers: &["65.22.171.17", "65.22.169.17", "65.22.170.17"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "game" => TldInfo {
         servers: &["185.24.64.147", "212.18.248.147", "194.169.218.147"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "games" => TldInfo {
         servers: &["65.22.20.39", "65.22.22.39", "161.232.11.39"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "gap" => TldInfo {
         servers: &["37.209.194.9", "156.154.145.66", "156.154.159.66"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "garden" => TldInfo {
         servers: &["156.154.173.82", "37.209.194.10", "156.154.172.82"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "gay" => TldInfo {
         servers: &["37.209.192.10", "37.209.194.10", "156.154.174.82"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["adult"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "gb" => TldInfo {
         servers: &["137.39.1.3", "128.86.1.20", "193.63.94.20"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "gbiz" => TldInfo {
         servers: &["216.239.60.105", "216.239.32.105", "216.239.36.105"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "gd" => TldInfo {
         servers: &["212.18.249.88", "185.24.64.88", "212.18.248.88"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "gdn" => TldInfo {
         servers: &["194.0.25.18", "194.0.26.11", "194.0.24.11"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ge" => TldInfo {
         servers: &["185.19.99.30", "35.153.81.228", "3.66.58.155"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "gea" => TldInfo {
         servers: &["194.0.26.13", "194.0.25.33", "194.0.24.13"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "gent" => TldInfo {
         servers: &["212.18.249.89", "212.18.248.89", "194.169.218.89"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "genting" => TldInfo {
         servers: &["65.22.111.13", "161.232.33.13", "65.22.108.13"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "george" => TldInfo {
         servers: &["37.209.196.9", "156.154.174.82", "156.154.173.82"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "gf" => TldInfo {
         servers: &["213.188.172.1", "185.56.51.194", "213.16.20.3"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "gg" => TldInfo {
         servers: &["213.248.223.254", "43.230.51.254", "204.61.216.74"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ggee" => TldInfo {
         servers: &["37.209.198.4", "37.209.196.4", "37.209.194.4"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "gh" => TldInfo {
         servers: &["197.253.95.251", "200.160.0.5", "197.253.127.251"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "gi" => TldInfo {
         servers: &["199.254.59.1", "199.254.60.1", "199.249.116.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "gift" => TldInfo {
         servers: &["185.159.198.3", "185.159.197.3", "64.96.2.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "gifts" => TldInfo {
         servers: &["161.232.16.23", "161.232.17.23", "65.22.33.23"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "gives" => TldInfo {
         servers: &["65.22.35.61", "65.22.34.61", "65.22.33.61"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "giving" => TldInfo {
         servers: &["199.19.56.1", "199.19.54.1", "199.249.120.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "gl" => TldInfo {
         servers: &["185.38.108.108", "185.28.194.194", "204.61.216.49"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "glass" => TldInfo {
         servers: &["161.232.13.57", "65.22.24.57", "65.22.27.57"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "gle" => TldInfo {
         servers: &["216.239.36.105", "216.239.34.105", "216.239.60.105"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "global" => TldInfo {
         servers: &["65.22.82.1", "65.22.83.1", "65.22.81.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "globo" => TldInfo {
         servers: &["200.219.154.10", "200.219.159.10", "200.229.248.10"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "gm" => TldInfo {
         servers: &["194.63.250.217", "196.216.168.29", "209.250.227.199"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "gmail" => TldInfo {
         servers: &["216.239.38.105", "216.239.34.105", "216.239.36.105"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "gmbh" => TldInfo {
         servers: &["161.232.16.7", "65.22.32.7", "65.22.34.7"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "gmo" => TldInfo {
         servers: &["37.209.196.4", "37.209.192.4", "37.209.198.4"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "gmx" => TldInfo {
         servers: &["195.253.65.12", "195.253.65.11", "195.253.64.11"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "gn" => TldInfo {
         servers: &["196.216.168.49", "41.77.190.237", "204.61.216.147"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "godaddy" => TldInfo {
         servers: &["156.154.174.82", "37.209.192.9", "156.154.173.82"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "gold" => TldInfo {
         servers: &["65.22.23.13", "65.22.20.13", "161.232.10.13"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "goldpoint" => TldInfo {
         servers: &["37.209.192.4", "37.209.198.4", "37.209.194.4"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "golf" => TldInfo {
         servers: &["65.22.28.46", "65.22.30.46", "65.22.31.46"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "goo" => TldInfo {
         servers: &["37.209.194.4", "37.209.198.4", "37.209.192.4"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "goodyear" => TldInfo {
         servers: &["65.22.120.41", "65.22.121.41", "65.22.122.41"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "goog" => TldInfo {
         servers: &["216.239.34.105", "216.239.60.105", "216.239.38.105"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "google" => TldInfo {
         servers: &["216.239.60.105", "216.239.38.105", "216.239.32.105"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "gop" => TldInfo {
         servers: &["156.154.102.3", "156.154.101.3", "156.154.103.3"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "got" => TldInfo {
         servers: &["213.248.218.68", "103.49.82.68", "156.154.100.3"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "gov" => TldInfo {
         servers: &["199.33.231.1", "199.33.233.1", "199.33.230.1"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: Some("whois.dotgov.gov"),
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "gp" => TldInfo {
         servers: &["194.0.9.1", "200.0.68.10", "193.0.9.76"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "gq" => TldInfo {
         servers: &["185.21.168.65", "185.21.171.65", "185.21.170.65"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "gr" => TldInfo {
         servers: &["78.104.145.227", "139.91.191.3", "194.0.11.102"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "grainger" => TldInfo {
         servers: &["37.209.192.9", "156.154.144.68", "156.154.159.68"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "graphics" => TldInfo {
         servers: &["65.22.31.20", "65.22.30.20", "65.22.29.20"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "gratis" => TldInfo {
         servers: &["65.22.22.24", "65.22.21.24", "161.232.11.24"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "green" => TldInfo {
         servers: &["65.22.35.9", "65.22.33.9", "65.22.34.9"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "gripe" => TldInfo {
         servers: &["65.22.27.33", "161.232.13.33", "161.232.12.33"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "grocery" => TldInfo {
         servers: &["37.209.194.10", "37.209.192.10", "156.154.172.82"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "group" => TldInfo {
         servers: &["65.22.21.2", "65.22.20.2", "65.22.22.2"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "gs" => TldInfo {
         servers: &["185.28.194.194", "204.61.216.21", "185.38.108.108"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "gt" => TldInfo {
         servers: &["200.7.5.14", "185.28.194.194", "185.38.108.108"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "gu" => TldInfo {
         servers: &["168.123.250.56", "128.223.32.35", "193.0.9.78"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "gucci" => TldInfo {
         servers: &["156.154.103.3", "213.248.219.43", "156.154.100.3"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "guge" => TldInfo {
         servers: &["216.239.32.105", "216.239.34.105", "216.239.36.105"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "guide" => TldInfo {
         servers: &["65.22.31.12", "161.232.14.12", "65.22.29.12"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "guitars" => TldInfo {
         servers: &["212.18.248.149", "212.18.249.149", "185.24.64.149"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "guru" => TldInfo {
         servers: &["161.232.14.36", "161.232.15.36", "65.22.28.36"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "gw" => TldInfo {
         servers: &["185.39.208.19", "194.146.106.138", "204.61.216.114"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "gy" => TldInfo {
         servers: &["204.61.216.34", "200.0.68.10"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "hair" => TldInfo {
         servers: &["212.18.248.117", "212.18.249.117", "194.169.218.117"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "hamburg" => TldInfo {
         servers: &["194.0.25.21", "194.0.26.10", "194.0.24.10"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "hangout" => TldInfo {
         servers: &["216.239.60.105", "216.239.32.105", "216.239.36.105"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "haus" => TldInfo {
         servers: &["65.22.27.20", "161.232.12.20", "65.22.24.20"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "hbo" => TldInfo {
         servers: &["156.154.145.70", "37.209.196.9", "156.154.159.70"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "hdfc" => TldInfo {
         servers: &["65.22.176.33", "65.22.178.33", "65.22.177.33"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "hdfcbank" => TldInfo {
         servers: &["65.22.182.33", "65.22.181.33", "65.22.180.33"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "health" => TldInfo {
         servers: &["37.209.194.10", "156.154.159.247", "156.154.145.247"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "healthcare" => TldInfo {
         servers: &["161.232.14.15", "65.22.30.15", "65.22.31.15"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "help" => TldInfo {
         servers: &["185.24.64.158", "212.18.249.158", "194.169.218.158"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "helsinki" => TldInfo {
         servers: &["65.22.223.33", "65.22.221.33", "65.22.222.33"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "here" => TldInfo {
         servers: &["216.239.60.105", "216.239.38.105", "216.239.34.105"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "hermes" => TldInfo {
         servers: &["65.22.235.25", "65.22.234.25", "65.22.233.25"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "hiphop" => TldInfo {
         servers: &["64.96.2.1", "185.159.197.3", "64.96.1.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "hisamitsu" => TldInfo {
         servers: &["37.209.192.4", "37.209.196.4", "37.209.194.4"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "hitachi" => TldInfo {
         servers: &["37.209.194.4", "37.209.198.4", "37.209.192.4"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "hiv" => TldInfo {
         servers: &["64.96.1.1", "185.159.198.3", "64.96.2.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "hk" => TldInfo {
         servers: &["203.119.87.218", "125.208.49.10", "210.201.138.58"],
         timeout_ms: 750,
         excluded_from_all: false,
         categories: &[],
+        whois: Some("whois.hkirc.hk"),
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "hkt" => TldInfo {
         servers: &["65.22.119.33", "65.22.116.33", "65.22.118.33"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "hm" => TldInfo {
         servers: &["142.171.40.232", "142.171.40.234", "128.199.180.188"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "hn" => TldInfo {
         servers: &["185.28.194.194", "204.61.216.64", "200.0.68.10"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "hockey" => TldInfo {
         servers: &["65.22.22.31", "161.232.11.31", "65.22.20.31"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "holdings" => TldInfo {
         servers: &["65.22.24.51", "161.232.13.51", "161.232.12.51"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "holiday" => TldInfo {
         servers: &["65.22.25.24", "65.22.24.24", "161.232.13.24"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "homedepot" => TldInfo {
         servers: &["65.22.193.1", "65.22.192.1", "65.22.195.1"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "homegoods" => TldInfo {
         servers: &["37.209.194.9", "156.154.145.73", "37.209.192.9"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "homes" => TldInfo {
         servers: &["194.169.218.132", "185.24.64.132", "212.18.248.132"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "homesense" => TldInfo {
         servers: &["156.154.159.74", "156.154.144.74", "37.209.196.9"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "honda" => TldInfo {
         servers: &["37.209.196.4", "37.209.192.4", "37.209.194.4"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "horse" => TldInfo {
         servers: &["37.209.196.10", "37.209.194.10", "156.154.172.82"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "hospital" => TldInfo {
         servers: &["65.22.22.29", "161.232.10.29", "65.22.23.29"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "host" => TldInfo {
         servers: &["185.24.64.53", "194.169.218.53", "212.18.248.53"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "hosting" => TldInfo {
         servers: &["194.169.218.152", "185.24.64.152", "212.18.248.152"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "hot" => TldInfo {
         servers: &["156.154.103.3", "213.248.222.69", "213.248.218.69"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "hotels" => TldInfo {
         servers: &["156.154.171.3", "37.209.196.10", "156.154.169.3"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "hotmail" => TldInfo {
         servers: &["103.49.83.131", "156.154.101.3", "43.230.51.131"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "house" => TldInfo {
         servers: &["65.22.26.7", "161.232.12.7", "65.22.27.7"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "how" => TldInfo {
         servers: &["216.239.36.105", "216.239.32.105", "216.239.38.105"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "hr" => TldInfo {
         servers: &["194.146.106.142", "204.61.216.90", "161.53.160.100"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "hsbc" => TldInfo {
         servers: &["156.154.145.76", "37.209.196.9", "37.209.194.9"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ht" => TldInfo {
         servers: &["194.0.9.1", "200.0.68.10", "204.61.216.38"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "hu" => TldInfo {
         servers: &["194.0.9.1", "194.0.25.11", "193.41.82.18"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "hughes" => TldInfo {
         servers: &["65.22.109.1", "65.22.111.1", "65.22.108.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "hyatt" => TldInfo {
         servers: &["37.209.192.9", "156.154.144.78", "156.154.159.78"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "hyundai" => TldInfo {
         servers: &["37.209.194.4", "37.209.196.4", "37.209.192.4"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ibm" => TldInfo {
         servers: &["37.209.192.9", "156.154.173.82", "37.209.194.9"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "icbc" => TldInfo {
         servers: &["203.99.25.1", "203.99.27.1", "116.169.54.111"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ice" => TldInfo {
         servers: &["65.22.113.9", "65.22.114.9", "65.22.112.9"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "icu" => TldInfo {
         servers: &["194.169.218.108", "212.18.249.108", "185.24.64.108"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "id" => TldInfo {
         servers: &["103.19.177.177", "103.19.178.178", "45.126.57.57"],
         timeout_ms: 750,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ie" => TldInfo {
         servers: &["77.72.72.40", "77.72.229.245", "192.93.0.4"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ieee" => TldInfo {
         servers: &["213.248.223.125", "156.154.102.3", "156.154.100.3"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ifm" => TldInfo {
         servers: &["195.253.65.11", "195.253.64.11", "195.253.64.12"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ikano" => TldInfo {
         servers: &["194.0.26.7", "194.0.24.7", "194.0.25.12"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "il" => TldInfo {
         servers: &["194.146.106.122", "128.139.35.5", "192.115.4.235"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "im" => TldInfo {
         servers: &["217.23.160.50", "193.62.157.66", "83.218.14.53"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "imamat" => TldInfo {
         servers: &["65.22.46.9", "65.22.47.9", "65.22.44.9"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "imdb" => TldInfo {
         servers: &["156.154.101.3", "43.230.50.50", "213.248.218.50"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "immo" => TldInfo {
         servers: &["65.22.30.42", "65.22.29.42", "65.22.31.42"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "immobilien" => TldInfo {
         servers: &["161.232.12.19", "161.232.13.19", "65.22.27.19"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "in" => TldInfo {
         servers: &["64.78.204.1", "64.96.1.1", "64.78.205.1"],
         timeout_ms: 750,
         excluded_from_all: false,
         categories: &[],
+        whois: Some("whois.registry.in"),
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "inc" => TldInfo {
         servers: &["212.18.248.137", "212.18.249.137", "194.169.218.137"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "industries" => TldInfo {
         servers: &["65.22.20.55", "65.22.23.55", "161.232.11.55"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "infiniti" => TldInfo {
         servers: &["37.209.198.4", "37.209.194.4", "37.209.196.4"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "info" => TldInfo {
         servers: &["199.254.49.1", "199.254.31.1", "199.249.121.1"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: Some("whois.nic.info"),
+        rdap: Some("https://rdap.identitydigital.services/rdap/"),
+        policy: LabelPolicy::DEFAULT,
     },
     "ing" => TldInfo {
         servers: &["216.239.60.105", "216.239.34.105", "216.239.32.105"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ink" => TldInfo {
         servers: &["37.209.194.10", "156.154.174.82", "156.154.172.82"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "institute" => TldInfo {
         servers: &["65.22.32.13", "65.22.35.13", "65.22.33.13"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "insurance" => TldInfo {
         servers: &["37.209.192.10", "156.154.105.22", "37.209.196.10"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "insure" => TldInfo {
         servers: &["65.22.25.53", "65.22.24.53", "65.22.26.53"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "int" => TldInfo {
         servers: &["199.43.133.53", "199.43.134.53", "199.43.135.53"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "international" => TldInfo {
         servers: &["161.232.15.6", "65.22.31.6", "65.22.30.6"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "intuit" => TldInfo {
         servers: &["37.209.192.9", "37.209.196.9", "156.154.157.82"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "investments" => TldInfo {
         servers: &["65.22.29.53", "161.232.15.53", "65.22.28.53"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "io" => TldInfo {
         servers: &["65.22.161.17", "65.22.163.17", "65.22.162.17"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: Some("whois.nic.io"),
+        rdap: Some("https://rdap.identitydigital.services/rdap/"),
+        policy: LabelPolicy::DEFAULT,
     },
     "ipiranga" => TldInfo {
         servers: &["156.154.158.83", "37.209.192.9", "37.209.196.9"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "iq" => TldInfo {
         servers: &["194.117.58.42", "194.117.57.105", "64.96.1.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ir" => TldInfo {
         servers: &["45.93.171.206", "193.189.123.2", "193.189.122.83"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "irish" => TldInfo {
         servers: &["161.232.16.27", "65.22.33.27", "65.22.35.27"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "is" => TldInfo {
         servers: &["185.93.156.154", "194.146.106.58", "185.93.156.10"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ismaili" => TldInfo {
         servers: &["65.22.46.25", "65.22.44.25", "65.22.45.25"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ist" => TldInfo {
         servers: &["65.22.147.1", "65.22.145.1", "65.22.144.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "istanbul" => TldInfo {
         servers: &["65.22.147.9", "65.22.146.9", "65.22.144.9"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "it" => TldInfo {
         servers: &["194.119.192.34", "194.0.16.215", "192.12.192.5"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: Some("whois.nic.it"),
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "itau" => TldInfo {
         servers: &["156.154.157.84", "156.154.156.84", "156.154.158.84"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "itv" => TldInfo {
         servers: &["65.22.89.25", "65.22.91.25", "65.22.88.25"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "jaguar" => TldInfo {
         servers: &["65.22.113.51", "65.22.112.51", "65.22.114.51"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "java" => TldInfo {
         servers: &["65.22.85.33", "65.22.87.33", "65.22.84.33"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "jcb" => TldInfo {
         servers: &["37.209.192.4", "37.209.198.4", "37.209.196.4"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "je" => TldInfo {
         servers: &["103.49.83.254", "204.61.216.74", "213.248.219.254"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "jeep" => TldInfo {
         servers: &["65.22.78.41", "65.22.76.41", "65.22.77.41"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "jetzt" => TldInfo {
         servers: &["65.22.20.49", "65.22.22.49", "65.22.21.49"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "jewelry" => TldInfo {
         servers: &["65.22.28.26", "161.232.15.26", "65.22.30.26"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "jio" => TldInfo {
         servers: &["65.22.215.33", "65.22.212.33", "65.22.213.33"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "jll" => TldInfo {
         servers: &["65.22.150.9", "65.22.149.9", "65.22.151.9"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "jm" => TldInfo {
         servers: &["196.2.1.6", "128.223.32.35", "196.3.191.66"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "jmp" => TldInfo {
         servers: &["156.154.145.88", "37.209.196.9", "37.209.192.9"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "jnj" => TldInfo {
         servers: &["212.18.248.161", "194.169.218.161", "185.24.64.161"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "jo" => TldInfo {
         servers: &["54.155.28.251", "193.0.9.83", "193.188.66.103"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "jobs" => TldInfo {
         servers: &["213.248.223.120", "43.230.51.120", "156.154.103.3"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "joburg" => TldInfo {
         servers: &["194.58.198.33", "194.146.106.74"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "jot" => TldInfo {
         servers: &["156.154.103.3", "103.49.82.70", "156.154.101.3"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "joy" => TldInfo {
         servers: &["43.230.50.71", "156.154.103.3", "156.154.101.3"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "jp" => TldInfo {
         servers: &["192.50.43.53", "203.119.1.1", "150.100.6.8"],
         timeout_ms: 750,
         excluded_from_all: false,
         categories: &[],
+        whois: Some("whois.jprs.jp"),
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "jpmorgan" => TldInfo {
         servers: &["156.154.158.92", "37.209.194.9", "37.209.196.9"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "jprs" => TldInfo {
         servers: &["103.47.2.1", "103.198.210.1", "65.22.40.129"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "juegos" => TldInfo {
         servers: &["185.159.198.3", "185.159.197.3", "64.96.1.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "juniper" => TldInfo {
         servers: &["65.22.113.52", "65.22.114.52", "65.22.115.52"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "kaufen" => TldInfo {
         servers: &["65.22.33.20", "65.22.32.20", "65.22.35.20"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "kddi" => TldInfo {
         servers: &["37.209.194.4", "37.209.192.4", "37.209.196.4"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ke" => TldInfo {
         servers: &["196.216.168.22", "196.1.4.3", "198.32.67.9"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "kerryhotels" => TldInfo {
         servers: &["65.22.114.53", "65.22.113.53", "65.22.115.53"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "kerryproperties" => TldInfo {
         servers: &["65.22.113.55", "65.22.112.55", "65.22.115.55"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "kfh" => TldInfo {
         servers: &["194.169.218.43", "212.18.249.43", "212.18.248.43"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "kg" => TldInfo {
         servers: &["195.38.160.36", "195.38.160.38", "193.0.9.84"],
         timeout_ms: 750,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "kh" => TldInfo {
         servers: &["203.189.128.1", "203.223.32.3", "203.223.32.21"],
         timeout_ms: 750,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ki" => TldInfo {
         servers: &["185.38.108.108", "204.61.216.26", "185.28.194.194"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "kia" => TldInfo {
         servers: &["37.209.198.4", "37.209.194.4", "37.209.196.4"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "kids" => TldInfo {
         servers: &["65.22.174.17", "65.22.173.17", "65.22.175.17"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "kim" => TldInfo {
         servers: &["65.22.29.1", "65.22.28.1", "65.22.31.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "kindle" => TldInfo {
         servers: &["156.154.100.3", "213.248.222.58", "156.154.101.3"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "kitchen" => TldInfo {
         servers: &["65.22.35.17", "161.232.17.17", "161.232.16.17"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "kiwi" => TldInfo {
         servers: &["185.159.197.1", "185.159.199.1", "185.159.198.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "km" => TldInfo {
         servers: &["197.255.224.18", "196.216.168.46", "197.255.224.66"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "kn" => TldInfo {
         servers: &["204.61.216.109", "185.38.108.108", "185.28.194.194"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "koeln" => TldInfo {
         servers: &["194.0.11.114", "193.56.204.111", "157.90.205.138"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "komatsu" => TldInfo {
         servers: &["37.209.198.4", "37.209.196.4", "37.209.192.4"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "kosher" => TldInfo {
         servers: &["65.22.222.1", "65.22.220.1", "65.22.223.1"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["religious"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "kp" => TldInfo {
         servers: &["175.45.176.15", "175.45.176.16"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "kpmg" => TldInfo {
         servers: &["156.154.145.98", "156.154.144.98", "37.209.192.9"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "kpn" => TldInfo {
         servers: &["212.18.248.90", "212.18.249.90", "194.169.218.90"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "kr" => TldInfo {
         servers: &["202.31.190.1", "210.101.60.1", "202.30.124.100"],
         timeout_ms: 750,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "krd" => TldInfo {
         servers: &["37.209.194.10", "37.209.196.10", "156.154.172.82"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "kred" => TldInfo {
         servers: &["212.18.248.79", "212.18.249.79", "185.24.64.79"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "kuokgroup" => TldInfo {
         servers: &["65.22.115.56", "65.22.114.56", "65.22.113.56"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "kw" => TldInfo {
         servers: &["185.42.137.135", "168.187.192.106", "194.58.198.135"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ky" => TldInfo {
         servers: &["64.96.2.1", "64.96.1.1", "185.159.197.3"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "kyoto" => TldInfo {
         servers: &["37.209.192.4", "37.209.198.4", "37.209.194.4"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "kz" => TldInfo {
         servers: &["194.0.21.5", "185.79.212.7", "204.61.216.143"],
         timeout_ms: 750,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "la" => TldInfo {
         servers: &["194.169.218.14", "185.24.64.15", "185.24.64.14"],
         timeout_ms: 750,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "lacaixa" => TldInfo {
         servers: &["195.253.64.11", "195.253.64.12", "195.253.65.11"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "lamborghini" => TldInfo {
         servers: &["65.22.210.33", "65.22.209.33", "65.22.211.33"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "lamer" => TldInfo {
         servers: &["65.22.54.25", "65.22.53.25", "65.22.55.25"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "land" => TldInfo {
         servers: &["161.232.11.11", "65.22.21.11", "65.22.23.11"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "landrover" => TldInfo {
         servers: &["65.22.114.57", "65.22.112.57", "65.22.113.57"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "lanxess" => TldInfo {
         servers: &["156.154.144.101", "156.154.159.101", "37.209.192.9"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "lasalle" => TldInfo {
         servers: &["65.22.151.1", "65.22.149.1", "65.22.148.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "lat" => TldInfo {
         servers: &["212.18.249.156", "185.24.64.156", "194.169.218.156"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "latino" => TldInfo {
         servers: &["185.159.198.3", "185.159.197.3", "64.96.1.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "latrobe" => TldInfo {
         servers: &["156.154.174.82", "156.154.173.82", "37.209.192.9"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "law" => TldInfo {
         servers: &["37.209.194.10", "156.154.172.82", "156.154.174.82"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "lawyer" => TldInfo {
         servers: &["65.22.24.14", "161.232.12.14", "161.232.13.14"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "lb" => TldInfo {
         servers: &["78.141.235.25", "185.91.97.18", "139.84.235.208"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "lc" => TldInfo {
         servers: &["199.254.60.1", "199.254.61.1", "199.249.116.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "lds" => TldInfo {
         servers: &["65.22.152.9", "65.22.155.9", "65.22.154.9"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["religious"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "lease" => TldInfo {
         servers: &["65.22.27.62", "65.22.25.62", "161.232.13.62"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "leclerc" => TldInfo {
         servers: &["194.0.36.1", "194.0.9.1", "194.146.106.46"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "lefrak" => TldInfo {
         servers: &["65.22.112.58", "65.22.114.58", "65.22.113.58"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "legal" => TldInfo {
         servers: &["65.22.20.12", "161.232.11.12", "65.22.23.12"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "lego" => TldInfo {
         servers: &["161.232.33.14", "65.22.109.14", "65.22.110.14"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "lexus" => TldInfo {
         servers: &["37.209.196.4", "37.209.198.4", "37.209.192.4"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "lgbt" => TldInfo {
         servers: &["65.22.35.1", "65.22.33.1", "65.22.32.1"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["adult"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "li" => TldInfo {
         servers: &["130.59.31.42", "194.0.17.1", "194.0.25.40"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: Some("whois.nic.li"),
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "lidl" => TldInfo {
         servers: &["212.18.249.91", "212.18.248.91", "194.169.218.91"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "life" => TldInfo {
         servers: &["65.22.21.35", "161.232.10.35", "65.22.23.35"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "lifeinsurance" => TldInfo {
         servers: &["37.209.194.9", "156.154.145.102", "37.209.196.9"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "lifestyle" => TldInfo {
         servers: &["64.96.1.1", "64.96.2.1", "185.159.198.3"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "lighting" => TldInfo {
         servers: &["65.22.35.52", "65.22.34.52", "161.232.16.52"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "like" => TldInfo {
         servers: &["156.154.103.3", "43.230.50.72", "156.154.100.3"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "lilly" => TldInfo {
         servers: &["37.209.196.9", "37.209.194.9", "156.154.145.104"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "limited" => TldInfo {
         servers: &["65.22.28.52", "65.22.29.52", "161.232.14.52"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "limo" => TldInfo {
         servers: &["65.22.21.30", "161.232.10.30", "65.22.23.30"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "lincoln" => TldInfo {
         servers: &["37.209.192.9", "156.154.159.105", "37.209.194.9"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "link" => TldInfo {
         servers: &["185.159.197.3", "64.96.1.1", "64.96.2.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "live" => TldInfo {
         servers: &["161.232.11.1", "65.22.21.1", "65.22.20.1"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "living" => TldInfo {
         servers: &["185.159.198.3", "185.159.197.3", "64.96.1.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "lk" => TldInfo {
         servers: &["128.10.2.5", "202.129.235.229", "203.94.66.129"],
         timeout_ms: 750,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "llc" => TldInfo {
         servers: &["65.22.39.9", "65.22.37.9", "65.22.36.9"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "llp" => TldInfo {
         servers: &["212.18.248.157", "212.18.249.157", "194.169.218.157"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "loan" => TldInfo {
         servers: &["37.209.196.10", "156.154.159.106", "156.154.145.106"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "loans" => TldInfo {
         servers: &["65.22.24.55", "65.22.27.55", "161.232.12.55"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "locker" => TldInfo {
         servers: &["64.96.2.1", "185.159.198.3", "185.159.197.3"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "locus" => TldInfo {
         servers: &["213.248.219.11", "213.248.223.11", "103.49.83.11"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "lol" => TldInfo {
         servers: &["194.169.218.146", "212.18.248.146", "212.18.249.146"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "london" => TldInfo {
         servers: &["185.24.64.141", "212.18.249.141", "194.169.218.141"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "lotte" => TldInfo {
         servers: &["37.209.196.4", "37.209.194.4", "37.209.198.4"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "lotto" => TldInfo {
         servers: &["65.22.29.25", "65.22.30.25", "65.22.28.25"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["gambling"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "love" => TldInfo {
         servers: &["64.96.2.1", "185.159.198.3", "185.159.197.3"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "lpl" => TldInfo {
         servers: &["212.18.248.92", "185.24.64.92", "212.18.249.92"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "lplfinancial" => TldInfo {
         servers: &["185.24.64.93", "212.18.248.93", "212.18.249.93"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "lr" => TldInfo {
         servers: &["196.216.168.61", "77.72.229.254", "147.28.0.39"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ls" => TldInfo {
         servers: &["196.11.175.1", "196.216.168.70", "196.43.249.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "lt" => TldInfo {
         servers: &["185.150.40.131", "194.0.19.1", "194.0.3.1"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ltd" => TldInfo {
         servers: &["65.22.31.2", "161.232.14.2", "65.22.29.2"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ltda" => TldInfo {
         servers: &["65.22.119.17", "65.22.118.17", "65.22.116.17"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "lu" => TldInfo {
         servers: &["77.72.229.247", "194.0.42.42", "158.64.229.18"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "lundbeck" => TldInfo {
         servers: &["65.22.114.60", "65.22.112.60", "65.22.115.60"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "luxe" => TldInfo {
         servers: &["37.209.192.10", "37.209.196.10", "37.209.194.10"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "luxury" => TldInfo {
         servers: &["212.18.249.98", "212.18.248.98", "185.24.64.98"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "lv" => TldInfo {
         servers: &["194.0.8.1", "192.36.125.2", "185.159.199.140"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ly" => TldInfo {
         servers: &["62.68.42.9", "196.216.168.24", "128.223.32.35"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ma" => TldInfo {
         servers: &["81.192.171.116", "81.192.171.131", "81.192.171.115"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "madrid" => TldInfo {
         servers: &["195.253.64.11", "195.253.65.12", "195.253.64.12"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "maif" => TldInfo {
         servers: &["161.232.33.5", "65.22.110.5", "65.22.108.5"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "maison" => TldInfo {
         servers: &["65.22.28.33", "65.22.29.33", "161.232.15.33"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "makeup" => TldInfo {
         servers: &["185.24.64.116", "194.169.218.116", "212.18.249.116"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "man" => TldInfo {
         servers: &["195.253.64.12", "195.253.65.11", "195.253.65.12"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "management" => TldInfo {
         servers: &["65.22.21.14", "161.232.10.14", "65.22.22.14"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "mango" => TldInfo {
         servers: &["195.253.64.11", "195.253.65.11", "195.253.65.12"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "map" => TldInfo {
         servers: &["216.239.34.105", "216.239.38.105", "216.239.60.105"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "market" => TldInfo {
         servers: &["65.22.20.8", "161.232.10.8", "65.22.23.8"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "marketing" => TldInfo {
         servers: &["65.22.20.6", "65.22.21.6", "161.232.11.6"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "markets" => TldInfo {
         servers: &["161.232.16.65", "65.22.33.65", "161.232.17.65"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "marriott" => TldInfo {
         servers: &["65.22.238.9", "65.22.236.9", "65.22.237.9"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "marshalls" => TldInfo {
         servers: &["156.154.145.108", "37.209.196.9", "37.209.192.9"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "mattel" => TldInfo {
         servers: &["37.209.194.9", "156.154.144.109", "37.209.196.9"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "mba" => TldInfo {
         servers: &["65.22.25.59", "65.22.26.59", "65.22.24.59"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "mc" => TldInfo {
         servers: &["15.237.153.29", "13.36.89.111", "193.0.9.92"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "mckinsey" => TldInfo {
         servers: &["65.22.237.25", "65.22.236.25", "65.22.238.25"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "md" => TldInfo {
         servers: &["185.108.181.191", "144.217.93.2", "185.108.181.192"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "me" => TldInfo {
         servers: &["199.253.59.1", "199.249.119.1", "199.253.61.1"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: Some("whois.nic.me"),
+        rdap: Some("https://rdap.identitydigital.services/rdap/"),
+        policy: LabelPolicy::DEFAULT,
     },
     "med" => TldInfo {
         servers: &["43.230.51.124", "103.49.83.124", "156.154.101.3"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "media" => TldInfo {
         servers: &["161.232.10.3", "65.22.20.3", "65.22.23.3"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "meet" => TldInfo {
         servers: &["216.239.36.105", "216.239.60.105", "216.239.38.105"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "melbourne" => TldInfo {
         servers: &["156.154.174.82", "156.154.172.82", "37.209.192.10"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "meme" => TldInfo {
         servers: &["216.239.60.105", "216.239.34.105", "216.239.38.105"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "memorial" => TldInfo {
         servers: &["65.22.35.64", "65.22.33.64", "161.232.17.64"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "men" => TldInfo {
         servers: &["156.154.174.82", "156.154.172.82", "156.154.173.82"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "menu" => TldInfo {
         servers: &["37.209.192.10", "37.209.194.10", "37.209.196.10"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "merckmsd" => TldInfo {
         servers: &["37.209.196.9", "37.209.194.9", "37.209.192.9"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "mg" => TldInfo {
         servers: &["196.192.42.153", "13.39.116.127", "196.216.168.35"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "mh" => TldInfo {
         servers: &["71.163.249.97"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "miami" => TldInfo {
         servers: &["156.154.174.82", "37.209.192.10", "37.209.196.10"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "microsoft" => TldInfo {
         servers: &["213.248.219.132", "43.230.51.132", "156.154.100.3"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "mil" => TldInfo {
         servers: &["199.252.155.234", "199.252.143.234", "199.252.162.234"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "mini" => TldInfo {
         servers: &["212.18.249.95", "194.169.218.95", "185.24.64.95"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "mint" => TldInfo {
         servers: &["156.154.157.112", "156.154.158.112", "37.209.194.9"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "mit" => TldInfo {
         servers: &["65.22.236.17", "65.22.237.17", "65.22.238.17"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "mitsubishi" => TldInfo {
         servers: &["37.209.196.4", "37.209.194.4", "37.209.192.4"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "mk" => TldInfo {
         servers: &["153.5.81.140", "78.104.145.4", "185.43.134.12"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ml" => TldInfo {
         servers: &["204.61.216.144", "196.216.168.37", "165.90.218.166"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "mlb" => TldInfo {
         servers: &["37.209.196.9", "156.154.145.113", "37.209.194.9"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "mls" => TldInfo {
         servers: &["185.159.199.8", "185.159.198.8", "185.159.197.8"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "mm" => TldInfo {
         servers: &["37.209.192.4", "37.209.198.4", "37.209.194.4"],
         timeout_ms: 750,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "mma" => TldInfo {
         servers: &["194.0.9.1", "194.146.106.46", "194.0.36.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "mn" => TldInfo {
         servers: &["199.254.59.1", "199.254.61.1", "199.249.124.1"],
         timeout_ms: 750,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "mo" => TldInfo {
         servers: &["202.175.87.48", "202.175.51.115", "202.175.51.116"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "mobi" => TldInfo {
         servers: &["199.254.57.1", "199.249.126.1", "199.254.56.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: Some("whois.nic.mobi"),
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "mobile" => TldInfo {
         servers: &["185.159.198.3", "64.96.2.1", "185.159.197.3"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "moda" => TldInfo {
         servers: &["161.232.10.28", "65.22.22.28", "65.22.21.28"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "moe" => TldInfo {
         servers: &["37.209.196.10", "156.154.145.114", "37.209.192.10"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "moi" => TldInfo {
         servers: &["156.154.102.3", "213.248.218.54", "103.49.82.54"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "mom" => TldInfo {
         servers: &["194.169.218.148", "212.18.248.148", "212.18.249.148"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "monash" => TldInfo {
         servers: &["37.209.196.9", "37.209.194.9", "156.154.174.82"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "money" => TldInfo {
         servers: &["161.232.14.13", "65.22.31.13", "161.232.15.13"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "monster" => TldInfo {
         servers: &["212.18.248.83", "212.18.249.83", "194.169.218.83"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "mormon" => TldInfo {
         servers: &["65.22.154.1", "65.22.153.1", "65.22.152.1"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["religious"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "mortgage" => TldInfo {
         servers: &["65.22.35.56", "161.232.16.56", "65.22.34.56"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "moscow" => TldInfo {
         servers: &["31.177.70.1", "195.253.65.6", "195.253.64.6"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "moto" => TldInfo {
         servers: &["37.209.192.9", "37.209.194.9", "156.154.172.81"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "motorcycles" => TldInfo {
         servers: &["212.18.248.135", "212.18.249.135", "185.24.64.135"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "mov" => TldInfo {
         servers: &["216.239.36.105", "216.239.60.105", "216.239.38.105"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "movie" => TldInfo {
         servers: &["65.22.34.26", "161.232.16.26", "65.22.32.26"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "mp" => TldInfo {
         servers: &["75.101.133.101", "16.163.54.122", "16.162.31.128"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "mq" => TldInfo {
         servers: &["213.188.172.1", "185.56.51.194", "213.16.20.3"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "mr" => TldInfo {
         servers: &["194.0.9.1", "82.151.64.2", "193.146.150.193"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ms" => TldInfo {
         servers: &["185.38.108.108", "204.61.216.33", "185.28.194.194"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "msd" => TldInfo {
         servers: &["156.154.173.82", "37.209.196.9", "37.209.192.9"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "mt" => TldInfo {
         servers: &["204.61.216.45", "193.188.34.241", "193.188.47.252"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "mtn" => TldInfo {
         servers: &["156.154.101.3", "156.154.100.3", "156.154.102.3"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "mtr" => TldInfo {
         servers: &["203.119.87.164", "203.119.2.164", "135.90.90.24"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "mu" => TldInfo {
         servers: &["199.249.124.33", "199.254.60.33", "199.254.59.33"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "museum" => TldInfo {
         servers: &["194.0.9.1", "194.146.106.46", "194.0.36.1"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "music" => TldInfo {
         servers: &["185.159.197.3", "64.96.2.1", "64.96.1.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "mv" => TldInfo {
         servers: &["202.1.192.196", "202.1.201.201", "204.61.216.24"],
         timeout_ms: 750,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "mw" => TldInfo {
         servers: &["196.45.188.5", "204.61.216.131", "196.45.190.9"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "mx" => TldInfo {
         servers: &["192.100.224.1", "200.23.1.1", "200.94.176.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "my" => TldInfo {
         servers: &["194.169.218.114", "212.18.248.114", "185.24.64.114"],
         timeout_ms: 750,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "mz" => TldInfo {
         servers: &["196.3.96.66", "204.61.216.14", "128.223.32.35"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "na" => TldInfo {
         servers: &["185.38.108.108", "195.253.64.7", "204.61.216.35"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "nab" => TldInfo {
         servers: &["65.22.113.61", "65.22.112.61", "65.22.114.61"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "nagoya" => TldInfo {
         servers: &["37.209.196.4", "37.209.194.4", "37.209.192.4"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "name" => TldInfo {
         servers: &["192.42.173.30", "192.42.174.30", "192.42.175.30"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: Some("whois.nic.name"),
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "navy" => TldInfo {
         servers: &["65.22.30.34", "161.232.14.34", "65.22.29.34"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "nba" => TldInfo {
         servers: &["37.209.196.9", "37.209.194.9", "37.209.192.9"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "nc" => TldInfo {
         servers: &["193.0.9.100", "114.69.222.1", "202.87.129.16"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ne" => TldInfo {
         servers: &["41.138.54.10", "196.216.168.45", "193.0.9.101"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "nec" => TldInfo {
         servers: &["37.209.198.4", "37.209.196.4", "37.209.194.4"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "net" => TldInfo {
         servers: &["192.31.80.30", "192.52.178.30", "192.42.93.30"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: Some("whois.verisign-grs.com"),
+        rdap: Some("https://rdap.verisign.com/net/v1/"),
+        policy: LabelPolicy::DEFAULT,
     },
     "netbank" => TldInfo {
         servers: &["37.209.192.9", "156.154.172.82", "37.209.194.9"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "netflix" => TldInfo {
         servers: &["37.209.196.9", "37.209.194.9", "156.154.158.121"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "network" => TldInfo {
         servers: &["65.22.34.36", "161.232.16.36", "65.22.35.36"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "neustar" => TldInfo {
         servers: &["37.209.194.9", "199.7.67.250", "192.100.59.250"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "new" => TldInfo {
         servers: &["216.239.38.105", "216.239.36.105", "216.239.32.105"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "news" => TldInfo {
         servers: &["65.22.34.3", "161.232.16.3", "161.232.17.3"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "next" => TldInfo {
         servers: &["65.22.115.62", "65.22.113.62", "65.22.114.62"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "nextdirect" => TldInfo {
         servers: &["65.22.114.63", "65.22.115.63", "65.22.112.63"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "nexus" => TldInfo {
         servers: &["216.239.38.105", "216.239.32.105", "216.239.60.105"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "nf" => TldInfo {
         servers: &["204.61.216.51", "185.38.108.108", "185.28.194.194"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "nfl" => TldInfo {
         servers: &["37.209.192.9", "156.154.157.122", "37.209.196.9"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ng" => TldInfo {
         servers: &["185.28.194.194", "185.38.108.108", "204.61.216.40"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ngo" => TldInfo {
         servers: &["199.249.112.1", "199.249.120.1", "199.19.57.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "nhk" => TldInfo {
         servers: &["37.209.196.4", "37.209.192.4", "37.209.194.4"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ni" => TldInfo {
         servers: &["186.1.31.8", "200.107.82.219", "200.119.166.36"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "nico" => TldInfo {
         servers: &["37.209.192.4", "37.209.196.4", "37.209.194.4"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "nike" => TldInfo {
         servers: &["156.154.144.123", "156.154.145.123", "156.154.159.123"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "nikon" => TldInfo {
         servers: &["65.22.113.64", "65.22.112.64", "65.22.114.64"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ninja" => TldInfo {
         servers: &["161.232.11.4", "65.22.21.4", "65.22.23.4"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "nissan" => TldInfo {
         servers: &["37.209.194.4", "37.209.192.4", "37.209.196.4"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "nissay" => TldInfo {
         servers: &["192.42.175.30", "192.42.176.30", "192.42.174.30"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "nl" => TldInfo {
         servers: &["194.0.28.53", "185.159.199.200", "194.0.25.24"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: Some("whois.domain-registry.nl"),
+        rdap: Some("https://rdap.sidn.nl/"),
+        policy: LabelPolicy::DEFAULT,
     },
     "no" => TldInfo {
         servers: &["158.38.8.133", "128.39.8.40", "193.75.4.22"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: Some("whois.norid.no"),
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "nokia" => TldInfo {
         servers: &["212.18.248.159", "194.169.218.159", "212.18.249.159"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "norton" => TldInfo {
         servers: &["192.42.176.30", "192.42.174.30", "192.42.175.30"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "now" => TldInfo {
         servers: &["213.248.218.73", "156.154.101.3", "213.248.222.73"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "nowruz" => TldInfo {
         servers: &["43.230.49.153", "103.49.81.153", "156.154.103.3"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "nowtv" => TldInfo {
         servers: &["65.22.173.1", "65.22.174.1", "65.22.175.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "np" => TldInfo {
         servers: &["27.111.31.5", "202.12.31.53", "198.32.126.50"],
         timeout_ms: 750,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "nr" => TldInfo {
         servers: &["128.223.32.35", "203.98.224.66", "203.98.225.9"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "nra" => TldInfo {
         servers: &["65.22.241.25", "65.22.243.25", "65.22.240.25"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "nrw" => TldInfo {
         servers: &["195.253.64.11", "37.209.196.2", "37.209.198.2"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ntt" => TldInfo {
         servers: &["117.104.133.17", "103.47.2.2", "65.22.40.145"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "nu" => TldInfo {
         servers: &["185.159.198.150", "213.108.25.4", "185.159.197.150"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: Some("whois.iis.nu"),
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "nyc" => TldInfo {
         servers: &["37.209.194.10", "156.154.159.125", "156.154.145.125"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "nz" => TldInfo {
         servers: &["202.46.187.130", "194.146.106.54", "202.46.189.130"],
         timeout_ms: 750,
         excluded_from_all: false,
         categories: &[],
+        whois: Some("whois.irs.net.nz"),
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "obi" => TldInfo {
         servers: &["65.22.114.17", "65.22.112.17", "65.22.113.17"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "observer" => TldInfo {
         servers: &["64.96.1.1", "185.159.197.3", "185.159.198.3"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "office" => TldInfo {
         servers: &["213.248.223.132", "156.154.100.3", "156.154.103.3"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "okinawa" => TldInfo {
         servers: &["37.209.198.4", "37.209.192.4", "37.209.196.4"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "olayan" => TldInfo {
         servers: &["156.154.172.82", "37.209.194.9", "37.209.192.9"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "olayangroup" => TldInfo {
         servers: &["156.154.174.82", "156.154.172.82", "37.209.194.9"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ollo" => TldInfo {
         servers: &["64.96.1.1", "185.159.198.3", "64.96.2.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "om" => TldInfo {
         servers: &["37.209.196.6", "185.27.88.50", "37.209.192.6"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "omega" => TldInfo {
         servers: &["213.248.219.140", "103.49.83.140", "156.154.100.3"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "one" => TldInfo {
         servers: &["156.154.172.82", "37.209.192.9", "156.154.173.82"],
         timeout_ms: 500,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ong" => TldInfo {
         servers: &["199.249.120.1", "199.19.54.1", "199.249.112.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "onl" => TldInfo {
         servers: &["65.22.138.1", "65.22.139.1", "65.22.136.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "online" => TldInfo {
         servers: &["212.18.249.54", "194.169.218.54", "212.18.248.54"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: Some("whois.nic.online"),
+        rdap: Some("https://rdap.centralnic.com/online/"),
+        policy: LabelPolicy::DEFAULT,
     },
     "ooo" => TldInfo {
         servers: &["212.18.248.33", "194.169.218.33", "212.18.249.33"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "open" => TldInfo {
         servers: &["37.209.194.9", "37.209.196.9", "156.154.159.128"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "oracle" => TldInfo {
         servers: &["65.22.85.25", "65.22.86.25", "65.22.84.25"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "orange" => TldInfo {
         servers: &["65.22.109.16", "65.22.110.16", "65.22.108.16"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "org" => TldInfo {
         servers: &["199.249.112.1", "199.19.53.1", "199.19.56.1"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: Some("whois.publicinterestregistry.org"),
+        rdap: Some("https://rdap.publicinterestregistry.org/rdap/"),
+        policy: LabelPolicy::DEFAULT,
     },
     "organic" => TldInfo {
         servers: &["65.22.20.40", "65.22.21.40", "65.22.22.40"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "origins" => TldInfo {
         servers: &["65.22.54.33", "65.22.52.33", "65.22.55.33"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "osaka" => TldInfo {
         servers: &["156.154.145.130", "37.209.194.10", "37.209.196.10"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "otsuka" => TldInfo {
         servers: &["37.209.198.4", "37.209.192.4", "37.209.196.4"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ott" => TldInfo {
         servers: &["185.159.198.3", "64.96.2.1", "185.159.197.3"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ovh" => TldInfo {
         servers: &["194.146.106.46", "194.0.36.1", "194.0.9.1"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "pa" => TldInfo {
         servers: &["168.77.9.7", "168.77.9.6", "185.28.194.194"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "page" => TldInfo {
         servers: &["216.239.32.105", "216.239.38.105", "216.239.36.105"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: Some("whois.nic.google"),
+        rdap: Some("https://pubapi.registry.google/rdap/"),
+        policy: LabelPolicy::DEFAULT,
     },
     "panasonic" => TldInfo {
         servers: &["37.209.196.4", "37.209.194.4", "37.209.198.4"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "paris" => TldInfo {
         servers: &["194.0.36.1", "194.0.9.1", "194.146.106.46"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "pars" => TldInfo {
         servers: &["156.154.100.3", "156.154.103.3", "156.154.102.3"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "partners" => TldInfo {
         servers: &["65.22.33.15", "161.232.16.15", "161.232.17.15"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "parts" => TldInfo {
         servers: &["161.232.10.53", "65.22.23.53", "65.22.20.53"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "party" => TldInfo {
         servers: &["156.154.169.24", "156.154.171.24", "37.209.194.10"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "pay" => TldInfo {
         servers: &["213.248.218.74", "156.154.102.3", "156.154.101.3"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "pccw" => TldInfo {
         servers: &["65.22.116.41", "65.22.118.41", "65.22.117.41"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "pe" => TldInfo {
         servers: &["200.1.176.4", "194.146.106.82", "204.61.216.85"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "pet" => TldInfo {
         servers: &["65.22.16.17", "65.22.17.17", "65.22.18.17"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "pf" => TldInfo {
         servers: &["202.3.225.20", "202.3.225.10"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "pfizer" => TldInfo {
         servers: &["37.209.194.9", "156.154.144.134", "37.209.192.9"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "pg" => TldInfo {
         servers: &["204.61.216.152", "185.28.194.194", "185.38.108.108"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ph" => TldInfo {
         servers: &["206.51.255.1", "137.189.6.21", "202.12.31.53"],
         timeout_ms: 750,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "pharmacy" => TldInfo {
         servers: &["213.248.219.45", "156.154.101.3", "213.248.223.45"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "phd" => TldInfo {
         servers: &["216.239.32.105", "216.239.36.105", "216.239.34.105"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "philips" => TldInfo {
         servers: &["156.154.173.82", "156.154.172.82", "37.209.194.9"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "phone" => TldInfo {
         servers: &["185.159.197.3", "64.96.2.1", "185.159.198.3"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "photo" => TldInfo {
         servers: &["156.154.174.82", "156.154.172.82", "37.209.196.10"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "photography" => TldInfo {
         servers: &["65.22.24.3", "161.232.12.3", "65.22.27.3"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "photos" => TldInfo {
         servers: &["65.22.28.40", "161.232.15.40", "65.22.31.40"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "physio" => TldInfo {
         servers: &["156.154.173.82", "37.209.194.10", "37.209.192.10"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "pics" => TldInfo {
         servers: &["212.18.248.151", "212.18.249.151", "194.169.218.151"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "pictet" => TldInfo {
         servers: &["65.22.110.6", "161.232.32.6", "65.22.111.6"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "pictures" => TldInfo {
         servers: &["65.22.21.16", "161.232.10.16", "65.22.22.16"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "pid" => TldInfo {
         servers: &["185.159.198.3", "185.159.197.3", "64.96.2.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "pin" => TldInfo {
         servers: &["213.248.222.75", "213.248.218.75", "156.154.102.3"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ping" => TldInfo {
         servers: &["156.154.171.29", "37.209.196.9", "156.154.169.29"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "pink" => TldInfo {
         servers: &["65.22.31.21", "65.22.29.17", "65.22.31.17"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "pioneer" => TldInfo {
         servers: &["156.154.101.3", "43.230.51.126", "103.49.83.126"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "pizza" => TldInfo {
         servers: &["161.232.15.50", "161.232.14.50", "65.22.30.50"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "pk" => TldInfo {
         servers: &["107.6.178.178", "185.159.198.160", "119.81.34.90"],
         timeout_ms: 750,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "pl" => TldInfo {
         servers: &["204.61.217.4", "185.159.198.48", "192.195.72.53"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: Some("whois.dns.pl"),
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "place" => TldInfo {
         servers: &["65.22.28.22", "65.22.30.22", "65.22.29.22"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "play" => TldInfo {
         servers: &["216.239.34.105", "216.239.38.105", "216.239.60.105"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "playstation" => TldInfo {
         servers: &["37.209.196.4", "37.209.194.4", "37.209.198.4"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "plumbing" => TldInfo {
         servers: &["65.22.24.28", "65.22.26.28", "65.22.27.28"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "plus" => TldInfo {
         servers: &["65.22.33.39", "161.232.16.39", "161.232.17.39"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "pm" => TldInfo {
         servers: &["194.0.36.1", "194.0.9.1", "194.146.106.46"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "pn" => TldInfo {
         servers: &["156.154.100.3", "156.154.102.3", "213.248.219.128"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "pnc" => TldInfo {
         servers: &["65.22.64.25", "65.22.65.25", "65.22.67.25"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "pohl" => TldInfo {
         servers: &["185.24.64.96", "194.169.218.96", "212.18.248.96"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "poker" => TldInfo {
         servers: &["65.22.21.33", "65.22.22.33", "65.22.20.33"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["gambling"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "politie" => TldInfo {
         servers: &["194.0.25.27", "194.0.28.5", "185.159.199.203"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "porn" => TldInfo {
         servers: &["37.209.194.10", "156.154.172.82", "37.209.192.10"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["adult"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "post" => TldInfo {
         servers: &["65.22.4.1", "65.22.5.1", "65.22.2.1"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "pr" => TldInfo {
         servers: &["194.0.11.111", "199.254.60.17", "200.0.68.10"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "praxi" => TldInfo {
         servers: &["37.209.194.9", "156.154.145.139", "37.209.196.9"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "press" => TldInfo {
         servers: &["212.18.249.34", "194.169.218.34", "212.18.248.34"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "prime" => TldInfo {
         servers: &["156.154.101.3", "156.154.103.3", "43.230.50.51"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "pro" => TldInfo {
         servers: &["199.182.40.1", "199.182.1.1", "199.182.17.1"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "prod" => TldInfo {
         servers: &["216.239.32.105", "216.239.60.105", "216.239.36.105"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "productions" => TldInfo {
         servers: &["161.232.14.16", "65.22.28.16", "65.22.31.16"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "prof" => TldInfo {
         servers: &["216.239.60.105", "216.239.36.105", "216.239.34.105"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "progressive" => TldInfo {
         servers: &["65.22.203.33", "65.22.201.33", "65.22.200.33"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "promo" => TldInfo {
         servers: &["65.22.244.9", "65.22.245.9", "65.22.247.9"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "properties" => TldInfo {
         servers: &["65.22.23.46", "161.232.10.46", "65.22.21.46"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "property" => TldInfo {
         servers: &["185.159.198.3", "64.96.2.1", "185.159.197.3"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "protection" => TldInfo {
         servers: &["185.24.64.67", "212.18.249.67", "194.169.218.67"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "pru" => TldInfo {
         servers: &["37.209.194.9", "156.154.144.141", "37.209.192.9"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "prudential" => TldInfo {
         servers: &["156.154.159.142", "156.154.145.142", "37.209.192.9"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ps" => TldInfo {
         servers: &["204.61.216.29", "77.72.229.254", "193.0.9.105"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "pt" => TldInfo {
         servers: &["204.61.216.105", "193.136.2.226", "185.39.210.1"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "pub" => TldInfo {
         servers: &["65.22.31.7", "161.232.15.7", "161.232.14.7"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private", "controlled"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "pw" => TldInfo {
         servers: &["194.169.218.12", "185.24.64.12", "212.18.249.12"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "pwc" => TldInfo {
         servers: &["65.22.81.9", "65.22.80.9", "65.22.83.9"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "py" => TldInfo {
         servers: &["204.61.216.107", "198.6.1.65", "130.59.31.30"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "qa" => TldInfo {
         servers: &["212.77.192.13", "37.209.192.6", "178.23.16.104"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "qpon" => TldInfo {
         servers: &["194.169.218.128", "185.24.64.128", "212.18.248.128"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "quebec" => TldInfo {
         servers: &["195.253.64.11", "195.253.65.11", "195.253.65.12"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "quest" => TldInfo {
         servers: &["212.18.249.19", "194.169.218.19", "212.18.248.19"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "racing" => TldInfo {
         servers: &["37.209.192.10", "37.209.196.10", "156.154.171.33"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "radio" => TldInfo {
         servers: &["195.253.65.11", "195.253.65.12", "195.253.64.11"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "re" => TldInfo {
         servers: &["194.146.106.46", "194.0.9.1", "194.0.36.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "read" => TldInfo {
         servers: &["43.230.50.76", "213.248.218.76", "156.154.102.3"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "realestate" => TldInfo {
         servers: &["156.154.102.3", "103.49.83.123", "156.154.101.3"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "realtor" => TldInfo {
         servers: &["156.154.100.3", "156.154.101.3", "213.248.223.122"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "realty" => TldInfo {
         servers: &["185.159.197.3", "64.96.2.1", "64.96.1.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "recipes" => TldInfo {
         servers: &["161.232.12.54", "65.22.27.54", "65.22.26.54"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "red" => TldInfo {
         servers: &["65.22.36.25", "65.22.39.25", "65.22.37.25"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "redstone" => TldInfo {
         servers: &["203.99.27.1", "203.99.26.1", "116.169.54.111"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "redumbrella" => TldInfo {
         servers: &["65.22.200.1", "65.22.201.1", "65.22.202.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "rehab" => TldInfo {
         servers: &["161.232.14.61", "65.22.30.61", "65.22.28.61"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "reise" => TldInfo {
         servers: &["161.232.14.63", "65.22.29.63", "65.22.28.63"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "reisen" => TldInfo {
         servers: &["65.22.28.54", "161.232.15.54", "161.232.14.54"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "reit" => TldInfo {
         servers: &["194.169.218.38", "212.18.248.38", "185.24.64.38"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "reliance" => TldInfo {
         servers: &["65.22.110.25", "65.22.111.25", "65.22.108.25"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ren" => TldInfo {
         servers: &["203.99.27.1", "116.169.54.111", "203.99.25.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "rent" => TldInfo {
         servers: &["212.18.248.63", "212.18.249.63", "194.169.218.63"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "rentals" => TldInfo {
         servers: &["65.22.34.14", "65.22.35.14", "161.232.17.14"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "repair" => TldInfo {
         servers: &["161.232.17.18", "65.22.32.18", "161.232.16.18"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "report" => TldInfo {
         servers: &["161.232.14.18", "65.22.28.18", "65.22.29.18"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "republican" => TldInfo {
         servers: &["161.232.10.32", "65.22.20.32", "65.22.22.32"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "rest" => TldInfo {
         servers: &["64.96.1.1", "64.96.2.1", "185.159.197.3"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "restaurant" => TldInfo {
         servers: &["161.232.15.49", "65.22.31.49", "65.22.28.49"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "review" => TldInfo {
         servers: &["37.209.192.10", "156.154.171.35", "156.154.169.35"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "reviews" => TldInfo {
         servers: &["161.232.17.12", "161.232.16.12", "65.22.35.12"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "rexroth" => TldInfo {
         servers: &["65.22.115.65", "65.22.113.65", "65.22.114.65"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "rich" => TldInfo {
         servers: &["65.22.138.17", "65.22.136.17", "65.22.139.17"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "richardli" => TldInfo {
         servers: &["65.22.136.33", "65.22.137.33", "65.22.138.33"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ricoh" => TldInfo {
         servers: &["37.209.194.4", "37.209.198.4", "37.209.192.4"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ril" => TldInfo {
         servers: &["65.22.183.17", "65.22.181.17", "65.22.182.17"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "rio" => TldInfo {
         servers: &["200.219.148.10", "200.192.233.10", "200.229.248.10"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "rip" => TldInfo {
         servers: &["65.22.34.22", "65.22.33.22", "65.22.35.22"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ro" => TldInfo {
         servers: &["192.162.16.20", "192.162.16.18", "194.0.11.113"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "rocks" => TldInfo {
         servers: &["65.22.35.35", "161.232.17.35", "65.22.34.35"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "rodeo" => TldInfo {
         servers: &["37.209.194.10", "37.209.196.10", "156.154.173.82"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "rogers" => TldInfo {
         servers: &["65.22.109.33", "65.22.111.33", "65.22.108.33"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "room" => TldInfo {
         servers: &["103.49.82.77", "156.154.101.3", "156.154.103.3"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "rs" => TldInfo {
         servers: &["204.61.216.32", "45.142.220.81", "195.178.32.2"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "rsvp" => TldInfo {
         servers: &["216.239.34.105", "216.239.60.105", "216.239.38.105"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ru" => TldInfo {
         servers: &["193.232.142.17", "194.190.124.17", "193.232.128.6"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy { idn: false, min_length: 2, ..LabelPolicy::DEFAULT },
     },
     "rugby" => TldInfo {
         servers: &["156.154.173.82", "37.209.192.10", "37.209.196.10"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ruhr" => TldInfo {
         servers: &["212.18.248.155", "185.24.64.155", "194.169.218.155"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "run" => TldInfo {
         servers: &["65.22.35.40", "65.22.33.40", "161.232.17.40"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "rw" => TldInfo {
         servers: &["77.72.229.254", "196.49.7.186", "204.61.216.91"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "rwe" => TldInfo {
         servers: &["65.22.110.18", "65.22.111.18", "161.232.32.18"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ryukyu" => TldInfo {
         servers: &["37.209.194.4", "37.209.192.4", "37.209.198.4"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "sa" => TldInfo {
         servers: &["86.51.28.37", "37.107.223.170", "86.111.192.9"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "saarland" => TldInfo {
         servers: &["185.24.64.97", "212.18.248.97", "194.169.218.97"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "safe" => TldInfo {
         servers: &["103.49.82.78", "213.248.222.78", "213.248.218.78"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "safety" => TldInfo {
         servers: &["156.154.145.148", "37.209.196.10", "37.209.192.10"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "sakura" => TldInfo {
         servers: &["65.22.40.137", "65.22.40.9", "117.104.133.18"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "sale" => TldInfo {
         servers: &["65.22.22.48", "161.232.11.48", "65.22.21.48"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "salon" => TldInfo {
         servers: &["65.22.29.58", "161.232.15.58", "65.22.30.58"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "samsclub" => TldInfo {
         servers: &["156.154.174.82", "37.209.192.9", "37.209.196.9"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "samsung" => TldInfo {
         servers: &["140.238.4.10", "23.2.10.1", "23.59.162.1"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "sandvik" => TldInfo {
         servers: &["156.154.174.82", "37.209.194.9", "37.209.192.9"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "sandvikcoromant" => TldInfo {
         servers: &["156.154.172.82", "37.209.196.9", "37.209.194.9"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "sanofi" => TldInfo {
         servers: &["65.22.114.67", "65.22.112.67", "65.22.115.67"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "sap" => TldInfo {
         servers: &["195.253.64.12", "195.253.65.11", "195.253.64.11"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "sarl" => TldInfo {
         servers: &["65.22.34.63", "65.22.32.63", "161.232.17.63"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "sas" => TldInfo {
         servers: &["37.209.194.9", "156.154.171.39", "156.154.169.39"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "save" => TldInfo {
         servers: &["156.154.103.3", "43.230.50.79", "156.154.102.3"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "saxo" => TldInfo {
         servers: &["65.22.108.27", "65.22.111.27", "161.232.32.27"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "sb" => TldInfo {
         servers: &["185.38.108.108", "185.28.194.194", "204.61.216.31"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "sbi" => TldInfo {
         servers: &["65.22.177.9", "65.22.176.9", "65.22.178.9"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "sbs" => TldInfo {
         servers: &["212.18.248.127", "194.169.218.127", "212.18.249.127"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "sc" => TldInfo {
         servers: &["199.249.116.1", "199.254.62.1", "41.86.56.214"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "scb" => TldInfo {
         servers: &["194.0.25.42", "194.58.198.136", "194.0.1.35"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "schaeffler" => TldInfo {
         servers: &["194.0.26.14", "194.0.25.34", "194.0.24.14"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "schmidt" => TldInfo {
         servers: &["156.154.173.82", "37.209.192.9", "156.154.172.82"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "scholarships" => TldInfo {
         servers: &["65.22.141.17", "65.22.142.17", "65.22.140.17"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "school" => TldInfo {
         servers: &["161.232.10.43", "65.22.22.43", "65.22.21.43"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "schule" => TldInfo {
         servers: &["161.232.11.68", "65.22.22.68", "65.22.21.68"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "schwarz" => TldInfo {
         servers: &["212.18.249.99", "194.169.218.99", "185.24.64.99"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "science" => TldInfo {
         servers: &["156.154.170.41", "37.209.192.10", "37.209.196.10"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "scot" => TldInfo {
         servers: &["195.253.64.12", "195.253.65.11", "195.253.65.12"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "sd" => TldInfo {
         servers: &["196.216.168.26", "193.0.9.109", "196.29.180.250"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "se" => TldInfo {
         servers: &["192.36.135.107", "192.36.144.107", "185.159.198.150"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: Some("whois.iis.se"),
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "search" => TldInfo {
         servers: &["216.239.38.105", "216.239.32.105", "216.239.36.105"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "seat" => TldInfo {
         servers: &["195.253.64.11", "195.253.65.11", "195.253.64.12"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "secure" => TldInfo {
         servers: &["156.154.100.3", "43.230.50.80", "103.49.82.80"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "security" => TldInfo {
         servers: &["212.18.248.68", "185.24.64.68", "194.169.218.68"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "seek" => TldInfo {
         servers: &["156.154.174.82", "156.154.173.82", "37.209.196.9"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "select" => TldInfo {
         servers: &["156.154.173.82", "156.154.174.82", "156.154.172.82"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "sener" => TldInfo {
         servers: &["161.232.33.19", "65.22.109.19", "65.22.110.19"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "services" => TldInfo {
         servers: &["161.232.11.37", "65.22.23.37", "65.22.22.37"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "seven" => TldInfo {
         servers: &["37.209.192.9", "156.154.174.82", "37.209.194.9"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "sew" => TldInfo {
         servers: &["65.22.190.25", "65.22.188.25", "65.22.191.25"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "sex" => TldInfo {
         servers: &["156.154.172.82", "156.154.173.82", "37.209.196.10"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["adult"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "sexy" => TldInfo {
         servers: &["64.96.1.1", "64.96.2.1", "185.159.197.3"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["adult"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "sfr" => TldInfo {
         servers: &["185.24.64.100", "194.169.218.100", "212.18.249.100"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "sg" => TldInfo {
         servers: &["204.61.216.57", "185.159.197.170", "45.142.220.65"],
         timeout_ms: 750,
         excluded_from_all: false,
         categories: &[],
+        whois: Some("whois.sgnic.sg"),
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "sh" => TldInfo {
         servers: &["65.22.160.9", "65.22.161.9", "65.22.163.9"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "shangrila" => TldInfo {
         servers: &["65.22.113.68", "65.22.115.68", "65.22.112.68"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "sharp" => TldInfo {
         servers: &["37.209.194.4", "37.209.192.4", "37.209.198.4"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "shell" => TldInfo {
         servers: &["156.154.103.3", "213.248.219.137", "43.230.51.137"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "shia" => TldInfo {
         servers: &["156.154.100.3", "213.248.221.153", "103.49.81.153"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["religious"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "shiksha" => TldInfo {
         servers: &["65.22.34.33", "65.22.32.33", "65.22.33.33"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "shoes" => TldInfo {
         servers: &["65.22.26.23", "65.22.27.23", "161.232.12.23"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "shop" => TldInfo {
         servers: &["37.209.198.4", "37.209.194.4", "37.209.192.4"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "shopping" => TldInfo {
         servers: &["65.22.33.19", "65.22.35.19", "161.232.16.19"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "shouji" => TldInfo {
         servers: &["103.61.63.1", "103.61.62.1", "103.61.61.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "show" => TldInfo {
         servers: &["65.22.29.43", "65.22.31.43", "161.232.14.43"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "si" => TldInfo {
         servers: &["185.159.197.11", "185.159.198.11", "194.0.25.22"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "silk" => TldInfo {
         servers: &["213.248.222.59", "156.154.103.3", "43.230.50.59"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "sina" => TldInfo {
         servers: &["65.22.140.33", "65.22.143.33", "65.22.141.33"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "singles" => TldInfo {
         servers: &["65.22.32.57", "161.232.16.57", "65.22.33.57"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "site" => TldInfo {
         servers: &["212.18.248.61", "194.169.218.61", "185.24.64.61"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: Some("whois.nic.site"),
+        rdap: Some("https://rdap.centralnic.com/site/"),
+        policy: LabelPolicy::DEFAULT,
     },
     "sj" => TldInfo {
         servers: &["158.38.0.181", "158.38.8.133", "193.75.4.22"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "sk" => TldInfo {
         servers: &["194.0.45.1", "84.245.96.1", "212.18.248.16"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ski" => TldInfo {
         servers: &["65.22.84.17", "65.22.86.17", "65.22.85.17"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "skin" => TldInfo {
         servers: &["212.18.249.118", "185.24.64.118", "194.169.218.118"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "sky" => TldInfo {
         servers: &["213.248.223.127", "213.248.219.127", "156.154.101.3"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "skype" => TldInfo {
         servers: &["103.49.83.132", "156.154.101.3", "156.154.100.3"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "sl" => TldInfo {
         servers: &["51.195.14.249", "45.83.41.38"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "sling" => TldInfo {
         servers: &["185.159.197.3", "64.96.1.1", "64.96.2.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "sm" => TldInfo {
         servers: &["194.0.27.12", "194.183.64.10", "193.0.9.110"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "smart" => TldInfo {
         servers: &["212.18.249.70", "194.169.218.70", "212.18.248.70"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "smile" => TldInfo {
         servers: &["156.154.102.3", "156.154.100.3", "156.154.101.3"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "sn" => TldInfo {
         servers: &["196.1.95.1", "13.39.116.127", "213.154.64.11"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "sncf" => TldInfo {
         servers: &["194.146.106.46", "194.0.36.1", "194.0.9.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "so" => TldInfo {
         servers: &["196.216.168.54", "204.61.216.101"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "soccer" => TldInfo {
         servers: &["65.22.34.58", "65.22.33.58", "65.22.35.58"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "social" => TldInfo {
         servers: &["65.22.20.41", "161.232.10.41", "65.22.21.41"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "softbank" => TldInfo {
         servers: &["37.209.198.4", "37.209.192.4", "37.209.194.4"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "software" => TldInfo {
         servers: &["65.22.33.10", "65.22.32.10", "65.22.35.10"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "sohu" => TldInfo {
         servers: &["116.169.54.111", "203.99.27.1", "203.99.25.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "solar" => TldInfo {
         servers: &["65.22.22.51", "65.22.21.51", "161.232.10.51"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "solutions" => TldInfo {
         servers: &["65.22.34.2", "65.22.32.2", "65.22.35.2"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "song" => TldInfo {
         servers: &["65.22.79.2", "161.232.38.2", "65.22.77.2"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "sony" => TldInfo {
         servers: &["37.209.192.4", "37.209.198.4", "37.209.194.4"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "soy" => TldInfo {
         servers: &["216.239.60.105", "216.239.38.105", "216.239.34.105"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "spa" => TldInfo {
         servers: &["65.22.49.17", "65.22.50.17", "65.22.51.17"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "space" => TldInfo {
         servers: &["212.18.248.51", "194.169.218.51", "185.24.64.51"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: Some("whois.nic.space"),
+        rdap: Some("https://rdap.centralnic.com/space/"),
+        policy: LabelPolicy::DEFAULT,
     },
     "sport" => TldInfo {
         servers: &["195.253.65.12", "195.253.64.11", "195.253.64.12"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "spot" => TldInfo {
         servers: &["103.49.82.82", "213.248.222.82", "213.248.218.82"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "sr" => TldInfo {
         servers: &["168.195.219.210", "168.195.216.214"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "srl" => TldInfo {
         servers: &["65.22.83.33", "65.22.82.33", "65.22.80.33"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ss" => TldInfo {
         servers: &["196.216.168.27", "204.61.216.130", "185.28.194.194"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "st" => TldInfo {
         servers: &["79.136.119.20", "34.218.33.231", "52.63.82.137"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "stada" => TldInfo {
         servers: &["65.22.156.25", "65.22.159.25", "65.22.158.25"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "staples" => TldInfo {
         servers: &["37.209.196.9", "156.154.170.79", "37.209.194.9"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "star" => TldInfo {
         servers: &["65.22.57.9", "65.22.56.9", "65.22.59.9"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "statebank" => TldInfo {
         servers: &["65.22.179.1", "65.22.177.1", "65.22.178.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "statefarm" => TldInfo {
         servers: &["156.154.145.154", "37.209.192.9", "156.154.144.154"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "stc" => TldInfo {
         servers: &["212.18.248.29", "194.169.218.29", "212.18.249.29"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "stcgroup" => TldInfo {
         servers: &["212.18.249.46", "185.24.64.46", "212.18.248.46"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "stockholm" => TldInfo {
         servers: &["65.22.223.41", "65.22.222.41", "65.22.221.41"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "storage" => TldInfo {
         servers: &["212.18.248.55", "194.169.218.55", "212.18.249.55"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "store" => TldInfo {
         servers: &["194.169.218.45", "212.18.249.45", "185.24.64.45"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: Some("whois.nic.store"),
+        rdap: Some("https://rdap.centralnic.com/store/"),
+        policy: LabelPolicy::DEFAULT,
     },
     "stream" => TldInfo {
         servers: &["37.209.196.10", "156.154.171.50", "37.209.194.10"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "studio" => TldInfo {
         servers: &["65.22.30.3", "65.22.28.3", "161.232.15.3"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "study" => TldInfo {
         servers: &["37.209.192.10", "156.154.172.82", "156.154.173.82"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "style" => TldInfo {
         servers: &["65.22.32.46", "65.22.35.46", "161.232.17.46"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "su" => TldInfo {
         servers: &["193.232.128.6", "194.190.124.17", "194.85.252.62"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "sucks" => TldInfo {
         servers: &["37.209.192.10", "156.154.174.82", "156.154.173.82"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["adult"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "supplies" => TldInfo {
         servers: &["65.22.22.59", "161.232.11.59", "65.22.20.59"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "supply" => TldInfo {
         servers: &["65.22.26.52", "65.22.27.52", "161.232.12.52"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "support" => TldInfo {
         servers: &["65.22.20.5", "65.22.22.5", "161.232.10.5"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "surf" => TldInfo {
         servers: &["37.209.192.10", "37.209.196.10", "156.154.173.82"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "surgery" => TldInfo {
         servers: &["65.22.26.31", "65.22.27.31", "161.232.13.31"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "suzuki" => TldInfo {
         servers: &["37.209.196.4", "37.209.192.4", "37.209.194.4"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "sv" => TldInfo {
         servers: &["200.160.0.5", "131.100.140.162", "200.0.68.10"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "swatch" => TldInfo {
         servers: &["156.154.101.3", "156.154.103.3", "103.49.83.139"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "swiss" => TldInfo {
         servers: &["195.253.64.12", "195.253.64.9", "195.253.65.12"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "sx" => TldInfo {
         servers: &["185.159.198.10", "45.142.220.101", "185.159.197.10"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "sy" => TldInfo {
         servers: &["204.61.216.71", "82.137.200.85", "193.0.9.113"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "sydney" => TldInfo {
         servers: &["156.154.174.82", "156.154.173.82", "156.154.172.82"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "systems" => TldInfo {
         servers: &["161.232.11.38", "65.22.21.38", "65.22.20.38"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "sz" => TldInfo {
         servers: &["41.77.232.4", "193.0.9.114", "147.28.0.39"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "tab" => TldInfo {
         servers: &["156.154.174.82", "37.209.196.9", "37.209.194.9"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "taipei" => TldInfo {
         servers: &["156.154.159.156", "37.209.196.10", "156.154.144.156"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "talk" => TldInfo {
         servers: &["156.154.101.3", "213.248.218.83", "156.154.100.3"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "taobao" => TldInfo {
         servers: &["65.22.54.17", "65.22.53.17", "65.22.55.17"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "target" => TldInfo {
         servers: &["37.209.196.9", "156.154.144.158", "156.154.145.158"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "tatamotors" => TldInfo {
         servers: &["65.22.113.69", "65.22.115.69", "65.22.112.69"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "tatar" => TldInfo {
         servers: &["194.190.124.17", "194.85.252.62", "193.232.128.6"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "tattoo" => TldInfo {
         servers: &["37.209.192.10", "156.154.172.82", "37.209.194.10"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "tax" => TldInfo {
         servers: &["65.22.24.16", "65.22.26.16", "65.22.25.16"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "taxi" => TldInfo {
         servers: &["65.22.20.21", "161.232.10.21", "65.22.21.21"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "tc" => TldInfo {
         servers: &["166.78.254.145", "31.169.81.225", "119.9.93.69"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "tci" => TldInfo {
         servers: &["156.154.103.3", "156.154.102.3", "213.248.217.153"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "td" => TldInfo {
         servers: &["185.38.108.108", "185.28.194.194", "196.216.168.31"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "tdk" => TldInfo {
         servers: &["156.154.144.159", "156.154.159.159", "156.154.145.159"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "team" => TldInfo {
         servers: &["161.232.16.37", "65.22.34.37", "161.232.17.37"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "tech" => TldInfo {
         servers: &["212.18.248.60", "212.18.249.60", "194.169.218.60"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: Some("whois.nic.tech"),
+        rdap: Some("https://rdap.centralnic.com/tech/"),
+        policy: LabelPolicy::DEFAULT,
     },
     "technology" => TldInfo {
         servers: &["65.22.28.37", "161.232.15.37", "65.22.30.37"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "tel" => TldInfo {
         servers: &["195.253.64.12", "195.253.65.11", "195.253.64.11"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "temasek" => TldInfo {
         servers: &["65.22.141.9", "65.22.143.9", "65.22.142.9"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "tennis" => TldInfo {
         servers: &["65.22.31.32", "161.232.14.32", "161.232.15.32"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "teva" => TldInfo {
         servers: &["156.154.159.160", "37.209.194.9", "37.209.192.9"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "tf" => TldInfo {
         servers: &["194.0.36.1", "194.0.9.1", "194.146.106.46"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "tg" => TldInfo {
         servers: &["198.73.186.1", "188.165.33.42", "41.207.188.36"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "th" => TldInfo {
         servers: &["194.146.106.154", "122.155.23.64", "202.28.0.1"],
         timeout_ms: 750,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "thd" => TldInfo {
         servers: &["65.22.193.9", "65.22.195.9", "65.22.192.9"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "theater" => TldInfo {
         servers: &["65.22.20.64", "65.22.22.64", "161.232.11.64"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "theatre" => TldInfo {
         servers: &["194.169.218.69", "212.18.248.69", "185.24.64.69"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "tiaa" => TldInfo {
         servers: &["161.232.33.7", "161.232.32.7", "65.22.110.7"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "tickets" => TldInfo {
         servers: &["212.18.248.58", "194.169.218.58", "185.24.64.58"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "tienda" => TldInfo {
         servers: &["161.232.17.29", "65.22.34.29", "65.22.33.29"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "tips" => TldInfo {
         servers: &["65.22.27.5", "161.232.13.5", "65.22.25.5"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "tires" => TldInfo {
         servers: &["65.22.27.64", "65.22.26.64", "161.232.13.64"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "tirol" => TldInfo {
         servers: &["157.90.205.138", "193.56.204.111", "194.0.11.114"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "tj" => TldInfo {
         servers: &["193.0.9.117", "193.111.11.4", "91.218.160.197"],
         timeout_ms: 750,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "tjmaxx" => TldInfo {
         servers: &["37.209.194.9", "37.209.196.9", "156.154.145.161"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "tjx" => TldInfo {
         servers: &["37.209.194.9", "156.154.145.162", "156.154.159.162"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "tk" => TldInfo {
         servers: &["194.0.38.1", "194.0.39.1", "194.0.40.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "tkmaxx" => TldInfo {
         servers: &["37.209.194.9", "37.209.196.9", "156.154.145.163"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "tl" => TldInfo {
         servers: &["185.38.108.108", "204.61.216.19", "185.28.194.194"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "tm" => TldInfo {
         servers: &["91.208.95.22", "194.0.2.22", "74.116.179.22"],
         timeout_ms: 750,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "tmall" => TldInfo {
         servers: &["65.22.52.9", "65.22.54.9", "65.22.53.9"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "tn" => TldInfo {
         servers: &["41.228.62.63", "147.28.0.39", "196.216.168.25"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "to" => TldInfo {
         servers: &["64.78.205.1", "64.96.1.1", "64.78.204.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "today" => TldInfo {
         servers: &["65.22.31.35", "161.232.14.35", "65.22.28.35"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "tokyo" => TldInfo {
         servers: &["37.209.198.4", "37.209.192.4", "37.209.194.4"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "tools" => TldInfo {
         servers: &["65.22.29.44", "65.22.28.44", "65.22.31.44"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "top" => TldInfo {
         servers: &["203.99.27.1", "203.99.26.1", "203.99.24.1"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "toray" => TldInfo {
         servers: &["37.209.192.4", "37.209.194.4", "37.209.196.4"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "toshiba" => TldInfo {
         servers: &["37.209.198.4", "37.209.194.4", "37.209.192.4"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "total" => TldInfo {
         servers: &["194.146.106.46", "194.0.36.1", "194.0.9.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "tours" => TldInfo {
         servers: &["65.22.26.47", "65.22.25.47", "161.232.12.47"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "town" => TldInfo {
         servers: &["65.22.28.55", "161.232.14.55", "65.22.30.55"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "toyota" => TldInfo {
         servers: &["37.209.192.4", "37.209.194.4", "37.209.198.4"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "toys" => TldInfo {
         servers: &["161.232.11.23", "65.22.20.23", "65.22.22.23"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "tr" => TldInfo {
         servers: &["31.145.253.159", "185.7.0.5", "40.68.114.66"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "trade" => TldInfo {
         servers: &["37.209.196.10", "156.154.144.165", "37.209.192.10"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "trading" => TldInfo {
         servers: &["161.232.16.66", "65.22.35.66", "65.22.32.66"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "training" => TldInfo {
         servers: &["65.22.25.41", "65.22.27.41", "161.232.13.41"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "travel" => TldInfo {
         servers: &["161.232.15.8", "161.232.14.8", "65.22.31.8"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "travelers" => TldInfo {
         servers: &["65.22.200.9", "65.22.203.9", "65.22.202.9"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "travelersinsurance" => TldInfo {
         servers: &["65.22.200.17", "65.22.203.17", "65.22.202.17"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "trust" => TldInfo {
         servers: &["64.96.2.1", "185.159.198.3", "64.96.1.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "trv" => TldInfo {
         servers: &["65.22.200.25", "65.22.203.25", "65.22.202.25"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "tt" => TldInfo {
         servers: &["193.0.9.50", "200.0.68.10", "204.61.216.63"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "tube" => TldInfo {
         servers: &["195.253.64.12", "195.253.65.12", "195.253.64.11"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["adult"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "tui" => TldInfo {
         servers: &["212.18.249.103", "194.169.218.103", "185.24.64.103"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "tunes" => TldInfo {
         servers: &["43.230.50.84", "213.248.222.84", "156.154.100.3"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "tushu" => TldInfo {
         servers: &["156.154.100.3", "213.248.222.85", "156.154.103.3"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "tv" => TldInfo {
         servers: &["37.209.196.6", "37.209.194.6", "37.209.198.6"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: Some("whois.nic.tv"),
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "tvs" => TldInfo {
         servers: &["65.22.207.9", "65.22.205.9", "65.22.206.9"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "tw" => TldInfo {
         servers: &["204.61.216.119", "60.199.165.186", "203.73.24.25"],
         timeout_ms: 750,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "tz" => TldInfo {
         servers: &["196.216.162.67", "185.43.134.11", "147.28.0.39"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ua" => TldInfo {
         servers: &["194.58.197.4", "195.47.253.1", "193.46.128.10"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ubank" => TldInfo {
         servers: &["65.22.112.70", "65.22.113.70", "65.22.115.70"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ubs" => TldInfo {
         servers: &["65.22.114.71", "65.22.113.71", "65.22.115.71"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ug" => TldInfo {
         servers: &["212.88.97.132", "199.4.138.53", "204.61.216.60"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "uk" => TldInfo {
         servers: &["156.154.103.3", "103.49.80.1", "43.230.48.1"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: Some("whois.nic.uk"),
+        rdap: Some("https://rdap.nominet.uk/uk/"),
+        policy: LabelPolicy::DEFAULT,
     },
     "unicom" => TldInfo {
         servers: &["203.99.25.1", "203.99.26.1", "116.169.54.111"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "university" => TldInfo {
         servers: &["161.232.10.19", "65.22.21.19", "65.22.22.19"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "uno" => TldInfo {
         servers: &["185.24.64.21", "212.18.249.21", "194.169.218.21"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "uol" => TldInfo {
         servers: &["200.192.233.10", "200.219.148.10", "200.229.248.10"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ups" => TldInfo {
         servers: &["65.22.241.1", "65.22.243.1", "65.22.240.1"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "us" => TldInfo {
         servers: &["37.209.194.15", "204.74.107.1", "209.173.58.70"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: Some("whois.nic.us"),
+        rdap: None,
+        policy: LabelPolicy { idn: false, ..LabelPolicy::DEFAULT },
     },
     "uy" => TldInfo {
         servers: &["164.73.128.70", "200.160.0.5", "65.22.41.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "uz" => TldInfo {
         servers: &["72.167.34.137", "83.221.179.222", "83.69.136.139"],
         timeout_ms: 750,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "va" => TldInfo {
         servers: &["212.77.2.58", "193.201.40.6", "212.77.0.2"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "vacations" => TldInfo {
         servers: &["161.232.11.58", "65.22.21.58", "65.22.20.58"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "vana" => TldInfo {
         servers: &["64.96.2.1", "185.159.198.3", "185.159.197.3"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "vanguard" => TldInfo {
         servers: &["65.22.112.72", "65.22.114.72", "65.22.115.72"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "vc" => TldInfo {
         servers: &["199.249.124.1", "199.249.116.1", "199.254.59.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ve" => TldInfo {
         servers: &["190.9.129.56", "200.0.68.10", "200.7.5.14"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "vegas" => TldInfo {
         servers: &["65.22.71.17", "65.22.69.17", "65.22.70.17"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private", "gambling"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ventures" => TldInfo {
         servers: &["65.22.26.11", "65.22.24.11", "161.232.12.11"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "verisign" => TldInfo {
         servers: &["192.42.175.30", "192.42.174.30", "192.42.173.30"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "versicherung" => TldInfo {
         servers: &["194.0.25.13", "194.0.26.2", "194.0.24.2"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "vet" => TldInfo {
         servers: &["65.22.32.49", "65.22.33.49", "65.22.35.49"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "vg" => TldInfo {
         servers: &["185.24.64.104", "212.18.248.104", "194.169.218.104"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "vi" => TldInfo {
         servers: &["185.17.236.230", "204.61.216.133"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "viajes" => TldInfo {
         servers: &["65.22.26.32", "161.232.13.32", "65.22.27.32"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "video" => TldInfo {
         servers: &["161.232.13.40", "65.22.27.40", "65.22.25.40"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "vig" => TldInfo {
         servers: &["65.22.49.25", "65.22.50.25", "65.22.48.25"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "viking" => TldInfo {
         servers: &["65.22.193.17", "65.22.194.17", "65.22.192.17"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "villas" => TldInfo {
         servers: &["161.232.15.62", "65.22.28.62", "65.22.31.62"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "vin" => TldInfo {
         servers: &["65.22.28.21", "161.232.15.21", "161.232.14.21"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "vip" => TldInfo {
         servers: &["37.209.196.10", "156.154.172.82", "156.154.174.82"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "virgin" => TldInfo {
         servers: &["156.154.102.3", "156.154.103.3", "103.49.83.40"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "visa" => TldInfo {
         servers: &["65.22.111.20", "161.232.33.20", "65.22.109.20"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "vision" => TldInfo {
         servers: &["65.22.27.15", "65.22.26.15", "65.22.24.15"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "viva" => TldInfo {
         servers: &["212.18.249.28", "212.18.248.28", "194.169.218.28"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "vivo" => TldInfo {
         servers: &["37.209.192.9", "37.209.196.9", "156.154.169.57"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "vlaanderen" => TldInfo {
         servers: &["194.0.25.19", "194.0.44.1", "194.0.6.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "vn" => TldInfo {
         servers: &["204.61.216.115", "203.119.68.105", "203.119.60.105"],
         timeout_ms: 750,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "vodka" => TldInfo {
         servers: &["37.209.196.10", "37.209.192.10", "37.209.194.10"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["controlled"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "volvo" => TldInfo {
         servers: &["65.22.114.73", "65.22.112.73", "65.22.115.73"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "vote" => TldInfo {
         servers: &["65.22.171.9", "65.22.169.9", "65.22.168.9"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "voting" => TldInfo {
         servers: &["156.154.173.82", "37.209.192.10", "156.154.174.82"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "voto" => TldInfo {
         servers: &["65.22.170.1", "65.22.169.1", "65.22.171.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "voyage" => TldInfo {
         servers: &["65.22.34.28", "65.22.35.28", "161.232.16.28"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "vu" => TldInfo {
         servers: &["37.209.196.6", "37.209.198.6", "37.209.192.6"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "wales" => TldInfo {
         servers: &["103.49.83.2", "43.230.51.2", "156.154.103.3"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "walmart" => TldInfo {
         servers: &["37.209.192.9", "156.154.174.82", "37.209.196.9"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "walter" => TldInfo {
         servers: &["37.209.192.9", "37.209.196.9", "156.154.174.82"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "wang" => TldInfo {
         servers: &["203.99.27.1", "203.99.24.1", "223.72.199.37"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "wanggou" => TldInfo {
         servers: &["213.248.218.86", "156.154.100.3", "103.49.82.86"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "watch" => TldInfo {
         servers: &["161.232.16.48", "65.22.32.48", "161.232.17.48"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "watches" => TldInfo {
         servers: &["65.22.205.1", "65.22.206.1", "65.22.204.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "weather" => TldInfo {
         servers: &["37.209.194.9", "37.209.192.9", "156.154.156.178"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "weatherchannel" => TldInfo {
         servers: &["37.209.194.9", "156.154.158.179", "37.209.192.9"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "webcam" => TldInfo {
         servers: &["156.154.170.60", "37.209.196.10", "156.154.169.60"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["adult"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "weber" => TldInfo {
         servers: &["65.22.110.21", "161.232.32.21", "65.22.108.21"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "website" => TldInfo {
         servers: &["194.169.218.52", "212.18.249.52", "212.18.248.52"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: Some("whois.nic.website"),
+        rdap: Some("https://rdap.centralnic.com/website/"),
+        policy: LabelPolicy::DEFAULT,
     },
     "wed" => TldInfo {
         servers: &["156.154.101.3", "213.248.221.153", "43.230.49.153"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "wedding" => TldInfo {
         servers: &["37.209.196.10", "156.154.173.82", "156.154.172.82"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "weibo" => TldInfo {
         servers: &["65.22.56.41", "65.22.57.41", "65.22.59.41"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "weir" => TldInfo {
         servers: &["65.22.114.75", "65.22.112.75", "65.22.115.75"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "wf" => TldInfo {
         servers: &["194.0.36.1", "194.0.9.1", "194.146.106.46"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "whoswho" => TldInfo {
         servers: &["195.253.64.12", "195.253.64.11", "195.253.65.11"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "wien" => TldInfo {
         servers: &["157.90.205.138", "194.0.11.114", "193.56.204.111"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "wiki" => TldInfo {
         servers: &["37.209.194.10", "156.154.173.82", "37.209.196.10"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "williamhill" => TldInfo {
         servers: &["156.154.145.181", "156.154.144.181", "156.154.159.181"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "win" => TldInfo {
         servers: &["37.209.194.10", "37.209.192.10", "156.154.145.182"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "windows" => TldInfo {
         servers: &["103.49.83.133", "156.154.101.3", "213.248.219.133"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "wine" => TldInfo {
         servers: &["65.22.34.11", "161.232.17.11", "65.22.35.11"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["controlled"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "winners" => TldInfo {
         servers: &["37.209.194.9", "156.154.144.183", "37.209.196.9"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "wme" => TldInfo {
         servers: &["212.18.248.31", "212.18.249.31", "194.169.218.31"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "wolterskluwer" => TldInfo {
         servers: &["65.22.207.25", "65.22.206.25", "65.22.204.25"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "woodside" => TldInfo {
         servers: &["37.209.192.9", "156.154.174.82", "156.154.173.82"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "work" => TldInfo {
         servers: &["37.209.196.10", "156.154.172.82", "156.154.173.82"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "works" => TldInfo {
         servers: &["65.22.26.6", "161.232.12.6", "65.22.24.6"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "world" => TldInfo {
         servers: &["65.22.25.1", "161.232.12.1", "65.22.27.1"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "wow" => TldInfo {
         servers: &["213.248.222.87", "156.154.102.3", "213.248.218.87"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ws" => TldInfo {
         servers: &["64.70.78.80", "64.70.19.80", "194.146.106.146"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "wtc" => TldInfo {
         servers: &["156.154.172.82", "37.209.192.9", "156.154.173.82"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "wtf" => TldInfo {
         servers: &["65.22.23.7", "161.232.11.7", "65.22.20.7"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xbox" => TldInfo {
         servers: &["156.154.100.3", "156.154.102.3", "156.154.103.3"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xerox" => TldInfo {
         servers: &["37.209.194.9", "156.154.174.82", "156.154.173.82"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xihuan" => TldInfo {
         servers: &["103.61.60.1", "103.61.61.1", "103.61.63.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xin" => TldInfo {
         servers: &["65.22.131.17", "65.22.129.17", "65.22.128.17"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--11b4c3d" => TldInfo {
         servers: &["192.42.176.30", "192.42.173.30", "192.42.174.30"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--1ck2e1b" => TldInfo {
         servers: &["161.232.39.3", "65.22.78.3", "65.22.79.3"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--1qqw23a" => TldInfo {
         servers: &["42.83.131.1", "42.83.133.1", "42.83.130.1"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--2scrj9c" => TldInfo {
         servers: &["64.78.205.1", "64.78.204.1", "64.96.2.1"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--30rr7y" => TldInfo {
         servers: &["203.99.26.1", "223.72.199.37", "203.99.27.1"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--3bst00m" => TldInfo {
         servers: &["203.99.24.1", "203.99.27.1", "116.169.54.111"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--3ds443g" => TldInfo {
         servers: &["103.61.60.1", "103.61.63.1", "103.61.62.1"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--3e0b707e" => TldInfo {
         servers: &["210.101.60.1", "210.101.62.1", "202.30.124.100"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--3hcrj9c" => TldInfo {
         servers: &["64.96.2.1", "64.78.204.1", "64.78.205.1"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--3pxu8k" => TldInfo {
         servers: &["192.42.176.30", "192.42.175.30", "192.42.173.30"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--42c2d9a" => TldInfo {
         servers: &["192.42.173.30", "192.42.174.30", "192.42.176.30"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--45br5cyl" => TldInfo {
         servers: &["64.78.204.1", "64.96.2.1", "64.78.205.1"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--45brj9c" => TldInfo {
         servers: &["64.96.1.1", "64.96.2.1", "64.78.205.1"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--45q11c" => TldInfo {
         servers: &["203.99.24.1", "203.99.25.1", "203.99.26.1"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--4dbrk0ce" => TldInfo {
         servers: &["192.115.7.53", "192.115.4.235", "128.139.35.5"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--4gbrim" => TldInfo {
         servers: &["194.169.218.144", "212.18.248.144", "212.18.249.144"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--54b7fta0cc" => TldInfo {
         servers: &["123.49.50.51", "180.211.212.213", "204.61.216.108"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--55qw42g" => TldInfo {
         servers: &["111.235.164.1", "111.235.162.1", "111.235.163.1"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--55qx5d" => TldInfo {
         servers: &["125.208.42.1", "125.208.44.1", "125.208.41.1"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--5su34j936bgsg" => TldInfo {
         servers: &["192.42.176.30", "192.42.175.30", "192.42.174.30"],
         timeout_ms: 1000,
         excluded_from_all: true,
-        categories: &["non_ascii"],
+        categories: &["non_ascii", "private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--5tzm5g" => TldInfo {
         servers: &["65.22.17.9", "65.22.19.9", "65.22.16.9"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--6frz82g" => TldInfo {
         servers: &["65.22.25.9", "65.22.27.9", "65.22.24.9"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--6qq986b3xl" => TldInfo {
         servers: &["203.99.27.1", "203.99.25.1", "203.99.24.1"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--80adxhks" => TldInfo {
         servers: &["195.253.65.6", "89.111.135.1", "31.177.70.1"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--80ao21a" => TldInfo {
         servers: &["194.0.21.5", "185.79.212.7", "204.61.216.143"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--80aqecdr1a" => TldInfo {
         servers: &["37.209.196.9", "156.154.172.82", "37.209.194.9"],
         timeout_ms: 1000,
         excluded_from_all: true,
-        categories: &["non_ascii"],
+        categories: &["non_ascii", "religious"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--80asehdb" => TldInfo {
         servers: &["195.253.64.11", "195.253.65.12", "195.253.65.11"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--80aswg" => TldInfo {
         servers: &["195.253.65.11", "195.253.64.12", "195.253.64.11"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--8y0a063a" => TldInfo {
         servers: &["203.99.26.1", "203.99.27.1", "116.169.54.111"],
         timeout_ms: 1000,
         excluded_from_all: true,
-        categories: &["non_ascii"],
+        categories: &["non_ascii", "private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--90a3ac" => TldInfo {
         servers: &["91.199.17.59", "45.142.220.81", "204.61.216.32"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--90ae" => TldInfo {
         servers: &["192.92.129.99", "193.68.99.99", "204.61.216.110"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--90ais" => TldInfo {
         servers: &["185.98.83.4", "93.125.25.72", "31.44.5.245"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--9dbq2a" => TldInfo {
         servers: &["192.42.175.30", "192.42.176.30", "192.42.173.30"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--9et52u" => TldInfo {
         servers: &["203.99.27.1", "203.99.24.1", "203.99.25.1"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--9krt00a" => TldInfo {
         servers: &["65.22.115.1", "65.22.112.1", "65.22.114.1"],
         timeout_ms: 1000,
         excluded_from_all: true,
-        categories: &["non_ascii"],
+        categories: &["non_ascii", "private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--b4w605ferd" => TldInfo {
         servers: &["65.22.143.1", "65.22.141.1", "65.22.142.1"],
         timeout_ms: 1000,
         excluded_from_all: true,
-        categories: &["non_ascii"],
+        categories: &["non_ascii", "private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--bck1b9a5dre4c" => TldInfo {
         servers: &["65.22.77.4", "161.232.38.4", "65.22.78.4"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--c1avg" => TldInfo {
         servers: &["65.22.186.25", "65.22.187.25", "65.22.184.25"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--c2br7g" => TldInfo {
         servers: &["192.42.174.30", "192.42.175.30", "192.42.176.30"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--cck2b3b" => TldInfo {
         servers: &["65.22.78.5", "65.22.79.5", "161.232.38.5"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--cckwcxetd" => TldInfo {
         servers: &["213.248.222.92", "156.154.101.3", "103.49.82.92"],
         timeout_ms: 1000,
         excluded_from_all: true,
-        categories: &["non_ascii"],
+        categories: &["non_ascii", "private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--cg4bki" => TldInfo {
         servers: &["23.59.162.1", "184.29.154.1", "23.2.10.1"],
         timeout_ms: 1000,
         excluded_from_all: true,
-        categories: &["non_ascii"],
+        categories: &["non_ascii", "private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--clchc0ea0b2g2a9gcd" => TldInfo {
         servers: &["45.142.220.65", "202.12.31.53", "204.61.216.57"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--czr694b" => TldInfo {
         servers: &["203.99.27.1", "223.72.199.37", "203.99.26.1"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--czrs0t" => TldInfo {
         servers: &["65.22.31.64", "161.232.14.64", "65.22.29.64"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--czru2d" => TldInfo {
         servers: &["203.99.26.1", "203.99.27.1", "203.99.25.1"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--d1acj3b" => TldInfo {
         servers: &["193.232.128.6", "193.232.142.17", "193.232.156.17"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--d1alf" => TldInfo {
         servers: &["78.104.145.4", "185.43.134.12", "194.149.137.168"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--e1a4c" => TldInfo {
         servers: &["194.146.106.90", "149.38.1.26", "193.2.221.62"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--eckvdtc9d" => TldInfo {
         servers: &["161.232.38.6", "161.232.39.6", "65.22.77.6"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--efvy88h" => TldInfo {
         servers: &["223.72.199.37", "203.99.26.1", "203.99.25.1"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--fct429k" => TldInfo {
         servers: &["161.232.39.7", "65.22.79.7", "161.232.38.7"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--fhbei" => TldInfo {
         servers: &["192.42.176.30", "192.42.174.30", "192.42.173.30"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--fiq228c5hs" => TldInfo {
         servers: &["103.61.62.1", "103.61.63.1", "103.61.60.1"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--fiq64b" => TldInfo {
         servers: &["223.72.199.37", "203.99.24.1", "203.99.27.1"],
         timeout_ms: 1000,
         excluded_from_all: true,
-        categories: &["non_ascii"],
+        categories: &["non_ascii", "private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--fiqs8s" => TldInfo {
         servers: &["125.208.35.1", "125.208.34.1", "125.208.32.1"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--fiqz9s" => TldInfo {
         servers: &["125.208.32.1", "125.208.36.1", "125.208.34.1"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--fjq720a" => TldInfo {
         servers: &["161.232.11.66", "161.232.10.66", "65.22.20.66"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--flw351e" => TldInfo {
         servers: &["216.239.36.105", "216.239.32.105", "216.239.34.105"],
         timeout_ms: 1000,
         excluded_from_all: true,
-        categories: &["non_ascii"],
+        categories: &["non_ascii", "private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--fpcrj9c3d" => TldInfo {
         servers: &["64.78.204.1", "64.96.1.1", "64.78.205.1"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--fzc2c9e2c" => TldInfo {
         servers: &["123.231.6.18", "203.143.29.3", "204.61.216.27"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--fzys8d69uvgm" => TldInfo {
         servers: &["65.22.173.33", "65.22.172.33", "65.22.175.33"],
         timeout_ms: 1000,
         excluded_from_all: true,
-        categories: &["non_ascii"],
+        categories: &["non_ascii", "private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--g2xx48c" => TldInfo {
         servers: &["156.154.169.68", "156.154.170.68", "37.209.194.10"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--gckr3f0f" => TldInfo {
         servers: &["65.22.78.8", "161.232.38.8", "65.22.79.8"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--gecrj9c" => TldInfo {
         servers: &["64.78.205.1", "64.96.1.1", "64.96.2.1"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--gk3at1e" => TldInfo {
         servers: &["65.22.78.9", "65.22.76.9", "65.22.77.9"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--h2breg3eve" => TldInfo {
         servers: &["64.78.205.1", "64.78.204.1", "64.96.2.1"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--h2brj9c" => TldInfo {
         servers: &["64.78.205.1", "64.78.204.1", "64.96.2.1"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--h2brj9c8c" => TldInfo {
         servers: &["64.96.1.1", "64.78.204.1", "64.96.2.1"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--hxt814e" => TldInfo {
         servers: &["116.169.54.111", "203.99.24.1", "203.99.27.1"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--i1b6b1a6a2e" => TldInfo {
         servers: &["65.22.187.1", "65.22.185.1", "65.22.186.1"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--imr513n" => TldInfo {
         servers: &["116.169.54.111", "203.99.27.1", "203.99.25.1"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--io0a7i" => TldInfo {
         servers: &["125.208.40.1", "125.208.44.1", "125.208.41.1"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--j1aef" => TldInfo {
         servers: &["192.42.173.30", "192.42.174.30", "192.42.176.30"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--j1amh" => TldInfo {
         servers: &["51.222.128.197", "91.231.86.238", "194.58.197.4"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--j6w193g" => TldInfo {
         servers: &["204.61.216.46", "203.119.2.218", "125.208.49.10"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--jlq480n2rg" => TldInfo {
         servers: &["156.154.100.3", "156.154.102.3", "213.248.222.91"],
         timeout_ms: 1000,
         excluded_from_all: true,
-        categories: &["non_ascii"],
+        categories: &["non_ascii", "private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--jvr189m" => TldInfo {
         servers: &["161.232.38.10", "65.22.78.10", "65.22.79.10"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--kcrx77d1x4a" => TldInfo {
         servers: &["156.154.173.82", "37.209.196.9", "156.154.172.82"],
         timeout_ms: 1000,
         excluded_from_all: true,
-        categories: &["non_ascii"],
+        categories: &["non_ascii", "private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--kprw13d" => TldInfo {
         servers: &["34.141.111.176", "40.65.125.222", "202.12.31.53"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--kpry57d" => TldInfo {
         servers: &["211.20.231.11", "204.61.216.119", "60.199.165.186"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--kput3i" => TldInfo {
         servers: &["103.61.60.1", "103.61.61.1", "103.61.63.1"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--l1acc" => TldInfo {
         servers: &["202.131.1.4", "202.131.4.12"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--lgbbat1ad8j" => TldInfo {
         servers: &["213.179.160.68", "193.194.64.243"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--mgb9awbf" => TldInfo {
         servers: &["37.209.198.6", "37.209.192.6", "185.27.88.50"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--mgba3a3ejt" => TldInfo {
         servers: &["156.154.158.15", "37.209.194.9", "37.209.196.9"],
         timeout_ms: 1000,
         excluded_from_all: true,
-        categories: &["non_ascii"],
+        categories: &["non_ascii", "private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--mgba3a4f16a" => TldInfo {
         servers: &["193.189.123.2", "193.189.122.83"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--mgba7c0bbn0a" => TldInfo {
         servers: &["156.154.172.82", "37.209.194.9", "156.154.173.82"],
         timeout_ms: 1000,
         excluded_from_all: true,
-        categories: &["non_ascii"],
+        categories: &["non_ascii", "private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--mgbaam7a8h" => TldInfo {
         servers: &["79.98.121.73", "79.98.120.73", "199.4.137.1"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--mgbab2bd" => TldInfo {
         servers: &["195.253.65.12", "195.253.64.11", "195.253.65.11"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--mgbah1a3hjkrd" => TldInfo {
         servers: &["193.146.150.193", "196.216.168.53", "194.0.9.1"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--mgbai9azgqp6j" => TldInfo {
         servers: &["202.83.164.167", "175.107.198.150", "202.83.164.166"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--mgbayh7gpa" => TldInfo {
         servers: &["54.155.28.251", "193.0.9.83", "193.188.66.2"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--mgbbh1a" => TldInfo {
         servers: &["64.78.204.1", "64.96.1.1", "64.78.205.1"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--mgbbh1a71e" => TldInfo {
         servers: &["64.78.205.1", "64.96.1.1", "64.78.204.1"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--mgbc0a9azcg" => TldInfo {
         servers: &["81.192.171.116", "194.0.9.1", "81.192.171.115"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--mgbca7dzdo" => TldInfo {
         servers: &["37.209.194.10", "37.209.198.10", "37.209.196.10"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--mgbcpq6gpa1a" => TldInfo {
         servers: &["185.24.64.115", "212.18.249.115", "212.18.248.115"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--mgberp4a5d4ar" => TldInfo {
         servers: &["213.236.36.92", "86.111.196.9", "194.146.106.102"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--mgbgu82a" => TldInfo {
         servers: &["64.96.1.1", "64.96.2.1", "64.78.205.1"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--mgbi4ecexp" => TldInfo {
         servers: &["156.154.172.82", "156.154.173.82", "37.209.194.9"],
         timeout_ms: 1000,
         excluded_from_all: true,
-        categories: &["non_ascii"],
+        categories: &["non_ascii", "religious"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--mgbpl2fh" => TldInfo {
         servers: &["204.61.216.97"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--mgbt3dhd" => TldInfo {
         servers: &["103.49.81.153", "213.248.217.153", "156.154.100.3"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--mgbtx2b" => TldInfo {
         servers: &["64.96.2.1", "194.117.58.42", "194.117.57.105"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--mgbx4cd0ab" => TldInfo {
         servers: &["212.18.249.114", "185.24.64.114", "103.44.108.53"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--mix891f" => TldInfo {
         servers: &["137.189.6.21", "194.0.1.17", "202.175.87.49"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--mk1bu44c" => TldInfo {
         servers: &["192.42.174.30", "192.42.173.30", "192.42.176.30"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--mxtq1m" => TldInfo {
         servers: &["203.73.24.25", "202.169.175.130", "60.199.165.185"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--ngbc5azd" => TldInfo {
         servers: &["156.154.172.82", "37.209.196.3", "37.209.192.3"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--ngbe9e0a" => TldInfo {
         servers: &["212.18.248.22", "212.18.249.22", "194.169.218.22"],
         timeout_ms: 1000,
         excluded_from_all: true,
-        categories: &["non_ascii"],
+        categories: &["non_ascii", "private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--ngbrx" => TldInfo {
         servers: &["37.209.192.10", "37.209.194.10", "37.209.198.10"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--node" => TldInfo {
         servers: &["188.93.94.2", "204.61.216.88"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--nqv7f" => TldInfo {
         servers: &["65.22.187.17", "65.22.185.17", "65.22.186.17"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--nqv7fs00ema" => TldInfo {
         servers: &["65.22.184.9", "65.22.185.9", "65.22.186.9"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--nyqy26a" => TldInfo {
         servers: &["103.61.60.1", "103.61.61.1", "103.61.62.1"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--o3cw4h" => TldInfo {
         servers: &["194.146.106.154", "204.61.216.126", "122.155.23.64"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--ogbpf8fl" => TldInfo {
         servers: &["204.61.216.71", "82.137.200.85", "193.0.9.113"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--otu796d" => TldInfo {
         servers: &["203.99.25.1", "203.99.26.1", "203.99.24.1"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--p1acf" => TldInfo {
         servers: &["72.0.49.2", "185.28.194.194", "72.42.113.2"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--p1ai" => TldInfo {
         servers: &["193.232.128.6", "194.190.124.17", "194.85.252.62"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy { min_length: 2, ..LabelPolicy::DEFAULT },
     },
     "xn--pgbs0dh" => TldInfo {
         servers: &["147.28.0.39", "204.61.216.94", "41.228.62.63"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--pssy2u" => TldInfo {
         servers: &["192.42.173.30", "192.42.174.30", "192.42.175.30"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--q7ce6a" => TldInfo {
         servers: &["194.169.218.17", "212.18.248.17", "185.24.64.17"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--q9jyb4c" => TldInfo {
         servers: &["216.239.36.105", "216.239.34.105", "216.239.32.105"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--qcka1pmc" => TldInfo {
         servers: &["216.239.60.105", "216.239.36.105", "216.239.32.105"],
         timeout_ms: 1000,
         excluded_from_all: true,
-        categories: &["non_ascii"],
+        categories: &["non_ascii", "private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--qxa6a" => TldInfo {
         servers: &["194.146.106.90", "149.38.1.26", "194.0.25.28"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--qxam" => TldInfo {
         servers: &["78.104.145.227", "194.0.11.102", "194.0.1.25"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--rhqv96g" => TldInfo {
         servers: &["103.61.61.1", "103.61.60.1", "103.61.63.1"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--rovu88b" => TldInfo {
         servers: &["161.232.38.11", "65.22.76.11", "65.22.78.11"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--rvc1e0am3e" => TldInfo {
         servers: &["64.78.205.1", "64.96.2.1", "64.96.1.1"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--s9brj9c" => TldInfo {
         servers: &["64.96.2.1", "64.78.205.1", "64.96.1.1"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--ses554g" => TldInfo {
         servers: &["203.99.27.1", "203.99.25.1", "203.99.26.1"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--t60b56a" => TldInfo {
         servers: &["192.42.173.30", "192.42.174.30", "192.42.176.30"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--tckwe" => TldInfo {
         servers: &["192.42.176.30", "192.42.174.30", "192.42.173.30"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--tiq49xqyj" => TldInfo {
         servers: &["156.154.173.82", "37.209.196.9", "37.209.194.9"],
         timeout_ms: 1000,
         excluded_from_all: true,
-        categories: &["non_ascii"],
+        categories: &["non_ascii", "religious"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--unup4y" => TldInfo {
         servers: &["65.22.21.34", "65.22.20.34", "65.22.22.34"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--vermgensberater-ctb" => TldInfo {
         servers: &["194.169.218.105", "212.18.249.105", "185.24.64.105"],
         timeout_ms: 1000,
         excluded_from_all: true,
-        categories: &["non_ascii"],
+        categories: &["non_ascii", "private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--vermgensberatung-pwb" => TldInfo {
         servers: &["185.24.64.106", "194.169.218.106", "212.18.248.106"],
         timeout_ms: 1000,
         excluded_from_all: true,
-        categories: &["non_ascii"],
+        categories: &["non_ascii", "private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--vhquv" => TldInfo {
         servers: &["65.22.29.30", "65.22.30.30", "65.22.31.30"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--vuq861b" => TldInfo {
         servers: &["103.61.63.1", "103.61.62.1", "103.61.60.1"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--w4r85el8fhu5dnra" => TldInfo {
         servers: &["192.42.173.30", "192.42.174.30", "192.42.175.30"],
         timeout_ms: 1000,
         excluded_from_all: true,
-        categories: &["non_ascii"],
+        categories: &["non_ascii", "private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--w4rs40l" => TldInfo {
         servers: &["192.42.174.30", "192.42.175.30", "192.42.176.30"],
         timeout_ms: 1000,
         excluded_from_all: true,
-        categories: &["non_ascii"],
+        categories: &["non_ascii", "private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--wgbh1c" => TldInfo {
         servers: &["81.21.97.155", "81.10.38.11", "204.61.216.106"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--wgbl6a" => TldInfo {
         servers: &["178.23.20.60", "204.61.216.62", "178.23.16.104"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--xhq521b" => TldInfo {
         servers: &["42.83.130.1", "42.83.133.1", "42.83.132.1"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--xkc2al3hye2a" => TldInfo {
         servers: &["202.129.235.229", "194.0.1.27", "203.94.66.129"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--xkc2dl3a5ee0h" => TldInfo {
         servers: &["64.96.1.1", "64.78.205.1", "64.96.2.1"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--y9a3aq" => TldInfo {
         servers: &["195.43.75.53", "77.72.229.254", "204.61.216.96"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--yfro4i67o" => TldInfo {
         servers: &["185.159.197.170", "202.12.31.53", "204.61.216.57"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--ygbi2ammx" => TldInfo {
         servers: &["193.0.9.105", "194.6.225.20", "77.72.229.254"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xn--zfr164b" => TldInfo {
         servers: &["111.235.163.1", "111.235.164.1", "111.235.161.1"],
         timeout_ms: 1000,
-        excluded_from_all: true,
+        excluded_from_all: false,
         categories: &["non_ascii"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xxx" => TldInfo {
         servers: &["37.209.196.10", "37.209.194.10", "156.154.173.82"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["adult"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "xyz" => TldInfo {
         servers: &["212.18.248.42", "185.24.64.42", "212.18.249.42"],
         timeout_ms: 500,
         excluded_from_all: false,
         categories: &[],
+        whois: Some("whois.nic.xyz"),
+        rdap: Some("https://rdap.centralnic.com/xyz/"),
+        policy: LabelPolicy::DEFAULT,
     },
     "yachts" => TldInfo {
         servers: &["185.24.64.133", "212.18.248.133", "212.18.249.133"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "yahoo" => TldInfo {
         servers: &["161.232.32.26", "65.22.108.26", "65.22.110.26"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "yamaxun" => TldInfo {
         servers: &["156.154.100.3", "156.154.103.3", "156.154.101.3"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "yandex" => TldInfo {
         servers: &["185.159.197.3", "64.96.1.1", "64.96.2.1"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "ye" => TldInfo {
         servers: &["204.61.216.153", "82.114.164.244", "195.94.10.22"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "yodobashi" => TldInfo {
         servers: &["37.209.194.4", "37.209.196.4", "37.209.192.4"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "yoga" => TldInfo {
         servers: &["156.154.172.82", "37.209.194.10", "37.209.192.10"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "yokohama" => TldInfo {
         servers: &["37.209.194.4", "37.209.192.4", "37.209.196.4"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "you" => TldInfo {
         servers: &["156.154.100.3", "156.154.103.3", "156.154.102.3"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "youtube" => TldInfo {
         servers: &["216.239.36.105", "216.239.32.105", "216.239.38.105"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "yt" => TldInfo {
         servers: &["194.0.9.1", "194.146.106.46", "194.0.36.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "yun" => TldInfo {
         servers: &["103.61.62.1", "103.61.61.1", "103.61.63.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "za" => TldInfo {
         servers: &["194.146.106.78", "196.4.160.27", "204.61.216.55"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "zappos" => TldInfo {
         servers: &["156.154.102.3", "43.230.50.52", "103.49.82.52"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "zara" => TldInfo {
         servers: &["65.22.232.33", "65.22.235.33", "65.22.233.33"],
         timeout_ms: 1000,
         excluded_from_all: true,
         categories: &["private"],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "zero" => TldInfo {
         servers: &["161.232.38.12", "65.22.78.12", "65.22.76.12"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "zip" => TldInfo {
         servers: &["216.239.34.105", "216.239.36.105", "216.239.38.105"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "zm" => TldInfo {
         servers: &["204.61.216.73", "196.216.168.44", "185.38.108.108"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "zone" => TldInfo {
         servers: &["161.232.14.5", "65.22.31.5", "161.232.15.5"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "zuerich" => TldInfo {
         servers: &["212.18.248.107", "194.169.218.107", "185.24.64.107"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
     "zw" => TldInfo {
         servers: &["204.61.216.128", "41.220.30.82", "5.11.11.1"],
         timeout_ms: 1000,
         excluded_from_all: false,
         categories: &[],
+        whois: None,
+        rdap: None,
+        policy: LabelPolicy::DEFAULT,
     },
 };
 
+/// Label policies of second-level suffixes (`com.au`), whose registries set their own rules.
+pub static SUFFIX_POLICIES: phf::Map<&'static str, LabelPolicy> = phf_map! {
+    "app.br" => LabelPolicy { max_length: 26, min_length: 2, ..LabelPolicy::DEFAULT },
+    "art.br" => LabelPolicy { max_length: 26, min_length: 2, ..LabelPolicy::DEFAULT },
+    "asn.au" => LabelPolicy { idn: false, min_length: 2, ..LabelPolicy::DEFAULT },
+    "blog.br" => LabelPolicy { max_length: 26, min_length: 2, ..LabelPolicy::DEFAULT },
+    "com.au" => LabelPolicy { idn: false, min_length: 2, ..LabelPolicy::DEFAULT },
+    "com.br" => LabelPolicy { max_length: 26, min_length: 2, ..LabelPolicy::DEFAULT },
+    "dev.br" => LabelPolicy { max_length: 26, min_length: 2, ..LabelPolicy::DEFAULT },
+    "eco.br" => LabelPolicy { max_length: 26, min_length: 2, ..LabelPolicy::DEFAULT },
+    "id.au" => LabelPolicy { idn: false, min_length: 2, ..LabelPolicy::DEFAULT },
+    "ind.br" => LabelPolicy { max_length: 26, min_length: 2, ..LabelPolicy::DEFAULT },
+    "net.au" => LabelPolicy { idn: false, min_length: 2, ..LabelPolicy::DEFAULT },
+    "net.br" => LabelPolicy { max_length: 26, min_length: 2, ..LabelPolicy::DEFAULT },
+    "org.au" => LabelPolicy { idn: false, min_length: 2, ..LabelPolicy::DEFAULT },
+    "org.br" => LabelPolicy { max_length: 26, min_length: 2, ..LabelPolicy::DEFAULT },
+    "tec.br" => LabelPolicy { max_length: 26, min_length: 2, ..LabelPolicy::DEFAULT },
+};
+
 /// Get TLD info for a domain
+///
+/// Looks up the domain's public suffix (`co.uk` for `brand.co.uk`), falling back to the
+/// TLD it belongs to when the suffix has no entry of its own.
 pub fn get_tld_info(domain: &str) -> Option<&'static TldInfo> {
-    let parts: Vec<&str> = domain.split('.').collect();
-    if parts.len() < 2 {
+    let domain = domain.to_lowercase();
+    if !domain.contains('.') {
         return None;
     }
 
-    let tld = parts[parts.len() - 1].to_lowercase();
-    TLD_SERVERS.get(tld.as_str())
+    let suffix = crate::psl::public_suffix(&domain)?;
+    let tld = suffix.rsplit('.').next()?;
+    TLD_SERVERS.get(suffix).or_else(|| TLD_SERVERS.get(tld))
+}
+
+/// Label policy of a public suffix (`com`, `com.au`): its own, never its TLD's.
+///
+/// `None` for suffixes without registry data, like `co.uk`.
+pub fn get_suffix_policy(suffix: &str) -> Option<LabelPolicy> {
+    SUFFIX_POLICIES
+        .get(suffix)
+        .copied()
+        .or_else(|| TLD_SERVERS.get(suffix).map(|info| info.policy))
 }
 
 /// Get TLDs suitable for --all flag (excludes private, adult, gambling, etc.)
@@ -8685,6 +13067,18 @@ mod tests {
         assert!(get_tld_info("test.xyz").is_some());
         assert!(get_tld_info("invalid.unknown").is_none());
         assert!(get_tld_info("invalid").is_none());
+        assert_eq!(
+            get_tld_info("brand.co.uk").map(|info| info.servers),
+            get_tld_info("brand.uk").map(|info| info.servers)
+        );
+    }
+
+    #[test]
+    fn suffix_policies_dont_inherit_the_tld() {
+        assert_eq!(get_suffix_policy("com"), Some(LabelPolicy::DEFAULT));
+        assert_eq!(get_suffix_policy("com.br").map(|policy| policy.max_length), Some(26));
+        assert!(get_suffix_policy("co.uk").is_none());
+        assert!(get_suffix_policy("unknown").is_none());
     }
 
     #[test]
@@ -8730,9 +13124,8 @@ mod tests {
         assert!(!public_tlds.contains(&"xxx"));
         assert!(!public_tlds.contains(&"aws"));
 
-        // Should not include IDN TLDs
-        for tld in &public_tlds {
-            assert!(!tld.starts_with("xn--"));
-        }
+        // Should include IDN TLDs (.рф, .中国)
+        assert!(public_tlds.contains(&"xn--p1ai"));
+        assert!(public_tlds.contains(&"xn--fiqs8s"));
     }
 }
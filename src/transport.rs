@@ -0,0 +1,131 @@
+//! How the fallback resolver is reached.
+//!
+//! When every authoritative server for a TLD fails, the checker asks a recursive resolver
+//! instead. By default that's plain UDP to the resolvers in the system configuration, which a
+//! network intercepting port 53 can answer however it likes. DNS over TLS (RFC 7858) and DNS
+//! over HTTPS (RFC 8484) reach a chosen upstream over an authenticated, encrypted connection
+//! instead; see [`crate::checker::CheckerBuilder::resolver_transport`].
+//!
+//! # Examples
+//!
+//! ```rust
+//! use dotchk::{Checker, ResolverTransport};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let dot = ResolverTransport::Tls(vec!["1.1.1.1".parse()?, "9.9.9.9#dns.quad9.net".parse()?]);
+//! let checker = Checker::builder().resolver_transport(dot)?.build().await?;
+//!
+//! let doh = ResolverTransport::Https(vec!["https://dns.google/dns-query".to_string()]);
+//! let checker = Checker::builder().resolver_transport(doh)?.build().await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+/// Port DNS over TLS listens on (RFC 7858 section 3.1).
+pub const DOT_PORT: u16 = 853;
+
+/// Transport for queries to the fallback resolver.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum ResolverTransport {
+    /// Plain UDP to the system's resolvers.
+    #[default]
+    Udp,
+    /// DNS over TLS to these upstreams, tried in order.
+    Tls(Vec<TlsUpstream>),
+    /// DNS over HTTPS: wire-format POSTs to these URLs, tried in order.
+    Https(Vec<String>),
+}
+
+/// A DNS-over-TLS resolver: where to connect and the name its certificate must carry.
+///
+/// Parsed from `HOST[:PORT][#NAME]`, e.g. `1.1.1.1`, `[2606:4700::1111]:853` or
+/// `9.9.9.9#dns.quad9.net`. Without a name the certificate must be issued for `HOST`
+/// itself, which the big public resolvers' certificates are, addresses included.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TlsUpstream {
+    /// IP address or hostname to connect to.
+    pub host: String,
+    pub port: u16,
+    /// Name (or address) the server's certificate is checked against.
+    pub server_name: String,
+}
+
+impl FromStr for TlsUpstream {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("invalid DNS-over-TLS upstream '{s}' (expected HOST[:PORT][#NAME])");
+        let spec = s.strip_prefix("tls://").unwrap_or(s);
+        let (address, name) = match spec.split_once('#') {
+            Some((address, name)) => (address, Some(name)),
+            None => (spec, None),
+        };
+
+        let (host, port) = if let Some(bracketed) = address.strip_prefix('[') {
+            let (host, rest) = bracketed.split_once(']').ok_or_else(invalid)?;
+            match rest.strip_prefix(':') {
+                Some(port) => (host, port.parse().map_err(|_| invalid())?),
+                None if rest.is_empty() => (host, DOT_PORT),
+                None => return Err(invalid()),
+            }
+        } else {
+            match address.split_once(':') {
+                // More than one colon is a bare IPv6 address
+                Some((host, port)) if !port.contains(':') => (host, port.parse().map_err(|_| invalid())?),
+                _ => (address, DOT_PORT),
+            }
+        };
+        if host.is_empty() || name.is_some_and(str::is_empty) {
+            return Err(invalid());
+        }
+
+        Ok(Self {
+            host: host.to_string(),
+            port,
+            server_name: name.unwrap_or(host).to_string(),
+        })
+    }
+}
+
+impl fmt::Display for TlsUpstream {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.host.contains(':') {
+            write!(f, "[{}]:{}", self.host, self.port)?;
+        } else {
+            write!(f, "{}:{}", self.host, self.port)?;
+        }
+        if self.server_name != self.host {
+            write!(f, "#{}", self.server_name)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_tls_upstreams() {
+        let plain: TlsUpstream = "1.1.1.1".parse().unwrap();
+        assert_eq!((plain.host.as_str(), plain.port), ("1.1.1.1", DOT_PORT));
+        assert_eq!(plain.server_name, "1.1.1.1");
+
+        let named: TlsUpstream = "tls://9.9.9.9:8853#dns.quad9.net".parse().unwrap();
+        assert_eq!((named.host.as_str(), named.port), ("9.9.9.9", 8853));
+        assert_eq!(named.server_name, "dns.quad9.net");
+        assert_eq!(named.to_string(), "9.9.9.9:8853#dns.quad9.net");
+
+        let v6: TlsUpstream = "[2606:4700::1111]:853".parse().unwrap();
+        assert_eq!(v6.host, "2606:4700::1111");
+        assert_eq!(v6.to_string(), "[2606:4700::1111]:853");
+        assert_eq!("2606:4700::1111".parse::<TlsUpstream>().unwrap().port, DOT_PORT);
+
+        for invalid in ["", "1.1.1.1:dns", "1.1.1.1#", "[::1", "[::1]853"] {
+            assert!(invalid.parse::<TlsUpstream>().is_err(), "{invalid}");
+        }
+    }
+}
@@ -0,0 +1,538 @@
+//! WHOIS verification for domains DNS reports as available.
+//!
+//! A missing NS record doesn't prove a domain is unregistered (it may be registered
+//! without nameservers, or on hold). [`WhoisClient`] asks the registry's WHOIS server
+//! (port 43) and turns its free-form answer into a [`WhoisVerdict`].
+//!
+//! The WHOIS server comes from the TLD registry; for TLDs without one, whois.iana.org is
+//! asked for a referral.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use dotchk::{Checker, WhoisClient};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let checker = Checker::builder().build().await?;
+//! let mut results = checker.check_batch(vec!["example.com".to_string()]).await;
+//!
+//! // Re-check only the domains DNS called available
+//! WhoisClient::new().verify_available(&mut results).await;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::DomainCheckerError;
+use crate::checker::CheckResult;
+use crate::tld_registry::get_tld_info;
+use dashmap::DashMap;
+use futures::{StreamExt, stream};
+use serde::{Deserialize, Serialize};
+use std::net::{IpAddr, SocketAddr};
+use std::sync::Arc;
+use std::time::Duration;
+use thiserror::Error;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+use tracing::{debug, warn};
+
+/// IANA's WHOIS server, which refers each TLD to its registry's server.
+const IANA_WHOIS_SERVER: &str = "whois.iana.org";
+const WHOIS_PORT: u16 = 43;
+/// Responses are a few KiB; anything beyond this is cut off.
+const MAX_RESPONSE_SIZE: u64 = 256 * 1024;
+
+/// What known registries answer for an unregistered domain, by WHOIS server. Each
+/// phrase must start a line (see [`normalize_line`]), so disclaimers that quote one don't count.
+const REGISTRY_NOT_FOUND: &[(&str, &[&str])] = &[
+    ("whois.verisign-grs.com", &["no match for"]),
+    ("whois.pir.org", &["not found", "domain not found"]),
+    ("whois.nic.google", &["domain not found"]),
+    ("whois.identity.digital", &["domain not found"]),
+    ("whois.donuts.co", &["domain not found"]),
+    ("whois.centralnic.com", &["domain not found"]),
+    ("whois.nic.co", &["no data found"]),
+    ("whois.nic.us", &["no data found"]),
+    ("whois.nic.biz", &["no data found"]),
+    ("whois.denic.de", &["status: free"]),
+    ("whois.eu", &["status: available"]),
+    ("whois.dns.be", &["status: available"]),
+    ("whois.nic.it", &["status: available"]),
+    (
+        "whois.nic.uk",
+        &["no match for", "this domain name has not been registered"],
+    ),
+    ("whois.nic.fr", &["not found"]),
+    ("whois.sidn.nl", &["is free", "domain name is free"]),
+    ("whois.cira.ca", &["not found"]),
+    ("whois.iis.se", &["not found"]),
+    ("whois.nic.ch", &["we do not have an entry in our database"]),
+    ("whois.jprs.jp", &["no match!!"]),
+    ("whois.auda.org.au", &["not found"]),
+    ("whois.registro.br", &["no match for"]),
+    ("whois.cnnic.cn", &["no matching record"]),
+    ("whois.kr", &["the requested domain was not found"]),
+];
+
+/// Phrases used for servers not in [`REGISTRY_NOT_FOUND`], matched the same way.
+const NOT_FOUND_PATTERNS: &[&str] = &[
+    "no match for",
+    "not found",
+    "domain not found",
+    "no entries found",
+    "no data found",
+    "no object found",
+    "status: free",
+    "status: available",
+];
+
+/// Phrases that mean the server refused to answer (rate limiting, access control).
+const REFUSED_PATTERNS: &[&str] = &[
+    "limit exceeded",
+    "rate limit",
+    "too many requests",
+    "quota exceeded",
+    "access denied",
+    "try again later",
+];
+
+/// Fields that only have a value in the record of a registered domain.
+const REGISTERED_FIELDS: &[&str] = &[
+    "registrar",
+    "registry domain id",
+    "creation date",
+    "created",
+    "registered",
+    "registered on",
+    "nserver",
+    "name server",
+];
+
+/// `status:` values of a registered domain.
+const REGISTERED_STATUSES: &[&str] = &["active", "connect", "ok", "registered"];
+
+/// Errors from a WHOIS lookup.
+#[derive(Error, Debug)]
+pub enum WhoisError {
+    #[error("WHOIS I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("WHOIS server did not respond within timeout period")]
+    Timeout,
+
+    #[error("No WHOIS server known for TLD '.{0}'")]
+    NoServer(String),
+}
+
+/// What a registry's WHOIS server says about a domain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WhoisVerdict {
+    /// The registry has no record of the domain.
+    Available,
+    /// The registry has a record: the domain is taken even though DNS didn't show it.
+    Registered,
+    /// The lookup failed, was refused, or the answer couldn't be interpreted.
+    Unknown,
+}
+
+impl WhoisVerdict {
+    /// Short machine-readable name, as used in CSV exports.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WhoisVerdict::Available => "available",
+            WhoisVerdict::Registered => "registered",
+            WhoisVerdict::Unknown => "unknown",
+        }
+    }
+}
+
+/// Interpret a WHOIS response from `server`.
+///
+/// Fields of a registration record win over "not found" phrases, which registries also
+/// put in disclaimers and footers of registered answers.
+pub fn parse_response(server: &str, response: &str) -> WhoisVerdict {
+    let response = response.to_lowercase();
+    if REFUSED_PATTERNS.iter().any(|pattern| response.contains(pattern)) {
+        return WhoisVerdict::Unknown;
+    }
+
+    let lines: Vec<String> = response.lines().map(normalize_line).collect();
+    let registered = lines.iter().any(|line| {
+        let Some((key, value)) = line.split_once(':') else {
+            return false;
+        };
+        let value = value.trim();
+        !value.is_empty()
+            && (REGISTERED_FIELDS.contains(&key)
+                || key == "status" && REGISTERED_STATUSES.iter().any(|status| value.starts_with(status)))
+    });
+    if registered {
+        return WhoisVerdict::Registered;
+    }
+
+    let host = server.split(':').next().unwrap_or(server);
+    let not_found = REGISTRY_NOT_FOUND
+        .iter()
+        .find(|(registry, _)| host.eq_ignore_ascii_case(registry))
+        .map_or(NOT_FOUND_PATTERNS, |(_, patterns)| patterns);
+    if lines
+        .iter()
+        .any(|line| not_found.iter().any(|pattern| line.starts_with(pattern)))
+    {
+        WhoisVerdict::Available
+    } else {
+        WhoisVerdict::Unknown
+    }
+}
+
+/// A lowercased response line without comment markers (`%`, `#`, `>`) and with runs of
+/// whitespace collapsed, so `Status:\tAVAILABLE` reads `status: available`.
+fn normalize_line(line: &str) -> String {
+    line.trim_start_matches(|c: char| c.is_whitespace() || matches!(c, '%' | '#' | '>'))
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Port-43 WHOIS client.
+#[derive(Debug, Clone)]
+pub struct WhoisClient {
+    timeout: Duration,
+    max_parallel: usize,
+    server: Option<String>,
+    // TLD -> registry server from IANA, for TLDs the registry has no server for
+    referrals: Arc<DashMap<String, Option<String>>>,
+}
+
+impl Default for WhoisClient {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(10),
+            max_parallel: 4,
+            server: None,
+            referrals: Arc::new(DashMap::new()),
+        }
+    }
+}
+
+impl WhoisClient {
+    /// Client with a 10 second timeout and at most 4 lookups at a time.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Timeout for one lookup: connecting, sending and reading the whole answer.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Maximum concurrent lookups in [`WhoisClient::verify_available`].
+    ///
+    /// WHOIS servers rate-limit aggressively, so keep this low.
+    pub fn max_parallel(mut self, max_parallel: usize) -> Self {
+        self.max_parallel = max_parallel.max(1);
+        self
+    }
+
+    /// Send every lookup to `server` (`host`, `host:port`, an IP address or `IP:port`,
+    /// with IPv6 addresses in brackets when a port follows) instead of the registry's.
+    pub fn server(mut self, server: impl Into<String>) -> Self {
+        self.server = Some(server.into());
+        self
+    }
+
+    /// The WHOIS server responsible for `domain`.
+    pub async fn server_for(&self, domain: &str) -> Result<String, WhoisError> {
+        if let Some(server) = &self.server {
+            return Ok(server.clone());
+        }
+        if let Some(server) = get_tld_info(domain).and_then(|info| info.whois) {
+            return Ok(server.to_string());
+        }
+
+        let tld = domain.rsplit('.').next().unwrap_or(domain).to_lowercase();
+        if let Some(referral) = self.referrals.get(&tld) {
+            return referral.clone().ok_or(WhoisError::NoServer(tld));
+        }
+
+        let response = self.query(IANA_WHOIS_SERVER, &tld).await?;
+        let referral = parse_referral(&response);
+        debug!("IANA refers .{} to WHOIS server {:?}", tld, referral);
+        self.referrals.insert(tld.clone(), referral.clone());
+        referral.ok_or(WhoisError::NoServer(tld))
+    }
+
+    /// Look `domain` up at its registry's WHOIS server.
+    pub async fn lookup(&self, domain: &str) -> Result<WhoisVerdict, WhoisError> {
+        let server = self.server_for(domain).await?;
+        let response = self.query(&server, domain).await?;
+        Ok(parse_response(&server, &response))
+    }
+
+    /// Send `domain` to `server` (see [`WhoisClient::server`]) and return the raw answer.
+    pub async fn query(&self, server: &str, domain: &str) -> Result<String, WhoisError> {
+        let address = server_address(server);
+
+        let exchange = async {
+            let mut stream = TcpStream::connect(&address).await?;
+            stream.write_all(query_line(server, domain).as_bytes()).await?;
+
+            let mut response = Vec::new();
+            stream.take(MAX_RESPONSE_SIZE).read_to_end(&mut response).await?;
+            Ok::<_, WhoisError>(response)
+        };
+
+        let response = tokio::time::timeout(self.timeout, exchange)
+            .await
+            .map_err(|_| WhoisError::Timeout)??;
+        Ok(String::from_utf8_lossy(&response).into_owned())
+    }
+
+    /// Look up every result DNS called available and record the verdict in its `whois` field.
+    ///
+    /// Failed lookups are recorded as [`WhoisVerdict::Unknown`]. Results that aren't
+    /// available, and failed checks, are left alone.
+    pub async fn verify_available(&self, results: &mut [Result<CheckResult, DomainCheckerError>]) {
+        let lookups: Vec<_> = results
+            .iter_mut()
+            .filter_map(|result| result.as_mut().ok())
+            .filter(|check| check.available())
+            .map(|check| async move {
+                check.whois = Some(match self.lookup(&check.domain).await {
+                    Ok(verdict) => verdict,
+                    Err(e) => {
+                        warn!("WHOIS lookup for {} failed: {}", check.domain, e);
+                        WhoisVerdict::Unknown
+                    }
+                });
+            })
+            .collect();
+
+        stream::iter(lookups)
+            .buffer_unordered(self.max_parallel)
+            .collect::<Vec<()>>()
+            .await;
+    }
+}
+
+/// Where to connect for `server`, adding the WHOIS port unless it names one.
+fn server_address(server: &str) -> String {
+    if let Ok(address) = server.parse::<SocketAddr>() {
+        address.to_string()
+    } else if let Ok(ip) = server.parse::<IpAddr>() {
+        // A bare IPv6 address is full of colons but has no port
+        SocketAddr::new(ip, WHOIS_PORT).to_string()
+    } else if server.rsplit_once(':').is_some_and(|(_, port)| port.parse::<u16>().is_ok()) {
+        server.to_string()
+    } else {
+        format!("{server}:{WHOIS_PORT}")
+    }
+}
+
+/// The query to send; a few registries want flags in front of the domain.
+fn query_line(server: &str, domain: &str) -> String {
+    let host = server.split(':').next().unwrap_or(server);
+    if host.eq_ignore_ascii_case("whois.denic.de") {
+        format!("-T dn,ace {domain}\r\n")
+    } else if host.eq_ignore_ascii_case("whois.jprs.jp") {
+        format!("{domain}/e\r\n")
+    } else {
+        format!("{domain}\r\n")
+    }
+}
+
+/// The `whois:` (or `refer:`) server in an IANA answer for a TLD.
+fn parse_referral(response: &str) -> Option<String> {
+    response.lines().find_map(|line| {
+        let (key, value) = line.split_once(':')?;
+        let key = key.trim();
+        let value = value.trim();
+        ((key.eq_ignore_ascii_case("whois") || key.eq_ignore_ascii_case("refer")) && !value.is_empty())
+            .then(|| value.to_lowercase())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checker::DomainStatus;
+    use tokio::net::TcpListener;
+
+    /// Serve one canned answer per connection; returns the address and the queries received.
+    async fn fake_server(answer: &'static str) -> (String, tokio::sync::mpsc::UnboundedReceiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                let tx = tx.clone();
+                tokio::spawn(async move {
+                    let mut query = vec![0; 512];
+                    let n = socket.read(&mut query).await.unwrap();
+                    let _ = tx.send(String::from_utf8_lossy(&query[..n]).into_owned());
+                    socket.write_all(answer.as_bytes()).await.unwrap();
+                });
+            }
+        });
+
+        (address, rx)
+    }
+
+    fn available(domain: &str) -> CheckResult {
+        CheckResult {
+            domain: domain.to_string(),
+            status: DomainStatus::NxDomain,
+            server: None,
+            rcode: Some(3),
+            authoritative: true,
+            rtt_ms: None,
+            attempts: 1,
+            nameservers: Vec::new(),
+            whois: None,
+            rdap: None,
+            dnssec: None,
+        }
+    }
+
+    #[test]
+    fn parses_common_responses() {
+        assert_eq!(
+            parse_response(
+                "whois.verisign-grs.com",
+                "No match for \"EXAMPLE-FREE.COM\".\r\n>>> Last update of whois database"
+            ),
+            WhoisVerdict::Available
+        );
+        assert_eq!(
+            parse_response("whois.denic.de", "Domain: example.de\nStatus:\tfree\n"),
+            WhoisVerdict::Available
+        );
+        assert_eq!(
+            parse_response("whois.nic.fr", "%% NOT FOUND\n"),
+            WhoisVerdict::Available
+        );
+        assert_eq!(parse_response("whois.example", "NOT FOUND\n"), WhoisVerdict::Available);
+        assert_eq!(
+            parse_response(
+                "whois.verisign-grs.com",
+                "Domain Name: EXAMPLE.COM\r\nRegistrar: RESERVED-Internet Assigned Numbers Authority\r\n"
+            ),
+            WhoisVerdict::Registered
+        );
+        assert_eq!(
+            parse_response("whois.denic.de", "Domain: example.de\nNserver: a.iana-servers.net\n"),
+            WhoisVerdict::Registered
+        );
+        assert_eq!(
+            parse_response("whois.example", "Query rate limit exceeded\n"),
+            WhoisVerdict::Unknown
+        );
+        assert_eq!(parse_response("whois.example", ""), WhoisVerdict::Unknown);
+    }
+
+    #[test]
+    fn disclaimers_do_not_make_registered_domains_available() {
+        let registered = "Domain Name: EXAMPLE.COM\r\nRegistrar: Example Registrar\r\n\r\n\
+                          NOTICE: if a domain is not found, it may still be reserved.\r\n\
+                          Not found in our records? Ask your registrar.\r\n";
+        assert_eq!(
+            parse_response("whois.verisign-grs.com", registered),
+            WhoisVerdict::Registered
+        );
+
+        // Another registry's phrase, or one quoted mid-line, isn't a verdict
+        assert_eq!(
+            parse_response(
+                "whois.denic.de",
+                "Domain: example.de\nNot found in cache, asking upstream\n"
+            ),
+            WhoisVerdict::Unknown
+        );
+        assert_eq!(
+            parse_response(
+                "whois.example",
+                "Terms: answers saying no match for a name are not binding\n"
+            ),
+            WhoisVerdict::Unknown
+        );
+        // Empty record fields don't count
+        assert_eq!(
+            parse_response(
+                "whois.nic.uk",
+                "Domain name:\n    free.co.uk\n\nNo match for \"free.co.uk\"\n"
+            ),
+            WhoisVerdict::Available
+        );
+    }
+
+    #[test]
+    fn parses_iana_referral() {
+        let answer = "% IANA WHOIS server\n\ndomain:       COM\n\nwhois:        whois.verisign-grs.com\n";
+        assert_eq!(parse_referral(answer).as_deref(), Some("whois.verisign-grs.com"));
+        assert_eq!(parse_referral("domain: EXAMPLE\nwhois:\n"), None);
+    }
+
+    #[test]
+    fn registry_servers_and_query_formats() {
+        assert_eq!(
+            get_tld_info("example.com").unwrap().whois,
+            Some("whois.verisign-grs.com")
+        );
+        assert_eq!(query_line("whois.denic.de", "example.de"), "-T dn,ace example.de\r\n");
+        assert_eq!(query_line("127.0.0.1:4343", "example.com"), "example.com\r\n");
+    }
+
+    #[test]
+    fn server_addresses_get_the_whois_port_unless_they_name_one() {
+        assert_eq!(server_address("whois.example"), "whois.example:43");
+        assert_eq!(server_address("whois.example:4343"), "whois.example:4343");
+        assert_eq!(server_address("192.0.2.1"), "192.0.2.1:43");
+        assert_eq!(server_address("192.0.2.1:4343"), "192.0.2.1:4343");
+        assert_eq!(server_address("2001:db8::43"), "[2001:db8::43]:43");
+        assert_eq!(server_address("[2001:db8::43]:4343"), "[2001:db8::43]:4343");
+    }
+
+    #[tokio::test]
+    async fn lookup_against_fake_server() {
+        let (address, mut queries) = fake_server("No match for \"FREE-EXAMPLE.COM\".\r\n").await;
+        let client = WhoisClient::new().server(address);
+
+        assert_eq!(
+            client.lookup("free-example.com").await.unwrap(),
+            WhoisVerdict::Available
+        );
+        assert_eq!(queries.recv().await.unwrap(), "free-example.com\r\n");
+    }
+
+    #[tokio::test]
+    async fn verify_available_marks_registered_hits() {
+        let (address, _queries) = fake_server("Domain Name: HELD.COM\r\nRegistrar: Example Registrar\r\n").await;
+        let client = WhoisClient::new().server(address);
+
+        let mut taken = available("taken.com");
+        taken.status = DomainStatus::Delegated;
+        let mut results = vec![Ok(available("held.com")), Ok(taken), Err(DomainCheckerError::Timeout)];
+        client.verify_available(&mut results).await;
+
+        let held = results[0].as_ref().unwrap();
+        assert_eq!(held.whois, Some(WhoisVerdict::Registered));
+        assert!(!held.available());
+        // Only DNS-available domains are looked up
+        assert_eq!(results[1].as_ref().unwrap().whois, None);
+    }
+
+    #[tokio::test]
+    async fn lookup_times_out_on_silent_server() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+        tokio::spawn(async move {
+            let (_socket, _) = listener.accept().await.unwrap();
+            tokio::time::sleep(Duration::from_secs(5)).await;
+        });
+
+        let client = WhoisClient::new().server(address).timeout(Duration::from_millis(100));
+        assert!(matches!(client.lookup("example.com").await, Err(WhoisError::Timeout)));
+    }
+}
@@ -0,0 +1,109 @@
+//! Enumerating a zone's registered names by walking its NSEC chain.
+//!
+//! A zone signed with plain NSEC (RFC 4034) proves a name absent by naming the two names
+//! around it, so one query per registered name lists them all in canonical order. Each
+//! query asks for a name sorting just after the last registered name and everything below
+//! it; the NSEC record covering that name gives the next one. The chain ends when it wraps
+//! around to the apex.
+//!
+//! Zones signed with NSEC3 (most large TLDs) hash their names and can't be walked this way,
+//! nor can unsigned ones; [`ZoneWalkError`] says which it was. The NSEC records aren't
+//! validated: a walk is for finding candidates, not proving anything about them.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use dotchk::Checker;
+//! use futures::StreamExt;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let checker = Checker::builder().qps(50)?.build().await?;
+//!
+//! let mut names = Box::pin(checker.zone_walk("example"));
+//! while let Some(name) = names.next().await {
+//!     println!("{}", name?);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use thiserror::Error;
+
+/// Longest label the wire format allows.
+const MAX_LABEL_LEN: usize = 63;
+
+/// Why a zone couldn't be walked.
+#[derive(Error, Debug)]
+pub enum ZoneWalkError {
+    #[error("No nameservers known for zone '{0}'")]
+    NoServers(String),
+
+    #[error("Zone '{0}' is signed with NSEC3, whose hashed names can't be walked")]
+    Nsec3(String),
+
+    #[error("Zone '{0}' sent no NSEC records; it may be unsigned")]
+    Unsigned(String),
+
+    #[error("NSEC chain of '{zone}' is broken after '{name}': next name is '{next}'")]
+    BrokenChain { zone: String, name: String, next: String },
+
+    #[error("Walking '{zone}' failed at '{name}': {reason}")]
+    Query { zone: String, name: String, reason: String },
+}
+
+/// The name below `zone` that `name` belongs to (`abc.com` for `www.abc.com` in `com`).
+pub(crate) fn registered_name(name: &str, zone: &str) -> Option<String> {
+    let rest = name.strip_suffix(zone)?.strip_suffix('.')?;
+    let label = rest.rsplit('.').next().filter(|label| !label.is_empty())?;
+    Some(format!("{label}.{zone}"))
+}
+
+/// A name that sorts right after `name` and everything below it, so the NSEC record
+/// covering it names whatever comes next in `zone`. `name` is `zone` itself to start a walk.
+pub(crate) fn probe_after(name: &str, zone: &str) -> Option<String> {
+    if name == zone {
+        return Some(format!("\0.{zone}"));
+    }
+    let registered = registered_name(name, zone)?;
+    let label = &registered[..registered.len() - zone.len() - 1];
+    // Appending the lowest possible byte sorts right after the label and its subdomains;
+    // a full-length label gets its last character bumped instead
+    let probe = if label.len() < MAX_LABEL_LEN {
+        format!("{label}\0")
+    } else {
+        let mut chars: Vec<char> = label.chars().collect();
+        let last = chars.pop()?;
+        chars.push(char::from_u32(u32::from(last) + 1)?);
+        chars.into_iter().collect()
+    };
+    Some(format!("{probe}.{zone}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_the_registered_name_of_a_chain_entry() {
+        assert_eq!(registered_name("abc.com", "com").as_deref(), Some("abc.com"));
+        assert_eq!(registered_name("www.abc.com", "com").as_deref(), Some("abc.com"));
+        assert_eq!(registered_name("abc.co.uk", "co.uk").as_deref(), Some("abc.co.uk"));
+        assert_eq!(registered_name("com", "com"), None);
+        assert_eq!(registered_name("abc.net", "com"), None);
+        assert_eq!(registered_name("abcom", "com"), None);
+    }
+
+    #[test]
+    fn probes_past_a_name_and_its_subdomains() {
+        assert_eq!(probe_after("com", "com").as_deref(), Some("\0.com"));
+        assert_eq!(probe_after("abc.com", "com").as_deref(), Some("abc\0.com"));
+        assert_eq!(probe_after("ns1.abc.com", "com").as_deref(), Some("abc\0.com"));
+
+        let long = "a".repeat(63);
+        assert_eq!(
+            probe_after(&format!("{long}.com"), "com"),
+            Some(format!("{}b.com", "a".repeat(62)))
+        );
+        assert_eq!(probe_after("abc.net", "com"), None);
+    }
+}
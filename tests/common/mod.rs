@@ -0,0 +1,34 @@
+//! Local stand-ins for the network services the integration tests talk to.
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+/// Serve RDAP from a local HTTP/1.1 stand-in: 200 with the given domain object for each
+/// listed domain, 404 for anything else. Returns the base URL.
+pub async fn rdap_stand_in(records: &'static [(&'static str, &'static str)]) -> String {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base = format!("http://{}/", listener.local_addr().unwrap());
+
+    tokio::spawn(async move {
+        while let Ok((mut socket, _)) = listener.accept().await {
+            tokio::spawn(async move {
+                let mut request = vec![0; 2048];
+                let n = socket.read(&mut request).await.unwrap();
+                let request = String::from_utf8_lossy(&request[..n]);
+                let record = records
+                    .iter()
+                    .find(|(domain, _)| request.starts_with(&format!("GET /domain/{domain} ")));
+                let (status, body) = match record {
+                    Some((_, body)) => ("200 OK", *body),
+                    None => ("404 Not Found", r#"{"errorCode": 404}"#),
+                };
+                let response = format!(
+                    "HTTP/1.1 {status}\r\nContent-Type: application/rdap+json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                socket.write_all(response.as_bytes()).await.unwrap();
+            });
+        }
+    });
+
+    base
+}
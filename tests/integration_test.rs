@@ -1,3 +1,5 @@
+mod common;
+
 use dotchk::{Checker, Pattern};
 use std::time::Duration;
 
@@ -24,7 +26,7 @@ async fn test_real_domain_checks() {
     for result in results {
         match result {
             Ok(check) => {
-                assert!(!check.available, "Domain {} should not be available", check.domain);
+                assert!(!check.available(), "Domain {} should not be available", check.domain);
             }
             Err(_) => {
                 // Errors are acceptable (e.g., DNS timeout), just not "available"
@@ -55,7 +57,7 @@ async fn test_likely_unregistered_domains() {
     for result in results {
         match result {
             Ok(check) => {
-                println!("Domain {} availability: {}", check.domain, check.available);
+                println!("Domain {} availability: {}", check.domain, check.available());
             }
             Err(e) => {
                 println!("Error checking domain: {}", e);
@@ -65,6 +67,7 @@ async fn test_likely_unregistered_domains() {
 }
 
 #[tokio::test]
+#[allow(clippy::single_match)]
 async fn test_different_tlds() {
     let checker = Checker::builder()
         .max_parallel(10)
@@ -118,20 +121,26 @@ async fn test_invalid_domains() {
         "test.invalid-tld-xyz".to_string(), // unsupported TLD
     ];
 
-    let results = checker.check_batch(invalid_domains).await;
+    let results = checker.check_batch(invalid_domains.clone()).await;
 
     for result in results {
         match result {
             Ok(check) => {
                 // If it succeeded, it should be unavailable
                 assert!(
-                    !check.available,
+                    !check.available(),
                     "Invalid domain {} should be unavailable",
                     check.domain
                 );
             }
-            Err(_) => {
-                // Errors are expected for invalid domains
+            Err(e) => {
+                // Errors are expected for invalid domains, and still name the domain
+                let domain = e.domain().expect("batch errors carry their domain");
+                assert!(
+                    invalid_domains.iter().any(|d| d == domain),
+                    "unexpected domain {domain}"
+                );
+                assert!(!matches!(e.cause(), dotchk::DomainCheckerError::Check { .. }));
             }
         }
     }
@@ -206,7 +215,7 @@ async fn test_edge_case_domains() {
     for result in results {
         match &result {
             Ok(check) => {
-                println!("Edge case domain {}: available={}", check.domain, check.available);
+                println!("Edge case domain {}: available={}", check.domain, check.available());
                 // These are all valid domains, should succeed
             }
             Err(e) => {
@@ -272,7 +281,7 @@ async fn test_timeout_handling() {
     // In practice, DNS queries take longer than 1ms, so this should error
     match result {
         Ok(check) => {
-            println!("Timeout test result: available={}", check.available);
+            println!("Timeout test result: available={}", check.available());
         }
         Err(e) => {
             println!("Timeout test error: {}", e);
@@ -346,7 +355,7 @@ async fn test_tld_checking_multiple_domains() {
                     domains.contains(&check.domain),
                     "Result domain should be in the input list"
                 );
-                println!("TLD check {}: available={}", check.domain, check.available);
+                println!("TLD check {}: available={}", check.domain, check.available());
             }
             Err(e) => {
                 println!("TLD check error: {}", e);
@@ -381,7 +390,7 @@ async fn test_tld_popular_domains() {
     let results = checker.check_batch(domains).await;
 
     // Count available domains
-    let available_count = results.iter().filter(|r| matches!(r, Ok(check) if check.available)).count();
+    let available_count = results.iter().filter(|r| matches!(r, Ok(check) if check.available())).count();
 
     println!("Available domains: {}/{}", available_count, popular_tlds.len());
 
@@ -475,7 +484,7 @@ async fn test_alternation_domain_checking() {
                     domains.contains(&check.domain),
                     "Result domain should be in the generated list"
                 );
-                println!("Alternation check {}: available={}", check.domain, check.available);
+                println!("Alternation check {}: available={}", check.domain, check.available());
             }
             Err(e) => {
                 println!("Alternation check error: {}", e);
@@ -565,7 +574,11 @@ async fn test_range_quantifier_domain_checking() {
                 assert!(number_part.len() == 1 || number_part.len() == 2);
                 assert!(number_part.chars().all(|c| c.is_numeric()));
 
-                println!("Range quantifier check {}: available={}", check.domain, check.available);
+                println!(
+                    "Range quantifier check {}: available={}",
+                    check.domain,
+                    check.available()
+                );
             }
             Err(e) => {
                 println!("Range quantifier check error: {}", e);
@@ -573,3 +586,391 @@ async fn test_range_quantifier_domain_checking() {
         }
     }
 }
+
+/// Serve RDAP answers from a local HTTP stand-in: 200 for taken.com, 404 for anything else.
+async fn rdap_stand_in() -> String {
+    common::rdap_stand_in(&[(
+        "taken.com",
+        r#"{"objectClassName":"domain","ldhName":"taken.com","status":["active"],
+           "events":[{"eventAction":"expiration","eventDate":"2030-01-01T00:00:00Z"}]}"#,
+    )])
+    .await
+}
+
+#[tokio::test]
+async fn test_rdap_lookup() {
+    let client = dotchk::RdapClient::new().base_url(rdap_stand_in().await);
+
+    let (taken, base_url) = client.lookup("taken.com").await.unwrap();
+    assert!(taken.registered);
+    assert_eq!(taken.status, vec!["active"]);
+    assert!(base_url.starts_with("http://127.0.0.1:"));
+
+    let (free, _) = client.lookup("free-to-register.com").await.unwrap();
+    assert!(!free.registered);
+}
+
+#[tokio::test]
+async fn test_rdap_primary_backend() {
+    let client = dotchk::RdapClient::new().base_url(rdap_stand_in().await);
+    let checker = Checker::builder()
+        .rdap(dotchk::RdapMode::Primary, Some(client))
+        .expect("Failed to set rdap")
+        .build()
+        .await
+        .expect("Failed to build checker");
+
+    let taken = checker.check("taken.com").await.unwrap();
+    assert_eq!(taken.status, dotchk::DomainStatus::Rdap { registered: true });
+    assert!(!taken.available());
+    let rdap = taken.rdap.expect("RDAP record is attached");
+    assert_eq!(rdap.status, vec!["active"]);
+    assert_eq!(rdap.expires_at.as_deref(), Some("2030-01-01T00:00:00Z"));
+
+    let free = checker.check("free-to-register.com").await.unwrap();
+    assert_eq!(free.status, dotchk::DomainStatus::Rdap { registered: false });
+    assert!(free.available());
+
+    // A subdomain is looked up as the registration it belongs to
+    let subdomain = checker.check("www.taken.com").await.unwrap();
+    assert_eq!(subdomain.domain, "www.taken.com");
+    assert_eq!(subdomain.status, dotchk::DomainStatus::Rdap { registered: true });
+    assert!(!subdomain.available());
+
+    // A public suffix has no registration to look up
+    let backend = dotchk::backend::RdapBackend::new(dotchk::RdapClient::new().base_url(rdap_stand_in().await));
+    let suffix = dotchk::Domain::new("co.uk").unwrap();
+    let undecided = dotchk::CheckBackend::check(&backend, &suffix).await.unwrap();
+    assert!(undecided.status.is_error());
+}
+
+/// Says every domain is available.
+struct AlwaysAvailable;
+
+impl dotchk::CheckBackend for AlwaysAvailable {
+    fn name(&self) -> &str {
+        "always-available"
+    }
+
+    fn check<'a>(
+        &'a self,
+        domain: &'a dotchk::Domain,
+    ) -> futures::future::BoxFuture<'a, Result<dotchk::CheckResult, dotchk::DomainCheckerError>> {
+        Box::pin(async move {
+            Ok(dotchk::CheckResult::new(
+                domain.as_str(),
+                dotchk::DomainStatus::NxDomain,
+            ))
+        })
+    }
+}
+
+#[tokio::test]
+async fn test_custom_backend_confirmed_by_rdap() {
+    let checker = Checker::builder()
+        .dns(false)
+        .expect("Failed to disable DNS")
+        .backend(AlwaysAvailable)
+        .expect("Failed to add backend")
+        .rdap(
+            dotchk::RdapMode::Verify,
+            Some(dotchk::RdapClient::new().base_url(rdap_stand_in().await)),
+        )
+        .expect("Failed to set rdap")
+        .build()
+        .await
+        .expect("Failed to build checker");
+
+    // RDAP vetoes the mock's "available"
+    let taken = checker.check("taken.com").await.unwrap();
+    assert_eq!(taken.status, dotchk::DomainStatus::Rdap { registered: true });
+    assert!(!taken.available());
+
+    let free = checker.check("free-to-register.com").await.unwrap();
+    assert_eq!(free.status, dotchk::DomainStatus::NxDomain);
+    assert!(free.available());
+    assert!(checker.server_health().is_empty());
+
+    let no_backends = Checker::builder().dns(false).unwrap().build().await;
+    assert!(no_backends.is_err());
+}
+
+#[tokio::test]
+async fn test_unicode_domains_are_checked_as_a_labels() {
+    let checker = Checker::builder()
+        .dns(false)
+        .unwrap()
+        .backend(AlwaysAvailable)
+        .unwrap()
+        .build()
+        .await
+        .unwrap();
+
+    let result = checker.check("München.de").await.unwrap();
+    assert_eq!(result.domain, "xn--mnchen-3ya.de");
+    assert_eq!(result.unicode_domain().as_deref(), Some("münchen.de"));
+
+    let result = checker.check("例子.中国").await.unwrap();
+    assert_eq!(result.domain, "xn--fsqu00a.xn--fiqs8s");
+    assert_eq!(checker.check("example.com").await.unwrap().unicode_domain(), None);
+}
+
+#[tokio::test]
+async fn test_unregistrable_names_are_not_queried() {
+    let checker = Checker::builder()
+        .dns(false)
+        .unwrap()
+        .backend(AlwaysAvailable)
+        .unwrap()
+        .build()
+        .await
+        .unwrap();
+
+    // The mock would call both available
+    let result = checker.check("x.eu").await.unwrap();
+    assert!(matches!(result.status, dotchk::DomainStatus::Unregistrable { .. }));
+    assert!(!result.available());
+
+    let result = checker.check("ab--cd.com").await.unwrap();
+    assert_eq!(result.status.as_str(), "unregistrable");
+}
+
+#[tokio::test]
+async fn test_loaded_registry_rules_apply() {
+    let registry = dotchk::Registry::from_toml(
+        r#"
+        [tlds.com]
+        policy = { min_length = 4 }
+        "#,
+    )
+    .unwrap();
+    let checker = Checker::builder()
+        .dns(false)
+        .unwrap()
+        .backend(AlwaysAvailable)
+        .unwrap()
+        .registry(registry)
+        .unwrap()
+        .build()
+        .await
+        .unwrap();
+
+    let result = checker.check("abc.com").await.unwrap();
+    assert!(matches!(result.status, dotchk::DomainStatus::Unregistrable { .. }));
+    assert!(checker.check("abcd.com").await.unwrap().available());
+}
+
+#[tokio::test]
+async fn test_resolver_transport_validation() {
+    use dotchk::ResolverTransport;
+
+    assert!(Checker::builder().resolver_transport(ResolverTransport::Tls(vec![])).is_err());
+    assert!(Checker::builder().resolver_transport(ResolverTransport::Https(vec![])).is_err());
+    let plain_dns = ResolverTransport::Https(vec!["dns.google/dns-query".to_string()]);
+    assert!(Checker::builder().resolver_transport(plain_dns).is_err());
+    let plain_http = ResolverTransport::Https(vec!["http://127.0.0.1:8053/dns-query".to_string()]);
+    assert!(Checker::builder().resolver_transport(plain_http).is_err());
+
+    let dot = ResolverTransport::Tls(vec!["9.9.9.9#dns.quad9.net".parse().unwrap()]);
+    assert!(Checker::builder().resolver_transport(dot).unwrap().build().await.is_ok());
+}
+
+/// Answer every DNS query with an authoritative NXDOMAIN from a local UDP stand-in; returns its address.
+async fn nxdomain_stand_in() -> String {
+    let socket = tokio::net::UdpSocket::bind("127.0.0.1:0").await.unwrap();
+    let address = socket.local_addr().unwrap().to_string();
+
+    tokio::spawn(async move {
+        let mut buffer = vec![0; 512];
+        while let Ok((n, from)) = socket.recv_from(&mut buffer).await {
+            let mut response = buffer[..n].to_vec();
+            response[2] |= 0x84; // QR=1, AA=1
+            response[3] = 0x03; // NXDOMAIN
+            socket.send_to(&response, from).await.unwrap();
+        }
+    });
+
+    address
+}
+
+#[tokio::test]
+async fn test_authoritative_override_and_resolvers() {
+    let stand_in = nxdomain_stand_in().await;
+    // Bound but never answered
+    let silent = tokio::net::UdpSocket::bind("127.0.0.1:0").await.unwrap();
+    let silent_address = silent.local_addr().unwrap().to_string();
+
+    let builder = || {
+        Checker::builder()
+            .timeout_ms(300)
+            .unwrap()
+            .authoritative_override("com", &[&stand_in])
+            .unwrap()
+            .authoritative_override(".NET", &[&silent_address])
+            .unwrap()
+            .authoritative_override("example", &[&stand_in])
+            .unwrap()
+    };
+
+    let checker = builder().resolvers(&[&stand_in]).unwrap().build().await.unwrap();
+    let pinned = checker.check("free-to-register.com").await.unwrap();
+    assert_eq!(pinned.status, dotchk::DomainStatus::NxDomain);
+    assert!(pinned.authoritative);
+    // A TLD the registry doesn't know is checked at its override's servers too
+    let unlisted = checker.check("free-to-register.example").await.unwrap();
+    assert_eq!(unlisted.status, dotchk::DomainStatus::NxDomain);
+    let fallback = checker.check("free-to-register.net").await.unwrap();
+    assert_eq!(fallback.status, dotchk::DomainStatus::Resolver { exists: false });
+
+    let checker = builder().resolver_fallback(false).unwrap().build().await.unwrap();
+    let unanswered = checker.check("free-to-register.net").await.unwrap();
+    assert!(matches!(unanswered.status, dotchk::DomainStatus::Error { .. }));
+    // Both attempts on the silent server count as one failed query
+    let health = checker.server_health();
+    let silent_health = health.iter().find(|health| health.server == silent_address).unwrap();
+    assert_eq!(silent_health.failures, 1);
+
+    assert!(Checker::builder().resolvers::<&str>(&[]).is_err());
+    assert!(Checker::builder().resolvers(&["dns.corp.example"]).is_err());
+    assert!(Checker::builder().authoritative_override("", &["192.0.2.1"]).is_err());
+    let dot = dotchk::ResolverTransport::Tls(vec!["9.9.9.9".parse().unwrap()]);
+    let conflicting = builder().resolvers(&["10.0.0.53"]).unwrap().resolver_transport(dot).unwrap();
+    assert!(conflicting.build().await.is_err());
+}
+
+#[tokio::test]
+async fn test_cached_answers_leave_server_health_alone() {
+    // Delegates every name to ns1.example for five minutes
+    let socket = tokio::net::UdpSocket::bind("127.0.0.1:0").await.unwrap();
+    let address = socket.local_addr().unwrap().to_string();
+    tokio::spawn(async move {
+        let mut buffer = vec![0; 512];
+        while let Ok((n, from)) = socket.recv_from(&mut buffer).await {
+            let mut response = buffer[..n].to_vec();
+            response[2] |= 0x80; // QR=1
+            response[9] = 1; // NSCOUNT=1
+            response[10..12].fill(0); // No OPT record
+            let question_end = 12 + response[12..].iter().position(|&byte| byte == 0).unwrap() + 5;
+            response.truncate(question_end);
+            response.extend_from_slice(&[0xc0, 12, 0, 2, 0, 1, 0, 0, 1, 44, 0, 13]);
+            response.extend_from_slice(b"\x03ns1\x07example\x00");
+            socket.send_to(&response, from).await.unwrap();
+        }
+    });
+
+    let checker = Checker::builder()
+        .authoritative_override("com", &[&address])
+        .unwrap()
+        .build()
+        .await
+        .unwrap();
+    for _ in 0..2 {
+        let result = checker.check("taken.com").await.unwrap();
+        assert_eq!(result.nameservers, ["ns1.example"]);
+    }
+    let health = checker.server_health();
+    assert_eq!(health.len(), 1);
+    assert_eq!(health[0].successes, 1);
+}
+
+/// `name` in DNS wire format, without compression.
+fn wire_name(name: &str) -> Vec<u8> {
+    let mut wire = Vec::new();
+    for label in name.split('.') {
+        wire.push(label.len() as u8);
+        wire.extend_from_slice(label.as_bytes());
+    }
+    wire.push(0);
+    wire
+}
+
+/// The authority section a signed stand-in zone sends for an NSEC query about `qname`.
+fn walk_records(qname: &str) -> Vec<(&'static str, u16, Vec<u8>)> {
+    const NSEC: u16 = 47;
+    const NSEC3: u16 = 50;
+    let nsec = |owner, next| {
+        let mut rdata = wire_name(next);
+        rdata.extend_from_slice(&[0, 1, 0x62]); // NS, SOA, RRSIG
+        (owner, NSEC, rdata)
+    };
+    match qname {
+        // walk -> alpha.walk -> ns1.alpha.walk -> beta.walk -> walk
+        "\0.walk" => vec![nsec("walk", "alpha.walk")],
+        "alpha\0.walk" => vec![nsec("ns1.alpha.walk", "beta.walk")],
+        "beta\0.walk" => vec![nsec("beta.walk", "walk")],
+        // The last record points back at a name already seen
+        "\0.loop" => vec![nsec("loop", "beta.loop")],
+        "beta\0.loop" => vec![nsec("beta.loop", "alpha.loop")],
+        // A record that covers the probe without moving past the last name
+        "\0.stuck" => vec![nsec("stuck", "alpha.stuck")],
+        "alpha\0.stuck" => vec![nsec("alpha.stuck", "alpha.stuck")],
+        _ if qname.ends_with(".hashed") => {
+            vec![("0p9mhaveqvm6t7vbl5lop2u3t2rp3tom.hashed", NSEC3, vec![1, 0, 0, 1, 0])]
+        }
+        _ => Vec::new(),
+    }
+}
+
+/// Answers every query NXDOMAIN with the records [`walk_records`] picks for its name.
+async fn signed_zone_stand_in() -> String {
+    let socket = tokio::net::UdpSocket::bind("127.0.0.1:0").await.unwrap();
+    let address = socket.local_addr().unwrap().to_string();
+
+    tokio::spawn(async move {
+        let mut buffer = vec![0; 512];
+        while let Ok((n, from)) = socket.recv_from(&mut buffer).await {
+            let query = &buffer[..n];
+            let mut labels = Vec::new();
+            let mut offset = 12;
+            while query[offset] != 0 {
+                let len = query[offset] as usize;
+                labels.push(String::from_utf8_lossy(&query[offset + 1..offset + 1 + len]).into_owned());
+                offset += 1 + len;
+            }
+            let records = walk_records(&labels.join("."));
+
+            let mut response = query[..offset + 5].to_vec();
+            response[2] |= 0x84; // QR=1, AA=1
+            response[3] = 0x03; // NXDOMAIN
+            response[9] = records.len() as u8; // NSCOUNT
+            response[10..12].fill(0); // No OPT record
+            for (owner, rtype, rdata) in records {
+                response.extend_from_slice(&wire_name(owner));
+                response.extend_from_slice(&rtype.to_be_bytes());
+                response.extend_from_slice(&[0, 1, 0, 0, 14, 16]); // IN, one hour
+                response.extend_from_slice(&(rdata.len() as u16).to_be_bytes());
+                response.extend_from_slice(&rdata);
+            }
+            socket.send_to(&response, from).await.unwrap();
+        }
+    });
+
+    address
+}
+
+#[tokio::test]
+async fn test_zone_walk() {
+    use futures::StreamExt;
+
+    let stand_in = signed_zone_stand_in().await;
+    let mut builder = Checker::builder().timeout_ms(500).unwrap();
+    for zone in ["walk", "loop", "stuck", "hashed", "plain"] {
+        builder = builder.authoritative_override(zone, &[&stand_in]).unwrap();
+    }
+    let checker = builder.build().await.unwrap();
+    let walk = |zone| checker.zone_walk(zone).collect::<Vec<_>>();
+
+    // The probe past alpha.walk skips its subdomains, and the walk stops back at the apex
+    let names: Vec<String> = walk("walk").await.into_iter().map(Result::unwrap).collect();
+    assert_eq!(names, ["alpha.walk", "beta.walk"]);
+
+    let error_of = |mut results: Vec<Result<String, dotchk::DomainCheckerError>>| {
+        let error = results.pop().unwrap().unwrap_err();
+        assert!(results.iter().all(Result::is_ok));
+        error.to_string()
+    };
+    assert!(error_of(walk("loop").await).contains("broken after 'beta.loop'"));
+    assert!(error_of(walk("stuck").await).contains("broken after 'alpha.stuck'"));
+    assert!(error_of(walk("hashed").await).contains("NSEC3"));
+    assert!(error_of(walk("plain").await).contains("may be unsigned"));
+}